@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API definition for the fanbase pallet.
+
+use codec::Codec;
+use pallet_fanbase::types::{
+	CalendarEntry, CreatorId, CreatorStats, CreatorView, Erc721Metadata, LaunchState,
+	LaunchTokenView, Receipt, Reputation, Sale, TokenId, TokenView,
+};
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait FanbaseApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get page of sales of tokens minted from launch token, oldest first.
+		fn sales_of(
+			launch_id: TokenId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Sale<AccountId, Balance, BlockNumber>>;
+
+		/// Get page of listed tokens with their prices starting after `cursor`, with the cursor of
+		/// the next page.
+		fn listed_tokens(
+			cursor: Option<TokenId>,
+			limit: u32,
+		) -> (Vec<(TokenId, Balance)>, Option<TokenId>);
+
+		/// Get page of listed tokens minted from launch tokens of creator with their prices
+		/// starting after `cursor`, with the cursor of the next page.
+		fn listed_tokens_of_creator(
+			creator_id: CreatorId,
+			cursor: Option<TokenId>,
+			limit: u32,
+		) -> (Vec<(TokenId, Balance)>, Option<TokenId>);
+
+		/// Get page of launch tokens in sale state.
+		fn launch_tokens_in_state(state: LaunchState, offset: u32, limit: u32) -> Vec<TokenId>;
+
+		/// Get page of launch tokens with name, ignoring case.
+		fn launch_tokens_by_name(name: Vec<u8>, offset: u32, limit: u32) -> Vec<TokenId>;
+
+		/// Resolve creator id to its owner account.
+		fn lookup_creator(creator_id: CreatorId) -> Option<AccountId>;
+
+		/// Resolve account to its primary creator id.
+		fn reverse_lookup_creator(account: AccountId) -> Option<CreatorId>;
+
+		/// Get on-chain activity of creator.
+		fn creator_reputation(creator_id: CreatorId) -> Reputation<Balance>;
+
+		/// Get reputation score of creator.
+		fn creator_score(creator_id: CreatorId) -> u32;
+
+		/// Get aggregate token counters of creator.
+		fn creator_stats(creator_id: CreatorId) -> CreatorStats;
+
+		/// Get page of token ids owned by account starting after `cursor`, with the cursor of the
+		/// next page if any.
+		fn tokens_of_paged(
+			account: AccountId,
+			cursor: Option<TokenId>,
+			limit: u32,
+		) -> (Vec<TokenId>, Option<TokenId>);
+
+		/// Get purchase receipts of launch token held by account with the purchased token ids.
+		fn receipts_of(
+			launch_id: TokenId,
+			account: AccountId,
+		) -> Vec<(TokenId, Receipt<Balance, BlockNumber>)>;
+
+		/// Get proceeds of account awaiting withdrawal.
+		fn pending_proceeds(account: AccountId) -> Balance;
+
+		/// Get frontend view of token.
+		fn token_view(token_id: TokenId) -> Option<TokenView<AccountId, Balance, BlockNumber>>;
+
+		/// Get frontend view of launch token.
+		fn launch_token_view(launch_id: TokenId) -> Option<LaunchTokenView<Balance>>;
+
+		/// Get frontend view of creator.
+		fn creator_view(creator_id: CreatorId) -> Option<CreatorView<AccountId, BlockNumber>>;
+
+		/// Get metadata uri of token.
+		fn token_uri(token_id: TokenId) -> Option<Vec<u8>>;
+
+		/// Get metadata of token in the ERC-721 metadata JSON schema.
+		fn erc721_metadata(token_id: TokenId) -> Option<Erc721Metadata>;
+
+		/// Get drops registered on the launch calendar for calendar period, the calendar period of
+		/// a block is the block number divided by the calendar period length.
+		fn launch_calendar(
+			period: BlockNumber,
+		) -> Vec<CalendarEntry<AccountId, Balance, BlockNumber>>;
+	}
+}
@@ -4,16 +4,1856 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Fanbase;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::{
+	traits::BenchmarkHelper,
+	types::{LaunchCloneOverrides, LaunchTokenMetadata, PriceMode, PriceQuote, SocialProof},
+};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, Saturating, Zero},
+		DispatchError, Perbill, Permill,
+	},
+	sp_std::prelude::*,
+	traits::{Currency, EnsureOrigin, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 
+/// Upper bound of tokens on an account covered by benchmarks
+const MAX_ACCOUNT_TOKENS: u32 = 1_000;
+
+/// Creator id unique to index.
+fn creator_id(index: u32) -> CreatorId {
+	let mut id = b"creator-".to_vec();
+	for byte in index.to_be_bytes() {
+		id.push(b'a' + (byte >> 4));
+		id.push(b'a' + (byte & 0x0f));
+	}
+	id.try_into().unwrap()
+}
+
+/// Social platform unique to index.
+fn platform(index: u32) -> SocialPlatform {
+	let mut platform = b"platform-".to_vec();
+	platform.extend_from_slice(&index.to_be_bytes());
+	platform.try_into().unwrap()
+}
+
+/// Fund account with enough balance for any benchmarked call.
+fn fund<T: Config>(account: &T::AccountId) {
+	T::Currency::make_free_balance_be(account, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// Move to block `blocks` after the current block.
+fn advance_blocks<T: Config>(blocks: T::BlockNumber) {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now.saturating_add(blocks));
+}
+
+/// Launch token metadata with metadata uri and inline data of given lengths.
+fn metadata(uri_len: u32, inline_len: u32, supply: u32) -> LaunchTokenMetadata {
+	LaunchTokenMetadata {
+		name: b"token".to_vec().try_into().unwrap(),
+		mime_type: b"image/svg+xml".to_vec().try_into().unwrap(),
+		metadata_uri: vec![b'u'; uri_len as usize].try_into().unwrap(),
+		tags: Default::default(),
+		content_hash: None,
+		supply,
+		gift_supply: 0,
+		price_mode: PriceMode::Native,
+		redeemable: Permill::zero(),
+		royalty: None,
+		royalty_enforced: false,
+		receipts: false,
+		lockup: 0,
+		curated: false,
+		draft: false,
+		inline_data: (inline_len > 0).then(|| vec![0u8; inline_len as usize].try_into().unwrap()),
+	}
+}
+
+/// Create `count` creator accounts owned by account, returns the last creator id.
+fn create_creators<T: Config>(owner: &T::AccountId, count: u32) -> CreatorId {
+	for index in 0..count {
+		Fanbase::<T>::create_account(RawOrigin::Signed(owner.clone()).into(), creator_id(index))
+			.unwrap();
+	}
+	creator_id(count.saturating_sub(1))
+}
+
+/// Mint launch token of creator account with metadata, returns the launch token id.
+fn mint_launch_with<T: Config>(
+	owner: &T::AccountId,
+	creator_id: &CreatorId,
+	metadata: LaunchTokenMetadata,
+) -> TokenId {
+	Fanbase::<T>::mint(
+		RawOrigin::Signed(owner.clone()).into(),
+		creator_id.clone(),
+		T::MinTokenPrice::get(),
+		metadata,
+	)
+	.unwrap();
+	Fanbase::<T>::launch_issuance_nonce()
+}
+
+/// Mint launch token of creator account with supply, returns the launch token id.
+fn mint_launch<T: Config>(owner: &T::AccountId, creator_id: &CreatorId, supply: u32) -> TokenId {
+	mint_launch_with::<T>(owner, creator_id, metadata(0, 0, supply))
+}
+
+/// Mint one launch token for each of `count` new creator accounts owned by account, returns the
+/// launch token ids.
+fn mint_launches<T: Config>(owner: &T::AccountId, count: u32) -> Vec<TokenId> {
+	create_creators::<T>(owner, count);
+	(0..count).map(|index| mint_launch::<T>(owner, &creator_id(index), 1)).collect()
+}
+
+/// Issue `count` tokens of launch token to account, returns the last token id.
+fn issue_tokens<T: Config>(
+	receiver: &T::AccountId,
+	launch_token_id: &TokenId,
+	count: u32,
+) -> TokenId {
+	let mut token_id = 0;
+	for _ in 0..count {
+		token_id = Fanbase::<T>::unchecked_launch_transfer(receiver, launch_token_id).unwrap();
+	}
+	token_id
+}
+
+/// Mint launch token owned by seller and issue a token of it to seller, returns the launch token id
+/// and token id.
+fn seller_token<T: Config>(seller: &T::AccountId, supply: u32) -> (TokenId, TokenId) {
+	fund::<T>(seller);
+	let creator_id = create_creators::<T>(seller, 1);
+	let launch_token_id = mint_launch::<T>(seller, &creator_id, supply);
+	let token_id = issue_tokens::<T>(seller, &launch_token_id, 1);
+	(launch_token_id, token_id)
+}
+
+/// Create auction of token owned by seller with a buy-now price of twice the min bid.
+fn create_auction<T: Config>(
+	seller: &T::AccountId,
+	token_id: TokenId,
+	reserve: Option<BalanceOf<T>>,
+) -> Result<(), BenchmarkError> {
+	let min_bid = T::MinTokenPrice::get();
+	Fanbase::<T>::create_auction(
+		RawOrigin::Signed(seller.clone()).into(),
+		token_id,
+		min_bid,
+		10u32.into(),
+		None,
+		reserve,
+		Some(reserve.unwrap_or(min_bid).saturating_mul(2u32.into())),
+	)?;
+	Ok(())
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create_account {
+		let c in 0 .. T::MaxCreatorAccounts::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		create_creators::<T>(&caller, c);
+		let creator_id = creator_id(c);
+	}: _(RawOrigin::Signed(caller.clone()), creator_id.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner), Some(caller));
+	}
+
+	// creator account without launch tokens is removed
+	drop_account_remove {
+		let c in 1 .. T::MaxCreatorAccounts::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let creator_id = create_creators::<T>(&caller, c);
+	}: drop_account(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::creator(&creator_id).is_none());
+	}
+
+	// creator account with launch tokens is kept and disconnected
+	drop_account_disconnect {
+		let c in 1 .. T::MaxCreatorAccounts::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, c);
+		mint_launch::<T>(&caller, &creator_id, 1);
+	}: drop_account(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::creator(&creator_id).map_or(false, |creator| creator.owner.is_none()));
+	}
+
+	mint {
+		let n in 0 .. 2048;
+		let d in 0 .. 8192;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let metadata = metadata(n, d, 10);
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), T::MinTokenPrice::get(), metadata)
+	verify {
+		assert_eq!(Fanbase::<T>::launch_token_ids_for_creator(&creator_id).len(), 1);
+	}
+
+	launch_gift {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch_with::<T>(&caller, &creator_id, LaunchTokenMetadata {
+			gift_supply: 1,
+			..metadata(0, 0, t)
+		});
+		issue_tokens::<T>(&receiver, &launch_token_id, t - 1);
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, receiver.clone(), None)
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&receiver).len() as u32, t);
+	}
+
+	transfer {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, t * 2);
+		// transferred token is the last on the owner account
+		issue_tokens::<T>(&receiver, &launch_token_id, t - 1);
+		let token_id = issue_tokens::<T>(&caller, &launch_token_id, t);
+	}: _(RawOrigin::Signed(caller), token_id, receiver.clone(), None)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(receiver));
+	}
+
+	buy {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, t * 2);
+		issue_tokens::<T>(&caller, &launch_token_id, t - 1);
+		let token_id = issue_tokens::<T>(&seller, &launch_token_id, t);
+		let price = T::MinTokenPrice::get();
+		Fanbase::<T>::list(RawOrigin::Signed(seller).into(), token_id, price)?;
+	}: _(RawOrigin::Signed(caller.clone()), token_id, price, None)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(caller));
+	}
+
+	launch_buy {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, t);
+		issue_tokens::<T>(&caller, &launch_token_id, t - 1);
+	}: _(RawOrigin::Signed(caller.clone()), launch_token_id, T::MinTokenPrice::get())
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&caller).len() as u32, t);
+	}
+
+	list {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let price = T::MinTokenPrice::get();
+	}: _(RawOrigin::Signed(caller), token_id, price)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).and_then(|token| token.price), Some(price));
+	}
+
+	unlist {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		Fanbase::<T>::list(
+			RawOrigin::Signed(caller.clone()).into(),
+			token_id,
+			T::MinTokenPrice::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).and_then(|token| token.price), None);
+	}
+
+	set_launch_price {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, 1);
+		let price = T::MinTokenPrice::get().saturating_mul(2u32.into());
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, price)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token(launch_token_id).map(|launch_token| launch_token.price),
+			Some(price)
+		);
+	}
+
+	set_price {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let price = T::MinTokenPrice::get().saturating_mul(2u32.into());
+		Fanbase::<T>::list(RawOrigin::Signed(caller.clone()).into(), token_id, price)?;
+		let price = T::MinTokenPrice::get();
+	}: _(RawOrigin::Signed(caller), token_id, price)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).and_then(|token| token.price), Some(price));
+	}
+
+	burn {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, t);
+		// burned token is the last on the owner account
+		let token_id = issue_tokens::<T>(&caller, &launch_token_id, t);
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert!(Fanbase::<T>::token(token_id).is_none());
+	}
+
+	create_sub_creator {
+		let caller: T::AccountId = whitelisted_caller();
+		let creator_id = create_creators::<T>(&caller, 1);
+		let name: CreatorId = b"sub".to_vec().try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), name)
+	verify {
+		assert_eq!(Fanbase::<T>::sub_creator_ids_for_creator(&creator_id).len(), 1);
+	}
+
+	renew_creator {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let expires_at = Fanbase::<T>::creator(&creator_id).map(|creator| creator.expires_at);
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::creator(&creator_id).map(|creator| creator.expires_at) > expires_at);
+	}
+
+	add_collaborator {
+		let caller: T::AccountId = whitelisted_caller();
+		let collaborator: T::AccountId = account("collaborator", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), collaborator, CollaboratorRole::Admin)
+	verify {
+		assert_eq!(Fanbase::<T>::collaborators(&creator_id).len(), 1);
+	}
+
+	remove_collaborator {
+		let caller: T::AccountId = whitelisted_caller();
+		let collaborator: T::AccountId = account("collaborator", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		Fanbase::<T>::add_collaborator(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			collaborator.clone(),
+			CollaboratorRole::Admin,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), collaborator)
+	verify {
+		assert!(Fanbase::<T>::collaborators(&creator_id).is_empty());
+	}
+
+	add_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), operator, expires_at)
+	verify {
+		assert_eq!(Fanbase::<T>::creator_operators(&creator_id).len(), 1);
+	}
+
+	remove_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Fanbase::<T>::add_operator(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			operator.clone(),
+			expires_at,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), operator)
+	verify {
+		assert!(Fanbase::<T>::creator_operators(&creator_id).is_empty());
+	}
+
+	make_creator_keyless {
+		let caller: T::AccountId = whitelisted_caller();
+		let creator_id = create_creators::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert_eq!(
+			Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner),
+			Some(Fanbase::<T>::keyless_creator_account(&creator_id))
+		);
+	}
+
+	dispatch_as_creator {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		// admins are added before handing over to the keyless account
+		Fanbase::<T>::add_collaborator(
+			RawOrigin::Signed(owner.clone()).into(),
+			creator_id.clone(),
+			caller.clone(),
+			CollaboratorRole::Admin,
+		)?;
+		Fanbase::<T>::make_creator_keyless(RawOrigin::Signed(owner).into(), creator_id.clone())?;
+		let call: <T as Config>::Call = Call::<T>::revoke_quotes {}.into();
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), Box::new(call))
+	verify {
+		assert_eq!(
+			Fanbase::<T>::quote_nonce(Fanbase::<T>::keyless_creator_account(&creator_id)),
+			1
+		);
+	}
+
+	clone_launch {
+		let n in 0 .. 2048;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let source_launch_id = mint_launch_with::<T>(&caller, &creator_id, metadata(n, 0, 10));
+		let overrides =
+			LaunchCloneOverrides { price: None, supply: None, gift_supply: None, sale_start: None };
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), source_launch_id, overrides)
+	verify {
+		assert_eq!(Fanbase::<T>::launch_token_ids_for_creator(&creator_id).len(), 2);
+	}
+
+	execute_quote {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller = T::BenchmarkHelper::signer();
+		fund::<T>(&caller);
+		let (launch_token_id, token_id) = seller_token::<T>(&seller, t);
+		issue_tokens::<T>(&caller, &launch_token_id, t - 1);
+		let quote = PriceQuote {
+			token_id,
+			seller: seller.clone(),
+			buyer: caller.clone(),
+			price: T::MinTokenPrice::get(),
+			expires_at: frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+			nonce: Fanbase::<T>::quote_nonce(&seller),
+		};
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		let payload = (b"fanbase/quote", genesis_hash, &quote).encode();
+		let signature = T::BenchmarkHelper::sign(&seller, &payload);
+	}: _(RawOrigin::Signed(caller.clone()), quote, signature)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(caller));
+	}
+
+	revoke_quotes {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Fanbase::<T>::quote_nonce(&caller), 1);
+	}
+
+	bridge_lock {
+		let dest_chain = T::BenchmarkHelper::bridge_chain().ok_or(BenchmarkError::Skip)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let dest_address: BridgeAddress = vec![0u8; 32].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), token_id, dest_chain, dest_address)
+	verify {
+		assert!(Fanbase::<T>::bridge_custody(token_id).is_some());
+	}
+
+	bridge_unlock {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let dest_chain = T::BenchmarkHelper::bridge_chain().ok_or(BenchmarkError::Skip)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		let (launch_token_id, token_id) = seller_token::<T>(&owner, t);
+		issue_tokens::<T>(&receiver, &launch_token_id, t - 1);
+		Fanbase::<T>::bridge_lock(
+			RawOrigin::Signed(owner).into(),
+			token_id,
+			dest_chain,
+			vec![0u8; 32].try_into().unwrap(),
+		)?;
+		let proof = T::BenchmarkHelper::bridge_unlock_proof(&token_id, &receiver)
+			.ok_or(BenchmarkError::Skip)?
+			.try_into()
+			.map_err(|_| BenchmarkError::Stop("bridge proof exceeds max proof length"))?;
+	}: _(RawOrigin::Signed(caller), token_id, proof)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(receiver));
+	}
+
+	transfer_batch {
+		let b in 1 .. T::MaxBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, b);
+		issue_tokens::<T>(&caller, &launch_token_id, b);
+		let token_ids: BoundedVec<_, _> =
+			Fanbase::<T>::token_ids_for_account(&caller).into_inner().try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), token_ids, receiver.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&receiver).len() as u32, b);
+	}
+
+	make_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (_, token_id) = seller_token::<T>(&seller, 1);
+		let amount = T::MinTokenPrice::get();
+	}: _(RawOrigin::Signed(caller.clone()), token_id, amount)
+	verify {
+		assert_eq!(Fanbase::<T>::offer(token_id, &caller), Some(amount));
+	}
+
+	set_offer_floor {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let floor = T::MinTokenPrice::get();
+	}: _(RawOrigin::Signed(caller), token_id, Some(floor))
+	verify {
+		assert_eq!(Fanbase::<T>::offer_floor(token_id), Some(floor));
+	}
+
+	cancel_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (_, token_id) = seller_token::<T>(&seller, 1);
+		Fanbase::<T>::make_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			token_id,
+			T::MinTokenPrice::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), token_id)
+	verify {
+		assert!(Fanbase::<T>::offer(token_id, &caller).is_none());
+	}
+
+	accept_offer {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&buyer);
+		let (launch_token_id, token_id) = seller_token::<T>(&caller, t);
+		issue_tokens::<T>(&buyer, &launch_token_id, t - 1);
+		Fanbase::<T>::make_offer(
+			RawOrigin::Signed(buyer.clone()).into(),
+			token_id,
+			T::MinTokenPrice::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller), token_id, buyer.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(buyer));
+	}
+
+	make_collection_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (launch_id, _) = seller_token::<T>(&seller, 1);
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), launch_id, T::MinTokenPrice::get(), 1, expiry)
+	verify {
+		assert!(Fanbase::<T>::collection_offer(launch_id, &caller).is_some());
+	}
+
+	cancel_collection_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (launch_id, _) = seller_token::<T>(&seller, 1);
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Fanbase::<T>::make_collection_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			launch_id,
+			T::MinTokenPrice::get(),
+			1,
+			expiry,
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), launch_id)
+	verify {
+		assert!(Fanbase::<T>::collection_offer(launch_id, &caller).is_none());
+	}
+
+	accept_collection_offer {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&buyer);
+		let (launch_id, token_id) = seller_token::<T>(&caller, t);
+		issue_tokens::<T>(&buyer, &launch_id, t - 1);
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Fanbase::<T>::make_collection_offer(
+			RawOrigin::Signed(buyer.clone()).into(),
+			launch_id,
+			T::MinTokenPrice::get(),
+			1,
+			expiry,
+		)?;
+	}: _(RawOrigin::Signed(caller), token_id, buyer.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(buyer));
+	}
+
+	accept_offers_batch {
+		let b in 1 .. T::MaxBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, b);
+		issue_tokens::<T>(&caller, &launch_token_id, b);
+		let mut offers = Vec::new();
+		let token_ids = Fanbase::<T>::token_ids_for_account(&caller);
+		for (index, token_id) in token_ids.into_iter().enumerate() {
+			let buyer: T::AccountId = account("buyer", index as u32, 0);
+			fund::<T>(&buyer);
+			Fanbase::<T>::make_offer(
+				RawOrigin::Signed(buyer.clone()).into(),
+				token_id,
+				T::MinTokenPrice::get(),
+			)?;
+			offers.push((token_id, buyer));
+		}
+		let offers: BoundedVec<_, _> = offers.try_into().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), offers)
+	verify {
+		assert!(Fanbase::<T>::token_ids_for_account(&caller).is_empty());
+	}
+
+	request_listing {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch_with::<T>(&seller, &creator_id, LaunchTokenMetadata {
+			curated: true,
+			..metadata(0, 0, 1)
+		});
+		let token_id = issue_tokens::<T>(&caller, &launch_token_id, 1);
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert!(Fanbase::<T>::listing_request(token_id).is_some());
+	}
+
+	approve_listing {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch_with::<T>(&caller, &creator_id, LaunchTokenMetadata {
+			curated: true,
+			..metadata(0, 0, 1)
+		});
+		let token_id = issue_tokens::<T>(&owner, &launch_token_id, 1);
+		Fanbase::<T>::request_listing(RawOrigin::Signed(owner).into(), token_id)?;
+	}: _(RawOrigin::Signed(caller), creator_id, token_id)
+	verify {
+		assert!(Fanbase::<T>::listing_request(token_id).map_or(false, |request| request.approved));
+	}
+
+	retire {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, t);
+		// retired token is the last on the owner account
+		let token_id = issue_tokens::<T>(&caller, &launch_token_id, t);
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert!(Fanbase::<T>::retired_at(token_id).is_some());
+	}
+
+	burn_batch {
+		let b in 1 .. T::MaxBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, b);
+		issue_tokens::<T>(&caller, &launch_token_id, b);
+		let token_ids: BoundedVec<_, _> =
+			Fanbase::<T>::token_ids_for_account(&caller).into_inner().try_into().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), token_ids)
+	verify {
+		assert!(Fanbase::<T>::token_ids_for_account(&caller).is_empty());
+	}
+
+	buy_protected {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (_, token_id) = seller_token::<T>(&seller, 1);
+		let price = T::MinTokenPrice::get();
+		Fanbase::<T>::list(RawOrigin::Signed(seller).into(), token_id, price)?;
+	}: _(RawOrigin::Signed(caller), token_id, price)
+	verify {
+		assert!(Fanbase::<T>::protected_sale(token_id).is_some());
+	}
+
+	finalize_sale {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (launch_token_id, token_id) = seller_token::<T>(&seller, t);
+		issue_tokens::<T>(&caller, &launch_token_id, t - 1);
+		let price = T::MinTokenPrice::get();
+		Fanbase::<T>::list(RawOrigin::Signed(seller).into(), token_id, price)?;
+		Fanbase::<T>::buy_protected(RawOrigin::Signed(caller.clone()).into(), token_id, price)?;
+	}: _(RawOrigin::Signed(caller.clone()), token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(caller));
+	}
+
+	reverse_sale {
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&buyer);
+		let (_, token_id) = seller_token::<T>(&seller, 1);
+		let price = T::MinTokenPrice::get();
+		Fanbase::<T>::list(RawOrigin::Signed(seller).into(), token_id, price)?;
+		Fanbase::<T>::buy_protected(RawOrigin::Signed(buyer).into(), token_id, price)?;
+		let origin = T::DisputeOrigin::successful_origin();
+	}: _<T::Origin>(origin, token_id)
+	verify {
+		assert!(Fanbase::<T>::protected_sale(token_id).is_none());
+	}
+
+	ban_creator {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::banned_creator(&creator_id).is_some());
+	}
+
+	unban_creator {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		Fanbase::<T>::ban_creator(T::ModerationOrigin::successful_origin(), creator_id.clone())?;
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::banned_creator(&creator_id).is_none());
+	}
+
+	block_account {
+		let account: T::AccountId = account("account", 0, 0);
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, account.clone())
+	verify {
+		assert!(Fanbase::<T>::blocked_account(&account).is_some());
+	}
+
+	unblock_account {
+		let account: T::AccountId = account("account", 0, 0);
+		Fanbase::<T>::block_account(T::ModerationOrigin::successful_origin(), account.clone())?;
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, account.clone())
+	verify {
+		assert!(Fanbase::<T>::blocked_account(&account).is_none());
+	}
+
+	// lowered max royalty queues clamping of existing launch tokens
+	set_parameter {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let creator_id = create_creators::<T>(&owner, 1);
+		mint_launch::<T>(&owner, &creator_id, 1);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, PalletParameter::MaxRoyalty(Some(Permill::zero())))
+	verify {
+		assert_eq!(Fanbase::<T>::max_royalty(), Permill::zero());
+	}
+
+	add_attester {
+		let attester: T::AccountId = account("attester", 0, 0);
+		let origin = T::AttestationOrigin::successful_origin();
+	}: _<T::Origin>(origin, attester.clone())
+	verify {
+		assert!(Fanbase::<T>::is_attester(&attester));
+	}
+
+	// attestations of the attester are cleared by queued work
+	remove_attester {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let attester: T::AccountId = account("attester", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		Fanbase::<T>::add_attester(T::AttestationOrigin::successful_origin(), attester.clone())?;
+		Fanbase::<T>::unchecked_attest_social(
+			&creator_id,
+			&platform(0),
+			Default::default(),
+			attester.clone(),
+		)
+		.map_err(DispatchError::from)?;
+		let origin = T::AttestationOrigin::successful_origin();
+	}: _<T::Origin>(origin, attester.clone())
+	verify {
+		assert!(!Fanbase::<T>::is_attester(&attester));
+	}
+
+	feature_launch {
+		let f in 1 .. T::MaxFeaturedLaunches::get();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let mut launch_token_ids = mint_launches::<T>(&owner, f);
+		let launch_token_id = launch_token_ids.pop().unwrap();
+		for featured in launch_token_ids {
+			Fanbase::<T>::feature_launch(T::CurationOrigin::successful_origin(), featured)?;
+		}
+		let origin = T::CurationOrigin::successful_origin();
+	}: _<T::Origin>(origin, launch_token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::featured_launches().len() as u32, f);
+	}
+
+	unfeature_launch {
+		let f in 1 .. T::MaxFeaturedLaunches::get();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let launch_token_ids = mint_launches::<T>(&owner, f);
+		for featured in launch_token_ids.iter() {
+			Fanbase::<T>::feature_launch(T::CurationOrigin::successful_origin(), *featured)?;
+		}
+		// unfeatured launch token is the last featured
+		let launch_token_id = launch_token_ids[launch_token_ids.len() - 1];
+		let origin = T::CurationOrigin::successful_origin();
+	}: _<T::Origin>(origin, launch_token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::featured_launches().len() as u32, f - 1);
+	}
+
+	reorder_featured_launches {
+		let f in 1 .. T::MaxFeaturedLaunches::get();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let mut launch_token_ids = mint_launches::<T>(&owner, f);
+		for featured in launch_token_ids.iter() {
+			Fanbase::<T>::feature_launch(T::CurationOrigin::successful_origin(), *featured)?;
+		}
+		launch_token_ids.reverse();
+		let order: BoundedVec<_, _> = launch_token_ids.clone().try_into().unwrap();
+		let origin = T::CurationOrigin::successful_origin();
+	}: _<T::Origin>(origin, order)
+	verify {
+		assert_eq!(Fanbase::<T>::featured_launches().into_inner(), launch_token_ids);
+	}
+
+	attest_social {
+		let p in 0 .. T::MaxSocialPlatforms::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		let attester = T::BenchmarkHelper::signer();
+		let creator_id = create_creators::<T>(&caller, 1);
+		Fanbase::<T>::add_attester(T::AttestationOrigin::successful_origin(), attester.clone())?;
+		for index in 0..p {
+			Fanbase::<T>::unchecked_attest_social(
+				&creator_id,
+				&platform(index),
+				Default::default(),
+				attester.clone(),
+			)
+			.map_err(DispatchError::from)?;
+		}
+		let platform = platform(p);
+		let handle: SocialHandle = vec![b'h'; 64].try_into().unwrap();
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		let payload = (&creator_id, &caller, &platform, &handle, expires_at).encode();
+		let proof = SocialProof {
+			attester: attester.clone(),
+			expires_at,
+			signature: T::BenchmarkHelper::sign(&attester, &payload),
+		};
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), platform.clone(), handle, proof)
+	verify {
+		assert!(Fanbase::<T>::social_attestation(&creator_id, &platform).is_some());
+	}
+
+	revoke_social {
+		let caller: T::AccountId = whitelisted_caller();
+		let attester: T::AccountId = account("attester", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		Fanbase::<T>::add_attester(T::AttestationOrigin::successful_origin(), attester.clone())?;
+		Fanbase::<T>::unchecked_attest_social(
+			&creator_id,
+			&platform(0),
+			Default::default(),
+			attester,
+		)
+		.map_err(DispatchError::from)?;
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), platform(0))
+	verify {
+		assert!(Fanbase::<T>::social_attestation(&creator_id, &platform(0)).is_none());
+	}
+
+	initiate_creator_recovery {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		let origin = T::RecoveryOrigin::successful_origin();
+	}: _<T::Origin>(origin, creator_id.clone(), new_owner)
+	verify {
+		assert!(Fanbase::<T>::pending_recovery(&creator_id).is_some());
+	}
+
+	// cancelled by the current owner
+	cancel_creator_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		Fanbase::<T>::initiate_creator_recovery(
+			T::RecoveryOrigin::successful_origin(),
+			creator_id.clone(),
+			new_owner,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::pending_recovery(&creator_id).is_none());
+	}
+
+	execute_creator_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collaborator: T::AccountId = account("collaborator", 0, 0);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		Fanbase::<T>::add_collaborator(
+			RawOrigin::Signed(owner).into(),
+			creator_id.clone(),
+			collaborator,
+			CollaboratorRole::Admin,
+		)?;
+		Fanbase::<T>::initiate_creator_recovery(
+			T::RecoveryOrigin::successful_origin(),
+			creator_id.clone(),
+			new_owner.clone(),
+		)?;
+		advance_blocks::<T>(T::RecoveryDelay::get());
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert_eq!(
+			Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner),
+			Some(new_owner)
+		);
+	}
+
+	transfer_creator {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), new_owner)
+	verify {
+		assert!(Fanbase::<T>::pending_creator_transfer(&creator_id).is_some());
+	}
+
+	accept_creator_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		Fanbase::<T>::transfer_creator(
+			RawOrigin::Signed(owner).into(),
+			creator_id.clone(),
+			caller.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), creator_id.clone())
+	verify {
+		assert_eq!(
+			Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner),
+			Some(caller)
+		);
+	}
+
+	cancel_creator_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		Fanbase::<T>::transfer_creator(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			new_owner,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::pending_creator_transfer(&creator_id).is_none());
+	}
+
+	// `s - q` cheaper listings of the buyer are scanned and skipped before buying `q` listings
+	sweep {
+		let q in 1 .. T::MaxSweepCount::get();
+		let s in T::MaxSweepCount::get() .. T::MaxSweepScan::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, s);
+		let price = T::MinTokenPrice::get();
+		for _ in 0..(s - q) {
+			let token_id = issue_tokens::<T>(&caller, &launch_token_id, 1);
+			Fanbase::<T>::list(RawOrigin::Signed(caller.clone()).into(), token_id, price)?;
+		}
+		for _ in 0..q {
+			let token_id = issue_tokens::<T>(&seller, &launch_token_id, 1);
+			Fanbase::<T>::list(
+				RawOrigin::Signed(seller.clone()).into(),
+				token_id,
+				price.saturating_mul(2u32.into()),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), launch_token_id, q, BalanceOf::<T>::max_value(), s)
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&caller).len() as u32, s);
+	}
+
+	list_dutch {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let end_price = T::MinTokenPrice::get();
+		let start_price = end_price.saturating_mul(2u32.into());
+	}: _(RawOrigin::Signed(caller), token_id, start_price, end_price, 10u32.into())
+	verify {
+		assert!(Fanbase::<T>::dutch_listing(token_id).is_some());
+	}
+
+	launch_buy_many {
+		let q in 1 .. T::MaxLaunchBuyQuantity::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, q);
+	}: _(RawOrigin::Signed(caller.clone()), launch_token_id, q, BalanceOf::<T>::max_value(), false)
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&caller).len() as u32, q);
+	}
+
+	set_launch_beneficiary {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, Some(beneficiary.clone()))
+	verify {
+		assert_eq!(Fanbase::<T>::launch_beneficiary(launch_token_id), Some(beneficiary));
+	}
+
+	set_royalty_beneficiary {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, Some(beneficiary.clone()))
+	verify {
+		assert_eq!(Fanbase::<T>::royalty_beneficiary(launch_token_id), Some(beneficiary));
+	}
+
+	set_drip_schedule {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, Some((1, 10u32.into())))
+	verify {
+		assert!(Fanbase::<T>::drip_schedule(launch_token_id).is_some());
+	}
+
+	set_block_purchase_cap {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, Some(1))
+	verify {
+		assert_eq!(Fanbase::<T>::block_purchase_cap(launch_token_id), Some(1));
+	}
+
+	schedule_drop {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let title: CalendarTitle = vec![b't'; 64].try_into().unwrap();
+		let starts_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	}: _(RawOrigin::Signed(caller), creator_id, title, starts_at)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_calendar(Fanbase::<T>::calendar_period_of(starts_at)).len(),
+			1
+		);
+	}
+
+	link_drop_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+		let starts_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Fanbase::<T>::schedule_drop(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id(0),
+			Default::default(),
+			starts_at,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id(0), starts_at, launch_token_id)
+	verify {
+		let period = Fanbase::<T>::calendar_period_of(starts_at);
+		assert_eq!(
+			Fanbase::<T>::launch_calendar(period).first().and_then(|entry| entry.launch_id),
+			Some(launch_token_id)
+		);
+	}
+
+	cancel_drop {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let starts_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Fanbase::<T>::schedule_drop(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			Default::default(),
+			starts_at,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id, starts_at)
+	verify {
+		assert!(
+			Fanbase::<T>::launch_calendar(Fanbase::<T>::calendar_period_of(starts_at)).is_empty()
+		);
+	}
+
+	open_raffle {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 2);
+		let ticket_fee = T::MinTokenPrice::get();
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, ticket_fee, 1, 10u32.into())
+	verify {
+		assert!(Fanbase::<T>::raffle(launch_token_id).is_some());
+	}
+
+	enter_raffle {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (launch_token_id, _) = seller_token::<T>(&seller, 2);
+		Fanbase::<T>::open_raffle(
+			RawOrigin::Signed(seller).into(),
+			creator_id(0),
+			launch_token_id,
+			T::MinTokenPrice::get(),
+			1,
+			10u32.into(),
+		)?;
+	}: _(RawOrigin::Signed(caller), launch_token_id, 1)
+	verify {
+		assert_eq!(Fanbase::<T>::raffle_entries(launch_token_id).len(), 1);
+	}
+
+	// every entrant wins a token
+	draw_raffle {
+		let e in 1 .. T::MaxRaffleEntries::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&seller);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, e);
+		Fanbase::<T>::open_raffle(
+			RawOrigin::Signed(seller).into(),
+			creator_id,
+			launch_token_id,
+			T::MinTokenPrice::get(),
+			e,
+			1u32.into(),
+		)?;
+		for index in 0..e {
+			let entrant: T::AccountId = account("entrant", index, 0);
+			fund::<T>(&entrant);
+			Fanbase::<T>::enter_raffle(RawOrigin::Signed(entrant).into(), launch_token_id, 1)?;
+		}
+		let ends_at = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+		frame_system::Pallet::<T>::set_block_number(ends_at);
+		Fanbase::<T>::seed_closing_raffles(ends_at);
+	}: _(RawOrigin::Signed(caller), launch_token_id)
+	verify {
+		assert!(Fanbase::<T>::raffle(launch_token_id).is_none());
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.issued),
+			Some(e)
+		);
+	}
+
+	submit_purchase_intent {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&caller);
+		let (launch_token_id, _) = seller_token::<T>(&seller, 2);
+	}: _(RawOrigin::Signed(caller), launch_token_id, T::MinTokenPrice::get())
+	verify {
+		assert_eq!(Fanbase::<T>::purchase_intents().len(), 1);
+	}
+
+	// listed token is unlisted
+	create_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let min_bid = T::MinTokenPrice::get();
+		Fanbase::<T>::list(RawOrigin::Signed(caller.clone()).into(), token_id, min_bid)?;
+		let buy_now = Some(min_bid.saturating_mul(2u32.into()));
+	}: _(RawOrigin::Signed(caller), token_id, min_bid, 10u32.into(), None, Some(min_bid), buy_now)
+	verify {
+		assert!(Fanbase::<T>::auction(token_id).is_some());
+	}
+
+	// highest bid is outbid and returned
+	bid {
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&bidder);
+		let (_, token_id) = seller_token::<T>(&seller, 1);
+		create_auction::<T>(&seller, token_id, None)?;
+		Fanbase::<T>::bid(RawOrigin::Signed(bidder).into(), token_id, T::MinTokenPrice::get())?;
+		let amount = Fanbase::<T>::auction(token_id)
+			.map(|auction| Fanbase::<T>::get_min_next_bid(&auction))
+			.ok_or(BenchmarkError::Stop("auction is created"))?;
+	}: _(RawOrigin::Signed(caller.clone()), token_id, amount)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::auction(token_id).and_then(|auction| auction.highest_bid),
+			Some((caller, amount))
+		);
+	}
+
+	buy_now {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&bidder);
+		let (launch_token_id, token_id) = seller_token::<T>(&seller, t);
+		issue_tokens::<T>(&caller, &launch_token_id, t - 1);
+		create_auction::<T>(&seller, token_id, None)?;
+		Fanbase::<T>::bid(RawOrigin::Signed(bidder).into(), token_id, T::MinTokenPrice::get())?;
+	}: _(RawOrigin::Signed(caller.clone()), token_id, BalanceOf::<T>::max_value())
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(caller));
+	}
+
+	settle_auction {
+		let t in 1 .. T::MaxTokens::get().min(MAX_ACCOUNT_TOKENS);
+		let caller: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, 0);
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		fund::<T>(&bidder);
+		let (launch_token_id, token_id) = seller_token::<T>(&seller, t);
+		issue_tokens::<T>(&bidder, &launch_token_id, t - 1);
+		create_auction::<T>(&seller, token_id, None)?;
+		Fanbase::<T>::bid(
+			RawOrigin::Signed(bidder.clone()).into(),
+			token_id,
+			T::MinTokenPrice::get(),
+		)?;
+		advance_blocks::<T>(10u32.into());
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(bidder));
+	}
+
+	// highest bid below the reserve is returned
+	relist_from_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		fund::<T>(&bidder);
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		let reserve = T::MinTokenPrice::get().saturating_mul(2u32.into());
+		create_auction::<T>(&caller, token_id, Some(reserve))?;
+		Fanbase::<T>::bid(RawOrigin::Signed(bidder).into(), token_id, T::MinTokenPrice::get())?;
+		advance_blocks::<T>(10u32.into());
+	}: _(RawOrigin::Signed(caller), token_id, None)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).and_then(|token| token.price), Some(reserve));
+	}
+
+	cancel_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, token_id) = seller_token::<T>(&caller, 1);
+		create_auction::<T>(&caller, token_id, None)?;
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert!(Fanbase::<T>::auction(token_id).is_none());
+	}
+
+	set_milestones {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, 1);
+		let milestones: BoundedVec<_, _> =
+			vec![Perbill::from_percent(50), Perbill::from_percent(50)].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, milestones)
+	verify {
+		assert!(Fanbase::<T>::milestone_escrow(launch_token_id).is_some());
+	}
+
+	approve_milestone {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&seller);
+		fund::<T>(&buyer);
+		let creator_id = create_creators::<T>(&seller, 1);
+		let launch_token_id = mint_launch::<T>(&seller, &creator_id, 1);
+		let milestones: BoundedVec<_, _> =
+			vec![Perbill::from_percent(50), Perbill::from_percent(50)].try_into().unwrap();
+		Fanbase::<T>::set_milestones(
+			RawOrigin::Signed(seller).into(),
+			creator_id,
+			launch_token_id,
+			milestones,
+		)?;
+		Fanbase::<T>::launch_buy(
+			RawOrigin::Signed(buyer).into(),
+			launch_token_id,
+			T::MinTokenPrice::get(),
+		)?;
+		let origin = T::MilestoneOrigin::successful_origin();
+	}: _<T::Origin>(origin, launch_token_id)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::milestone_escrow(launch_token_id).map(|escrow| escrow.approved),
+			Some(1)
+		);
+	}
+
+	// proceeds of sales after the last approval are released
+	claim_milestone_proceeds {
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&caller);
+		fund::<T>(&buyer);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, 2);
+		let milestones: BoundedVec<_, _> =
+			vec![Perbill::from_percent(50), Perbill::from_percent(50)].try_into().unwrap();
+		Fanbase::<T>::set_milestones(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			launch_token_id,
+			milestones,
+		)?;
+		Fanbase::<T>::launch_buy(
+			RawOrigin::Signed(buyer.clone()).into(),
+			launch_token_id,
+			T::MinTokenPrice::get(),
+		)?;
+		Fanbase::<T>::approve_milestone(T::MilestoneOrigin::successful_origin(), launch_token_id)?;
+		Fanbase::<T>::launch_buy(
+			RawOrigin::Signed(buyer).into(),
+			launch_token_id,
+			T::MinTokenPrice::get(),
+		)?;
+		let released =
+			Fanbase::<T>::milestone_escrow(launch_token_id).map(|escrow| escrow.released);
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id)
+	verify {
+		assert!(
+			Fanbase::<T>::milestone_escrow(launch_token_id).map(|escrow| escrow.released) >
+				released
+		);
+	}
+
+	// previous pool of creator account is closed
+	fund_match {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&owner, 1);
+		let amount = T::MinTokenPrice::get();
+		let end_block = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+		Fanbase::<T>::fund_match(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			amount,
+			Permill::from_percent(10),
+			end_block,
+		)?;
+		advance_blocks::<T>(1u32.into());
+		let end_block = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		let ratio = Permill::from_percent(10);
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), amount, ratio, end_block)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::matching_pool(&creator_id).map(|pool| pool.ends_at),
+			Some(end_block)
+		);
+	}
+
+	close_match {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&owner, 1);
+		let end_block = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+		Fanbase::<T>::fund_match(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			T::MinTokenPrice::get(),
+			Permill::from_percent(10),
+			end_block,
+		)?;
+		advance_blocks::<T>(1u32.into());
+	}: _(RawOrigin::Signed(caller), creator_id.clone())
+	verify {
+		assert!(Fanbase::<T>::matching_pool(&creator_id).is_none());
+	}
+
+	add_tag {
+		let name: TagName = vec![b't'; 64].try_into().unwrap();
+		let origin = T::TaxonomyOrigin::successful_origin();
+	}: _<T::Origin>(origin, name)
+	verify {
+		assert!(Fanbase::<T>::tag(0).is_some());
+	}
+
+	remove_tag {
+		Fanbase::<T>::add_tag(T::TaxonomyOrigin::successful_origin(), Default::default())?;
+		let origin = T::TaxonomyOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(Fanbase::<T>::tag(0).is_none());
+	}
+
+	set_tags {
+		let g in 0 .. 10;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, 1);
+		for _ in 0..g {
+			Fanbase::<T>::add_tag(T::TaxonomyOrigin::successful_origin(), Default::default())?;
+		}
+		let tags: TokenTags = (0..g).collect::<Vec<_>>().try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, tags)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token(launch_token_id)
+				.map(|launch_token| launch_token.tags.len() as u32),
+			Some(g)
+		);
+	}
+
+	report_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let (launch_token_id, _) = seller_token::<T>(&owner, 1);
+		let reason: ReportReason = vec![b'r'; 256].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), launch_token_id, reason)
+	verify {
+		assert_eq!(Fanbase::<T>::report_count(launch_token_id), 1);
+	}
+
+	// upheld reports add a strike
+	resolve_report {
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&reporter);
+		let (launch_token_id, _) = seller_token::<T>(&owner, 1);
+		Fanbase::<T>::report_launch(
+			RawOrigin::Signed(reporter).into(),
+			launch_token_id,
+			Default::default(),
+		)?;
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, launch_token_id, 0, true)
+	verify {
+		assert_eq!(Fanbase::<T>::strikes(launch_token_id), 1);
+	}
+
+	claim_infringement {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let (launch_token_id, _) = seller_token::<T>(&owner, 1);
+	}: _(RawOrigin::Signed(caller), launch_token_id, Default::default())
+	verify {
+		assert!(Fanbase::<T>::infringement_claim(launch_token_id).is_some());
+	}
+
+	// upheld claims freeze the launch token
+	resolve_infringement {
+		let claimant: T::AccountId = account("claimant", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&claimant);
+		let (launch_token_id, _) = seller_token::<T>(&owner, 1);
+		Fanbase::<T>::claim_infringement(
+			RawOrigin::Signed(claimant).into(),
+			launch_token_id,
+			Default::default(),
+		)?;
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, launch_token_id, true)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Frozen)
+		);
+	}
+
+	update_draft {
+		let n in 0 .. 2048;
+		let d in 0 .. 8192;
+		let g in 0 .. 10;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch_with::<T>(&caller, &creator_id, LaunchTokenMetadata {
+			draft: true,
+			..metadata(0, 0, 10)
+		});
+		for _ in 0..g {
+			Fanbase::<T>::add_tag(T::TaxonomyOrigin::successful_origin(), Default::default())?;
+		}
+		let metadata = LaunchTokenMetadata {
+			tags: (0..g).collect::<Vec<_>>().try_into().unwrap(),
+			..metadata(n, d, 10)
+		};
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, T::MinTokenPrice::get(), metadata)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token(launch_token_id)
+				.map(|launch_token| launch_token.tags.len() as u32),
+			Some(g)
+		);
+	}
+
+	publish_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch_with::<T>(&caller, &creator_id, LaunchTokenMetadata {
+			draft: true,
+			..metadata(0, 0, 1)
+		});
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Live)
+		);
+	}
+
+	set_launch_defaults {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), Permill::zero(), Some(beneficiary))
+	verify {
+		assert!(Fanbase::<T>::launch_defaults_for_creator(&creator_id).is_some());
+	}
+
+	pause_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 2);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Paused)
+		);
+	}
+
+	resume_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 2);
+		Fanbase::<T>::pause_launch(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id(0),
+			launch_token_id,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Live)
+		);
+	}
+
+	cancel_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 2);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Cancelled)
+		);
+	}
+
+	delegate_launch {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let delegate = create_creators::<T>(&caller, 2);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id(0), 1);
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, delegate.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::launch_delegate(launch_token_id), Some(delegate));
+	}
+
+	revoke_launch_delegation {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let delegate = create_creators::<T>(&caller, 2);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id(0), 1);
+		Fanbase::<T>::delegate_launch(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id(0),
+			launch_token_id,
+			delegate,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert!(Fanbase::<T>::launch_delegate(launch_token_id).is_none());
+	}
+
+	set_primary_creator {
+		let caller: T::AccountId = whitelisted_caller();
+		let creator_id = create_creators::<T>(&caller, 2);
+	}: _(RawOrigin::Signed(caller.clone()), creator_id.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::primary_creator_for_account(&caller), Some(creator_id));
+	}
+
+	upload_media_chunk {
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+		let chunk: MediaChunk = vec![0u8; 65536].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id, 0, chunk)
+	verify {
+		assert!(Fanbase::<T>::media_chunk(launch_token_id, 0).is_some());
+	}
+
+	finalize_media {
+		let m in 1 .. T::MaxMediaChunks::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+		for index in 0..m {
+			Fanbase::<T>::upload_media_chunk(
+				RawOrigin::Signed(caller.clone()).into(),
+				creator_id(0),
+				launch_token_id,
+				index,
+				vec![0u8; 65536].try_into().unwrap(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert!(Fanbase::<T>::media_upload(launch_token_id)
+			.map_or(false, |upload| upload.is_finalized()));
+	}
+
+	clear_media {
+		let m in 1 .. T::MaxMediaChunks::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let (launch_token_id, _) = seller_token::<T>(&caller, 1);
+		for index in 0..m {
+			Fanbase::<T>::upload_media_chunk(
+				RawOrigin::Signed(caller.clone()).into(),
+				creator_id(0),
+				launch_token_id,
+				index,
+				vec![0u8; 65536].try_into().unwrap(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), creator_id(0), launch_token_id)
+	verify {
+		assert!(Fanbase::<T>::media_upload(launch_token_id).is_none());
+	}
+
+	// creator account is moved to a new owner
+	force_set_creator {
+		if !cfg!(feature = "force-calls") {
+			return Err(BenchmarkError::Skip)
+		}
+		let owner: T::AccountId = account("owner", 0, 0);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		let creator = Creator {
+			owner: Some(new_owner.clone()),
+			..Fanbase::<T>::creator(&creator_id).ok_or(BenchmarkError::Stop("creator is created"))?
+		};
+	}: _(RawOrigin::Root, creator)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner),
+			Some(new_owner)
+		);
+	}
+
+	// launch token is replaced
+	force_set_launch_token {
+		if !cfg!(feature = "force-calls") {
+			return Err(BenchmarkError::Skip)
+		}
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (launch_token_id, _) = seller_token::<T>(&owner, 2);
+		let launch_token = Fanbase::<T>::launch_token(launch_token_id)
+			.ok_or(BenchmarkError::Stop("launch token is minted"))?;
+		let launch_token_state = LaunchTokenState {
+			state: LaunchState::Paused,
+			..Fanbase::<T>::launch_token_state(launch_token_id)
+				.ok_or(BenchmarkError::Stop("launch token is minted"))?
+		};
+	}: _(RawOrigin::Root, launch_token, launch_token_state)
+	verify {
+		assert_eq!(
+			Fanbase::<T>::launch_token_state(launch_token_id).map(|state| state.state),
+			Some(LaunchState::Paused)
+		);
+	}
+
+	// listed token is moved to a new owner
+	force_set_token {
+		if !cfg!(feature = "force-calls") {
+			return Err(BenchmarkError::Skip)
+		}
+		let owner: T::AccountId = account("owner", 0, 0);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let (_, token_id) = seller_token::<T>(&owner, 1);
+		Fanbase::<T>::list(RawOrigin::Signed(owner).into(), token_id, T::MinTokenPrice::get())?;
+		let token = Token {
+			owner: new_owner.clone(),
+			..Fanbase::<T>::token(token_id).ok_or(BenchmarkError::Stop("token is issued"))?
+		};
+	}: _(RawOrigin::Root, token)
+	verify {
+		assert_eq!(Fanbase::<T>::token(token_id).map(|token| token.owner), Some(new_owner));
+	}
+
+	add_marketplace {
+		let account: T::AccountId = account("marketplace", 0, 0);
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, account.clone())
+	verify {
+		assert!(Fanbase::<T>::is_marketplace(&account));
+	}
+
+	remove_marketplace {
+		let account: T::AccountId = account("marketplace", 0, 0);
+		Fanbase::<T>::add_marketplace(T::ModerationOrigin::successful_origin(), account.clone())?;
+		let origin = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(origin, account.clone())
+	verify {
+		assert!(!Fanbase::<T>::is_marketplace(&account));
+	}
+
+	transfer_all_of_launch {
+		let b in 1 .. T::MaxBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, b);
+		issue_tokens::<T>(&caller, &launch_token_id, b);
+	}: _(RawOrigin::Signed(caller), launch_token_id, receiver.clone())
+	verify {
+		assert_eq!(Fanbase::<T>::token_ids_for_account(&receiver).len() as u32, b);
+	}
+
+	set_successor {
+		let caller: T::AccountId = whitelisted_caller();
+		let successor: T::AccountId = account("successor", 0, 0);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let succession = Some((successor, T::MinSuccessionThreshold::get()));
+	}: _(RawOrigin::Signed(caller), creator_id.clone(), succession)
+	verify {
+		assert!(Fanbase::<T>::succession(&creator_id).is_some());
+	}
+
+	claim_succession {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let creator_id = create_creators::<T>(&owner, 1);
+		Fanbase::<T>::set_successor(
+			RawOrigin::Signed(owner).into(),
+			creator_id.clone(),
+			Some((caller.clone(), T::MinSuccessionThreshold::get())),
+		)?;
+		advance_blocks::<T>(T::MinSuccessionThreshold::get());
+	}: _(RawOrigin::Signed(caller.clone()), creator_id.clone())
+	verify {
+		assert_eq!(
+			Fanbase::<T>::creator(&creator_id).and_then(|creator| creator.owner),
+			Some(caller)
+		);
+	}
+
+	withdraw_proceeds {
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&buyer);
+		let (launch_token_id, _) = seller_token::<T>(&caller, 2);
+		Fanbase::<T>::launch_buy(
+			RawOrigin::Signed(buyer).into(),
+			launch_token_id,
+			T::MinTokenPrice::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Fanbase::<T>::pending_proceeds(&caller).is_zero());
+	}
+
+	set_payout_account {
+		let caller: T::AccountId = whitelisted_caller();
+		let payout: T::AccountId = account("payout", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), Some(payout.clone()))
+	verify {
+		assert_eq!(Fanbase::<T>::payout_account(&caller), Some(payout));
+	}
+
+	take_snapshot {
+		let h in 1 .. T::MaxSnapshotHolders::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch::<T>(&caller, &creator_id, h);
+		for index in 0..h {
+			issue_tokens::<T>(&account("holder", index, 0), &launch_token_id, 1);
+		}
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id)
+	verify {
+		assert_eq!(Fanbase::<T>::snapshot(0).map(|snapshot| snapshot.holders), Some(h));
+	}
+
+	airdrop_to_snapshot {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let holder: T::AccountId = account("holder", 0, 0);
+		fund::<T>(&caller);
+		let creator_id = create_creators::<T>(&caller, 1);
+		let launch_token_id = mint_launch_with::<T>(&caller, &creator_id, LaunchTokenMetadata {
+			gift_supply: 1,
+			..metadata(0, 0, 2)
+		});
+		issue_tokens::<T>(&holder, &launch_token_id, 1);
+		Fanbase::<T>::take_snapshot(
+			RawOrigin::Signed(caller.clone()).into(),
+			creator_id.clone(),
+			launch_token_id,
+		)?;
+	}: _(RawOrigin::Signed(caller), creator_id, launch_token_id, 0)
 	verify {
-		// assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(Fanbase::<T>::work_queue().len(), 1);
 	}
 
 	impl_benchmark_test_suite!(Fanbase, crate::mock::new_test_ext(), crate::mock::Test);
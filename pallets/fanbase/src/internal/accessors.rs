@@ -0,0 +1,625 @@
+use crate::{
+	AttestationCounts, Attesters, Auction, Auctions, BalanceOf, BannedCreators, BlockPurchaseCaps,
+	BlockPurchases, BlockedAccounts, BridgeCustodies, BridgeCustodyOf, CalendarEntryOf,
+	CollaboratorRole, Collaborators, CollectionOffer, CollectionOffers, Config, Creator,
+	CreatorActivity, CreatorActivityLog, CreatorId, CreatorIdsForAccount, CreatorOperators,
+	CreatorStats, CreatorStatsOf, CreatorTransfer, Creators, DripSchedule, DripSchedules,
+	DutchListing, DutchListings, FeaturedLaunches, HeldTokens, InfringementClaim,
+	InfringementClaims, InlineContent, InlineContents, IssuanceNonce, LastActive, LastPriceChange,
+	LastTrades, LaunchBeneficiaries, LaunchCalendar, LaunchDefaults, LaunchDefaultsForCreator,
+	LaunchDelegates, LaunchIssuanceNonce, LaunchState, LaunchToken, LaunchTokenIdsForCreator,
+	LaunchTokenState, LaunchTokenStates, LaunchTokens, LaunchTokensByState, ListingRequest,
+	ListingRequests, Marketplaces, MatchingPool, MatchingPools, MediaChunk, MediaChunks,
+	MediaUpload, MediaUploads, MilestoneEscrow, MilestoneEscrows, MintQuota, OfferDeposits,
+	OfferFloors, Offers, Pallet, PalletParametersOf, Parameters, PayoutAccounts,
+	PendingCreatorTransfers, PendingProceeds, PendingRecoveries, PrimaryCreatorForAccount,
+	ProtectedSale, ProtectedSales, PurchaseIntent, PurchaseIntents, QuoteNonces, Raffle,
+	RaffleEntries, Raffles, RafflesClosingAt, RedeemableReserves, Report, ReportCount, ReportId,
+	Reports, ReputationOf, Reputations, RetiredTokens, RoyaltyBeneficiaries, SaleCount, SaleOf,
+	Sales, Snapshot, SnapshotHolders, SnapshotId, SnapshotNonce, Snapshots, SocialAttestationOf,
+	SocialAttestations, SocialPlatform, Strikes, SubCreatorIdsForCreator, Succession, Successions,
+	TagId, TagName, TagNonce, Tags, Token, TokenId, TokenIdsForAccount, Tokens, UniqueHolders,
+	WorkId, WorkNonce, WorkOf, WorkQueue, Works,
+};
+use codec::EncodeLike;
+use frame_support::pallet_prelude::*;
+
+/// Typed storage accessors.
+impl<T: Config> Pallet<T> {
+	/// Get creator account by id.
+	pub fn creator<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<Creator<T>> {
+		Creators::<T>::get(key)
+	}
+
+	/// Get creator ids owned by account.
+	pub fn creator_ids_for_account<KArg: EncodeLike<T::AccountId>>(
+		key: KArg,
+	) -> BoundedVec<CreatorId, T::MaxCreatorAccounts> {
+		CreatorIdsForAccount::<T>::get(key)
+	}
+
+	/// Get primary creator id of account.
+	pub fn primary_creator_for_account<KArg: EncodeLike<T::AccountId>>(
+		key: KArg,
+	) -> Option<CreatorId> {
+		PrimaryCreatorForAccount::<T>::get(key)
+	}
+
+	/// Get on-chain content of launch token.
+	pub fn inline_content<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<InlineContent<T>> {
+		InlineContents::<T>::get(key)
+	}
+
+	/// Get media upload of launch token.
+	pub fn media_upload<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<MediaUpload<T>> {
+		MediaUploads::<T>::get(key)
+	}
+
+	/// Get media chunk of launch token at index.
+	pub fn media_chunk<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<u32>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<MediaChunk> {
+		MediaChunks::<T>::get(key1, key2)
+	}
+
+	/// Get collaborators of creator account and their roles.
+	pub fn collaborators<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> BoundedVec<(T::AccountId, CollaboratorRole), T::MaxCollaborators> {
+		Collaborators::<T>::get(key)
+	}
+
+	/// Get latest privileged actions performed on behalf of creator account.
+	pub fn creator_activity_log<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> BoundedVec<CreatorActivity<T>, T::MaxActivityLog> {
+		CreatorActivityLog::<T>::get(key)
+	}
+
+	/// Get sub-creator ids created under creator account.
+	pub fn sub_creator_ids_for_creator<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> BoundedVec<CreatorId, T::MaxSubCreators> {
+		SubCreatorIdsForCreator::<T>::get(key)
+	}
+
+	/// Get pending recovery of creator account.
+	pub fn pending_recovery<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> Option<(T::AccountId, T::BlockNumber)> {
+		PendingRecoveries::<T>::get(key)
+	}
+
+	/// Get pending handover of creator account.
+	pub fn pending_creator_transfer<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> Option<CreatorTransfer<T>> {
+		PendingCreatorTransfers::<T>::get(key)
+	}
+
+	/// Get block creator account was banned at, if banned.
+	pub fn banned_creator<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<T::BlockNumber> {
+		BannedCreators::<T>::get(key)
+	}
+
+	/// Get block account was blocked at, if blocked.
+	pub fn blocked_account<KArg: EncodeLike<T::AccountId>>(key: KArg) -> Option<T::BlockNumber> {
+		BlockedAccounts::<T>::get(key)
+	}
+
+	/// Returns `true` if account is a social attester.
+	pub fn is_attester<KArg: EncodeLike<T::AccountId>>(key: KArg) -> bool {
+		Attesters::<T>::contains_key(key)
+	}
+
+	/// Get attested social handle of creator account on platform.
+	pub fn social_attestation<KArg1: EncodeLike<CreatorId>, KArg2: EncodeLike<SocialPlatform>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<SocialAttestationOf<T>> {
+		SocialAttestations::<T>::get(key1, key2)
+	}
+
+	/// Get number of social handles attested by attester.
+	pub fn attestation_count<KArg: EncodeLike<T::AccountId>>(key: KArg) -> u32 {
+		AttestationCounts::<T>::get(key)
+	}
+
+	/// Returns `true` if account is a registered marketplace.
+	pub fn is_marketplace<KArg: EncodeLike<T::AccountId>>(key: KArg) -> bool {
+		Marketplaces::<T>::contains_key(key)
+	}
+
+	/// Get on-chain activity of creator account.
+	pub fn reputation<KArg: EncodeLike<CreatorId>>(key: KArg) -> ReputationOf<T> {
+		Reputations::<T>::get(key)
+	}
+
+	/// Get aggregate token counters of creator account.
+	pub fn creator_stats<KArg: EncodeLike<CreatorId>>(key: KArg) -> CreatorStats {
+		CreatorStatsOf::<T>::get(key)
+	}
+
+	/// Get open infringement claim against launch token.
+	pub fn infringement_claim<KArg: EncodeLike<TokenId>>(
+		key: KArg,
+	) -> Option<InfringementClaim<T>> {
+		InfringementClaims::<T>::get(key)
+	}
+
+	/// Get report against launch token by id.
+	pub fn report<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<ReportId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<Report<T>> {
+		Reports::<T>::get(key1, key2)
+	}
+
+	/// Get number of reports filed against launch token.
+	pub fn report_count<KArg: EncodeLike<TokenId>>(key: KArg) -> ReportId {
+		ReportCount::<T>::get(key)
+	}
+
+	/// Get number of upheld reports against launch token.
+	pub fn strikes<KArg: EncodeLike<TokenId>>(key: KArg) -> u32 {
+		Strikes::<T>::get(key)
+	}
+
+	/// Get launch token by id.
+	pub fn launch_token<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<LaunchToken<T>> {
+		LaunchTokens::<T>::get(key)
+	}
+
+	/// Get issuance counters and sale state of launch token.
+	pub fn launch_token_state<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<LaunchTokenState> {
+		LaunchTokenStates::<T>::get(key)
+	}
+
+	/// Get state index entry of launch token.
+	pub fn launch_tokens_by_state<KArg1: EncodeLike<LaunchState>, KArg2: EncodeLike<TokenId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<()> {
+		LaunchTokensByState::<T>::get(key1, key2)
+	}
+
+	/// Get launch token ids of creator account.
+	pub fn launch_token_ids_for_creator<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> BoundedVec<TokenId, T::MaxLaunchTokens> {
+		LaunchTokenIdsForCreator::<T>::get(key)
+	}
+
+	/// Get token by id.
+	pub fn token<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Token<T>> {
+		Tokens::<T>::get(key)
+	}
+
+	/// Get block token was retired at.
+	pub fn retired_at<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<T::BlockNumber> {
+		RetiredTokens::<T>::get(key)
+	}
+
+	/// Get token ids owned by account.
+	pub fn token_ids_for_account<KArg: EncodeLike<T::AccountId>>(
+		key: KArg,
+	) -> BoundedVec<TokenId, T::MaxTokens> {
+		TokenIdsForAccount::<T>::get(key)
+	}
+
+	/// Get current mint period start and mint count of creator account.
+	pub fn mint_quota<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<(T::BlockNumber, u32)> {
+		MintQuota::<T>::get(key)
+	}
+
+	/// Get per block purchase cap of launch token.
+	pub fn block_purchase_cap<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<u32> {
+		BlockPurchaseCaps::<T>::get(key)
+	}
+
+	/// Get block of last purchase and purchases in it of buyer for launch token.
+	pub fn block_purchases<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<(T::BlockNumber, u32)> {
+		BlockPurchases::<T>::get(key1, key2)
+	}
+
+	/// Get block and price of last price change of token.
+	pub fn last_price_change<KArg: EncodeLike<TokenId>>(
+		key: KArg,
+	) -> Option<(T::BlockNumber, BalanceOf<T>)> {
+		LastPriceChange::<T>::get(key)
+	}
+
+	/// Get pending protected sale of token.
+	pub fn protected_sale<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<ProtectedSale<T>> {
+		ProtectedSales::<T>::get(key)
+	}
+
+	/// Get redeemable reserve of token.
+	pub fn redeemable_reserve<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<BalanceOf<T>> {
+		RedeemableReserves::<T>::get(key)
+	}
+
+	/// Get beneficiary of launch token.
+	pub fn launch_beneficiary<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<T::AccountId> {
+		LaunchBeneficiaries::<T>::get(key)
+	}
+
+	/// Get royalty beneficiary of launch token.
+	pub fn royalty_beneficiary<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<T::AccountId> {
+		RoyaltyBeneficiaries::<T>::get(key)
+	}
+
+	/// Get listing request of token.
+	pub fn listing_request<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<ListingRequest<T>> {
+		ListingRequests::<T>::get(key)
+	}
+
+	/// Get creator account managing launch token by delegation.
+	pub fn launch_delegate<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<CreatorId> {
+		LaunchDelegates::<T>::get(key)
+	}
+
+	/// Get succession of creator account.
+	pub fn succession<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<Succession<T>> {
+		Successions::<T>::get(key)
+	}
+
+	/// Get block of last signed call of account tracked by a succession.
+	pub fn last_active<KArg: EncodeLike<T::AccountId>>(key: KArg) -> Option<T::BlockNumber> {
+		LastActive::<T>::get(key)
+	}
+
+	/// Get drip schedule of launch token.
+	pub fn drip_schedule<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<DripSchedule<T>> {
+		DripSchedules::<T>::get(key)
+	}
+
+	/// Get open raffle of launch token.
+	pub fn raffle<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Raffle<T>> {
+		Raffles::<T>::get(key)
+	}
+
+	/// Get launch tokens whose raffle closes at block.
+	pub fn raffles_closing_at<KArg: EncodeLike<T::BlockNumber>>(
+		key: KArg,
+	) -> BoundedVec<TokenId, T::MaxRafflesClosingPerBlock> {
+		RafflesClosingAt::<T>::get(key)
+	}
+
+	/// Get raffle entrants of launch token and their ticket count.
+	pub fn raffle_entries<KArg: EncodeLike<TokenId>>(
+		key: KArg,
+	) -> BoundedVec<(T::AccountId, u32), T::MaxRaffleEntries> {
+		RaffleEntries::<T>::get(key)
+	}
+
+	/// Get drops registered on the launch calendar for calendar period.
+	pub fn launch_calendar<KArg: EncodeLike<T::BlockNumber>>(
+		key: KArg,
+	) -> BoundedVec<CalendarEntryOf<T>, T::MaxCalendarEntries> {
+		LaunchCalendar::<T>::get(key)
+	}
+
+	/// Get payout account of account.
+	pub fn payout_account<KArg: EncodeLike<T::AccountId>>(key: KArg) -> Option<T::AccountId> {
+		PayoutAccounts::<T>::get(key)
+	}
+
+	/// Get operator keys of creator and the block each key expires at.
+	pub fn creator_operators<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> BoundedVec<(T::AccountId, T::BlockNumber), T::MaxOperators> {
+		CreatorOperators::<T>::get(key)
+	}
+
+	/// Get nonce off-chain price quotes of seller must be signed for.
+	pub fn quote_nonce<KArg: EncodeLike<T::AccountId>>(key: KArg) -> u32 {
+		QuoteNonces::<T>::get(key)
+	}
+
+	/// Get bridge custody of token locked for another chain.
+	pub fn bridge_custody<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<BridgeCustodyOf<T>> {
+		BridgeCustodies::<T>::get(key)
+	}
+
+	/// Get featured launch tokens in display order.
+	pub fn featured_launches() -> BoundedVec<TokenId, T::MaxFeaturedLaunches> {
+		FeaturedLaunches::<T>::get()
+	}
+
+	/// Get queued purchase intents.
+	pub fn purchase_intents() -> BoundedVec<PurchaseIntent<T>, T::MaxPurchaseIntents> {
+		PurchaseIntents::<T>::get()
+	}
+
+	/// Get number of tokens of launch token held by account.
+	pub fn held_tokens<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> u32 {
+		HeldTokens::<T>::get(key1, key2)
+	}
+
+	/// Get number of accounts holding tokens of launch token.
+	pub fn unique_holders<KArg: EncodeLike<TokenId>>(key: KArg) -> u32 {
+		UniqueHolders::<T>::get(key)
+	}
+
+	/// Get launch defaults of creator account.
+	pub fn launch_defaults_for_creator<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> Option<LaunchDefaults<T>> {
+		LaunchDefaultsForCreator::<T>::get(key)
+	}
+
+	/// Get milestone escrow of launch token.
+	pub fn milestone_escrow<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<MilestoneEscrow<T>> {
+		MilestoneEscrows::<T>::get(key)
+	}
+
+	/// Get matching pool of creator account.
+	pub fn matching_pool<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<MatchingPool<T>> {
+		MatchingPools::<T>::get(key)
+	}
+
+	/// Get running auction of token.
+	pub fn auction<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Auction<T>> {
+		Auctions::<T>::get(key)
+	}
+
+	/// Get offer of buyer for token.
+	pub fn offer<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<BalanceOf<T>> {
+		Offers::<T>::get(key1, key2)
+	}
+
+	/// Get offer deposit of buyer for token.
+	pub fn offer_deposit<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<BalanceOf<T>> {
+		OfferDeposits::<T>::get(key1, key2)
+	}
+
+	/// Get collection offer of buyer for launch token.
+	pub fn collection_offer<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<CollectionOffer<T>> {
+		CollectionOffers::<T>::get(key1, key2)
+	}
+
+	/// Get offer floor of token.
+	pub fn offer_floor<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<BalanceOf<T>> {
+		OfferFloors::<T>::get(key)
+	}
+
+	/// Get dutch listing of token.
+	pub fn dutch_listing<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<DutchListing<T>> {
+		DutchListings::<T>::get(key)
+	}
+
+	/// Get sale of launch token at index.
+	pub fn sale<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<u32>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<SaleOf<T>> {
+		Sales::<T>::get(key1, key2)
+	}
+
+	/// Get seller, buyer and block of last sale of token.
+	pub fn last_trade<KArg: EncodeLike<TokenId>>(
+		key: KArg,
+	) -> Option<(T::AccountId, T::AccountId, T::BlockNumber)> {
+		LastTrades::<T>::get(key)
+	}
+
+	/// Get number of sales of launch token.
+	pub fn sale_count<KArg: EncodeLike<TokenId>>(key: KArg) -> u32 {
+		SaleCount::<T>::get(key)
+	}
+
+	/// Get tag name by id.
+	pub fn tag<KArg: EncodeLike<TagId>>(key: KArg) -> Option<TagName> {
+		Tags::<T>::get(key)
+	}
+
+	/// Get proceeds of account awaiting withdrawal.
+	pub fn pending_proceeds<KArg: EncodeLike<T::AccountId>>(key: KArg) -> BalanceOf<T> {
+		PendingProceeds::<T>::get(key)
+	}
+
+	/// Get governance set values of pallet parameters.
+	pub fn parameters() -> PalletParametersOf<T> {
+		Parameters::<T>::get()
+	}
+
+	/// Get snapshot of launch token holders by id.
+	pub fn snapshot<KArg: EncodeLike<SnapshotId>>(key: KArg) -> Option<Snapshot<T>> {
+		Snapshots::<T>::get(key)
+	}
+
+	/// Get holder of snapshot by index.
+	pub fn snapshot_holder<KArg1: EncodeLike<SnapshotId>, KArg2: EncodeLike<u32>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<T::AccountId> {
+		SnapshotHolders::<T>::get(key1, key2)
+	}
+
+	/// Get number of taken snapshots.
+	pub fn snapshot_nonce() -> SnapshotId {
+		SnapshotNonce::<T>::get()
+	}
+
+	/// Get queued work by id.
+	pub fn work<KArg: EncodeLike<WorkId>>(key: KArg) -> Option<WorkOf<T>> {
+		Works::<T>::get(key)
+	}
+
+	/// Get queued work ids in processing order.
+	pub fn work_queue() -> BoundedVec<WorkId, T::MaxQueuedWork> {
+		WorkQueue::<T>::get()
+	}
+
+	/// Get number of queued work.
+	pub fn work_nonce() -> WorkId {
+		WorkNonce::<T>::get()
+	}
+
+	/// Get number of created tags.
+	pub fn tag_nonce() -> TagId {
+		TagNonce::<T>::get()
+	}
+
+	/// Get number of issued launch tokens.
+	pub fn launch_issuance_nonce() -> TokenId {
+		LaunchIssuanceNonce::<T>::get()
+	}
+
+	/// Get number of issued tokens.
+	pub fn issuance_nonce() -> TokenId {
+		IssuanceNonce::<T>::get()
+	}
+}
+
+/// Accessors formerly generated by `#[pallet::getter]` under different names, kept until downstream
+/// runtimes have migrated.
+impl<T: Config> Pallet<T> {
+	#[deprecated(note = "use `Pallet::creator` instead")]
+	pub fn creators<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<Creator<T>> {
+		Self::creator(key)
+	}
+
+	#[deprecated(note = "use `Pallet::inline_content` instead")]
+	pub fn inline_contents<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<InlineContent<T>> {
+		Self::inline_content(key)
+	}
+
+	#[deprecated(note = "use `Pallet::media_upload` instead")]
+	pub fn media_uploads<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<MediaUpload<T>> {
+		Self::media_upload(key)
+	}
+
+	#[deprecated(note = "use `Pallet::media_chunk` instead")]
+	pub fn media_chunks<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<u32>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<MediaChunk> {
+		Self::media_chunk(key1, key2)
+	}
+
+	#[deprecated(note = "use `Pallet::pending_recovery` instead")]
+	pub fn pending_recoveries<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> Option<(T::AccountId, T::BlockNumber)> {
+		Self::pending_recovery(key)
+	}
+
+	#[deprecated(note = "use `Pallet::pending_creator_transfer` instead")]
+	pub fn pending_creator_transfers<KArg: EncodeLike<CreatorId>>(
+		key: KArg,
+	) -> Option<CreatorTransfer<T>> {
+		Self::pending_creator_transfer(key)
+	}
+
+	#[deprecated(note = "use `Pallet::banned_creator` instead")]
+	pub fn banned_creators<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<T::BlockNumber> {
+		Self::banned_creator(key)
+	}
+
+	#[deprecated(note = "use `Pallet::blocked_account` instead")]
+	pub fn blocked_accounts<KArg: EncodeLike<T::AccountId>>(key: KArg) -> Option<T::BlockNumber> {
+		Self::blocked_account(key)
+	}
+
+	#[deprecated(note = "use `Pallet::reputation` instead")]
+	pub fn reputations<KArg: EncodeLike<CreatorId>>(key: KArg) -> ReputationOf<T> {
+		Self::reputation(key)
+	}
+
+	#[deprecated(note = "use `Pallet::report` instead")]
+	pub fn reports<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<ReportId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<Report<T>> {
+		Self::report(key1, key2)
+	}
+
+	#[deprecated(note = "use `Pallet::launch_token` instead")]
+	pub fn launch_tokens<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<LaunchToken<T>> {
+		Self::launch_token(key)
+	}
+
+	#[deprecated(note = "use `Pallet::token` instead")]
+	pub fn tokens<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Token<T>> {
+		Self::token(key)
+	}
+
+	#[deprecated(note = "use `Pallet::protected_sale` instead")]
+	pub fn protected_sales<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<ProtectedSale<T>> {
+		Self::protected_sale(key)
+	}
+
+	#[deprecated(note = "use `Pallet::redeemable_reserve` instead")]
+	pub fn redeemable_reserves<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<BalanceOf<T>> {
+		Self::redeemable_reserve(key)
+	}
+
+	#[deprecated(note = "use `Pallet::launch_beneficiary` instead")]
+	pub fn launch_beneficiaries<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<T::AccountId> {
+		Self::launch_beneficiary(key)
+	}
+
+	#[deprecated(note = "use `Pallet::raffle` instead")]
+	pub fn raffles<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Raffle<T>> {
+		Self::raffle(key)
+	}
+
+	#[deprecated(note = "use `Pallet::milestone_escrow` instead")]
+	pub fn milestone_escrows<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<MilestoneEscrow<T>> {
+		Self::milestone_escrow(key)
+	}
+
+	#[deprecated(note = "use `Pallet::matching_pool` instead")]
+	pub fn matching_pools<KArg: EncodeLike<CreatorId>>(key: KArg) -> Option<MatchingPool<T>> {
+		Self::matching_pool(key)
+	}
+
+	#[deprecated(note = "use `Pallet::auction` instead")]
+	pub fn auctions<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<Auction<T>> {
+		Self::auction(key)
+	}
+
+	#[deprecated(note = "use `Pallet::offer` instead")]
+	pub fn offers<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<T::AccountId>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<BalanceOf<T>> {
+		Self::offer(key1, key2)
+	}
+
+	#[deprecated(note = "use `Pallet::dutch_listing` instead")]
+	pub fn dutch_listings<KArg: EncodeLike<TokenId>>(key: KArg) -> Option<DutchListing<T>> {
+		Self::dutch_listing(key)
+	}
+
+	#[deprecated(note = "use `Pallet::sale` instead")]
+	pub fn sales<KArg1: EncodeLike<TokenId>, KArg2: EncodeLike<u32>>(
+		key1: KArg1,
+		key2: KArg2,
+	) -> Option<SaleOf<T>> {
+		Self::sale(key1, key2)
+	}
+
+	#[deprecated(note = "use `Pallet::tag` instead")]
+	pub fn tags<KArg: EncodeLike<TagId>>(key: KArg) -> Option<TagName> {
+		Self::tag(key)
+	}
+}
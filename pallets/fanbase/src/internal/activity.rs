@@ -0,0 +1,44 @@
+use crate::{
+	CollaboratorRole, Config, CreatorAction, CreatorActivity, CreatorActivityLog, CreatorId, Event,
+	Pallet, TokenId,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Record privileged action performed by actor on behalf of creator account.
+	///
+	/// The log keeps the latest `MaxActivityLog` entries, dropping the oldest when full.
+	///
+	/// **Storage ops**
+	/// - One storage read to get collaborators of creator `Collaborators<T>`
+	/// - One storage read-write to append entry to log `CreatorActivityLog<T>`
+	pub fn record_creator_action(
+		creator_id: &CreatorId,
+		actor: &T::AccountId,
+		action: CreatorAction,
+		launch_token_id: TokenId,
+	) {
+		let role: Option<CollaboratorRole> = Self::get_collaborator_role(creator_id, actor);
+
+		CreatorActivityLog::<T>::mutate(creator_id, |log| {
+			if log.is_full() {
+				log.remove(0);
+			}
+			// cannot fail since an entry was removed if full
+			let _ = log.try_push(CreatorActivity {
+				actor: actor.clone(),
+				role,
+				action,
+				launch_token_id,
+				at: frame_system::Pallet::<T>::block_number(),
+			});
+		});
+
+		Self::deposit_event(Event::<T>::CreatorActionPerformed(
+			creator_id.clone(),
+			actor.clone(),
+			role,
+			action,
+			launch_token_id,
+		));
+	}
+}
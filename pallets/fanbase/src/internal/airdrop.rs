@@ -0,0 +1,99 @@
+use crate::{
+	traits::EnsureTransferAllowed, weights, Config, CreatorId, Error, Event, HeldTokens, Pallet,
+	Snapshot, SnapshotHolders, SnapshotId, SnapshotNonce, Snapshots, TokenId,
+};
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Record current holders of launch token in a new snapshot.
+	///
+	/// Returns the snapshot id and number of recorded holders.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update snapshot nonce `SnapshotNonce<T>`
+	/// - One storage read per holder of launch token `HeldTokens<T>`
+	/// - One storage write per holder of launch token `SnapshotHolders<T>`
+	/// - One storage write to save snapshot `Snapshots<T>`
+	pub fn unchecked_take_snapshot(
+		creator_id: &CreatorId,
+		launch_id: &TokenId,
+	) -> Result<(SnapshotId, u32), Error<T>> {
+		let snapshot_id = Self::snapshot_nonce();
+		let next_snapshot_id = snapshot_id.checked_add(1).ok_or(Error::<T>::SnapshotsOverflow)?;
+
+		let mut holders = 0u32;
+		for account in HeldTokens::<T>::iter_key_prefix(launch_id) {
+			SnapshotHolders::<T>::insert(snapshot_id, holders, account);
+			holders = holders.saturating_add(1);
+		}
+
+		Snapshots::<T>::insert(
+			snapshot_id,
+			Snapshot {
+				creator: creator_id.clone(),
+				launch_id: *launch_id,
+				holders,
+				taken_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		SnapshotNonce::<T>::set(next_snapshot_id);
+
+		Ok((snapshot_id, holders))
+	}
+
+	/// Weight of airdropping a single token.
+	pub fn airdrop_step_weight() -> Weight {
+		weights::MID.saturating_add(T::DbWeight::get().reads_writes(15, 9))
+	}
+
+	/// Issue token of launch token from its gift supply to snapshot holder at `index`.
+	///
+	/// Each token is issued atomically so failed issuance leaves no partial changes.
+	///
+	/// **Storage ops**
+	/// - One storage read to get snapshot holder `SnapshotHolders<T>`
+	/// - Storage ops of `get_launch_token_owner`
+	/// - Storage ops of `unchecked_launch_gift`
+	pub fn airdrop_step(launch_token_id: &TokenId, snapshot_id: &SnapshotId, index: u32) {
+		let receiver = match Self::snapshot_holder(snapshot_id, index) {
+			Some(receiver) => receiver,
+			None => return,
+		};
+
+		let result = with_transaction(|| {
+			let result = Self::get_launch_token_owner(launch_token_id)
+				.ok_or(Error::<T>::TokenNotFound)
+				.and_then(|(owner, _)| {
+					// ensure transfer is allowed
+					ensure!(
+						T::TransferFilter::is_transfer_allowed(&owner, &receiver, launch_token_id),
+						Error::<T>::TransferNotAllowed
+					);
+					Self::unchecked_launch_gift(&receiver, launch_token_id)
+				});
+
+			match result {
+				Ok(token_id) => TransactionOutcome::Commit(Ok(token_id)),
+				Err(err) => TransactionOutcome::Rollback(Err(err)),
+			}
+		});
+
+		match result {
+			Ok(token_id) => Self::deposit_event(Event::<T>::TokenAirdropped(
+				receiver,
+				*launch_token_id,
+				token_id,
+			)),
+			Err(err) => Self::deposit_event(Event::<T>::AirdropFailed(
+				receiver,
+				*launch_token_id,
+				err.into(),
+			)),
+		}
+	}
+}
@@ -0,0 +1,176 @@
+use crate::{Auction, Auctions, BalanceOf, BidIncrement, Config, Error, Pallet, TokenId};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{One, Saturating},
+	storage::{with_transaction, TransactionOutcome},
+	traits::ReservableCurrency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Place bid on auction, reserving the bid and returning the reserve of the outbid bidder.
+	///
+	/// Returns the outbid bidder and their bid.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update auction `Auctions<T>`
+	/// - One storage read-write to reserve bid `Balances`
+	/// - One storage read-write to unreserve outbid bid `Balances`
+	pub fn unchecked_place_bid(
+		bidder: &T::AccountId,
+		token_id: &TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<Option<(T::AccountId, BalanceOf<T>)>, Error<T>> {
+		Auctions::<T>::try_mutate(token_id, |auction| {
+			let auction = auction.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+
+			// reserve bid from bidder
+			T::Currency::reserve(bidder, amount).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			// return reserve of outbid bidder
+			let outbid = auction.highest_bid.replace((bidder.clone(), amount));
+			if let Some((outbid_bidder, outbid_amount)) = &outbid {
+				T::Currency::unreserve(outbid_bidder, *outbid_amount);
+			}
+
+			Ok(outbid)
+		})
+	}
+
+	/// Settle auction by transferring token to highest bidder and their reserved bid to seller.
+	///
+	/// Auctions without bids are closed and the token remains with the seller. Highest bids below
+	/// the reserve price are returned and the token remains with the seller. If the sale to the
+	/// highest bidder fails, the bid is returned and the token remains with the seller so the
+	/// auction cannot get stuck.
+	///
+	/// Returns settled auction and the error of the failed sale, if any.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove auction `Auctions<T>`
+	/// - One storage read-write to unreserve highest bid if reserve is not met or sale failed
+	///   `Balances`
+	/// - Storage ops of `settle_auction_sale`
+	pub fn unchecked_settle_auction(
+		token_id: &TokenId,
+	) -> Result<(Auction<T>, Option<DispatchError>), Error<T>> {
+		let auction = Auctions::<T>::take(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+		if let Some((bidder, amount)) = &auction.highest_bid {
+			// return highest bid below reserve
+			if !Self::is_reserve_met(&auction) {
+				T::Currency::unreserve(bidder, *amount);
+				return Ok((auction, None))
+			}
+
+			// return highest bid if the token cannot be sold to the bidder
+			let sale = Self::settle_auction_sale(&auction.seller, bidder, token_id, *amount);
+			if let Err(err) = sale {
+				T::Currency::unreserve(bidder, *amount);
+				return Ok((auction, Some(err)))
+			}
+		}
+
+		Ok((auction, None))
+	}
+
+	/// Sell token to highest bidder of auction for their reserved bid.
+	///
+	/// Settled atomically so a failed sale leaves no partial changes.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token by id `Tokens<T>`
+	/// - Storage ops of `unchecked_transfer`
+	/// - Storage ops of `unchecked_pay_reserved_sale`
+	/// - Storage ops of `record_sale`
+	fn settle_auction_sale(
+		seller: &T::AccountId,
+		bidder: &T::AccountId,
+		token_id: &TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		with_transaction(|| {
+			let result = Self::token(token_id).ok_or(Error::<T>::TokenNotFound).and_then(|token| {
+				// transfer token to highest bidder
+				Self::unchecked_transfer(seller, bidder, token_id)?;
+
+				// transfer reserved bid to seller, less royalty
+				Self::unchecked_pay_reserved_sale(bidder, seller, &token, amount)?;
+
+				Self::record_sale(
+					&token.launch_id,
+					*token_id,
+					seller.clone(),
+					bidder.clone(),
+					amount,
+				)
+			});
+			match result {
+				Ok(()) => TransactionOutcome::Commit(Ok(())),
+				Err(err) => TransactionOutcome::Rollback(Err(err.into())),
+			}
+		})
+	}
+
+	/// Settle auction by selling token to buyer at the buy-now price, returning the reserve of the
+	/// highest bidder.
+	///
+	/// Returns closed auction.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove auction `Auctions<T>`
+	/// - One storage read-write to unreserve highest bid `Balances`
+	/// - Storage ops of `unchecked_buy`
+	pub fn unchecked_buy_now(
+		buyer: &T::AccountId,
+		token_id: &TokenId,
+		price: BalanceOf<T>,
+	) -> Result<Auction<T>, Error<T>> {
+		let auction = Auctions::<T>::take(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+		// return reserve of highest bidder
+		if let Some((bidder, amount)) = &auction.highest_bid {
+			T::Currency::unreserve(bidder, *amount);
+		}
+
+		let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+		Self::unchecked_buy(buyer, &token, price)?;
+
+		Ok(auction)
+	}
+
+	/// Get lowest amount the next bid on auction must reach.
+	///
+	/// The first bid must meet the min bid, later bids must raise the highest bid by at least the
+	/// min increment and never by less than one unit.
+	pub fn get_min_next_bid(auction: &Auction<T>) -> BalanceOf<T> {
+		match &auction.highest_bid {
+			Some((_, highest)) => {
+				let increment = match auction.min_increment {
+					BidIncrement::Absolute(amount) => amount,
+					BidIncrement::Percent(share) => share * *highest,
+				};
+				highest.saturating_add(increment.max(One::one()))
+			},
+			None => auction.min_bid,
+		}
+	}
+
+	/// Returns `true` if auction has a highest bid meeting its reserve price.
+	pub fn is_reserve_met(auction: &Auction<T>) -> bool {
+		auction
+			.highest_bid
+			.as_ref()
+			.map_or(false, |(_, amount)| auction.reserve.map_or(true, |reserve| *amount >= reserve))
+	}
+
+	/// Returns `true` if bidding on auction has closed.
+	pub fn is_auction_over(auction: &Auction<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >= auction.ends_at
+	}
+}
@@ -0,0 +1,59 @@
+use crate::{
+	BridgeAddress, BridgeCustodies, BridgeCustody, BridgeCustodyOf, ChainId, Config, Error, Pallet,
+	TokenId,
+};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Lock token of owner in bridge custody for the destination chain.
+	///
+	/// Token is unlisted and locked until released with a bridge proof.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to unlist token `Tokens<T>`
+	/// - One storage write to save bridge custody `BridgeCustodies<T>`
+	pub fn unchecked_bridge_lock(
+		owner: T::AccountId,
+		token_id: &TokenId,
+		dest_chain: ChainId,
+		dest_address: BridgeAddress,
+	) -> Result<(), Error<T>> {
+		// unlist token
+		Self::unchecked_set_price(token_id, None)?;
+
+		// save bridge custody
+		let locked_at = frame_system::Pallet::<T>::block_number();
+		BridgeCustodies::<T>::insert(
+			token_id,
+			BridgeCustody { owner, dest_chain, dest_address, locked_at },
+		);
+
+		Ok(())
+	}
+
+	/// Release token from bridge custody to receiver, transferring it if receiver is not the
+	/// account that locked it.
+	///
+	/// Returns released bridge custody.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove bridge custody `BridgeCustodies<T>`
+	/// - Storage ops of `unchecked_transfer` if receiver is not the owner
+	pub fn unchecked_bridge_unlock(
+		token_id: &TokenId,
+		receiver: &T::AccountId,
+	) -> Result<BridgeCustodyOf<T>, Error<T>> {
+		let custody = BridgeCustodies::<T>::take(token_id).ok_or(Error::<T>::TokenNotBridged)?;
+
+		// transfer token to receiver
+		if &custody.owner != receiver {
+			Self::unchecked_transfer(&custody.owner, receiver, token_id)?;
+		}
+
+		Ok(custody)
+	}
+}
@@ -0,0 +1,115 @@
+use crate::{
+	CalendarEntry, CalendarEntryOf, CalendarTitle, Config, CreatorId, Error, LaunchCalendar,
+	Pallet, TokenId,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{CheckedDiv, Zero},
+	sp_std::prelude::*,
+	traits::ReservableCurrency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Get calendar period of block, counted in `CalendarPeriod` blocks from genesis.
+	pub fn calendar_period_of(block: T::BlockNumber) -> T::BlockNumber {
+		block.checked_div(&T::CalendarPeriod::get()).unwrap_or_else(Zero::zero)
+	}
+
+	/// Register upcoming drop of creator account on the launch calendar and reserve deposit from
+	/// depositor.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to add entry to calendar period `LaunchCalendar<T>`
+	/// - One storage read-write to reserve deposit `Balances`
+	pub fn unchecked_schedule_drop(
+		depositor: &T::AccountId,
+		creator_id: CreatorId,
+		title: CalendarTitle,
+		starts_at: T::BlockNumber,
+	) -> Result<(), Error<T>> {
+		LaunchCalendar::<T>::try_mutate(Self::calendar_period_of(starts_at), |entries| {
+			// ensure creator has no drop starting at the same block
+			ensure!(
+				!entries
+					.iter()
+					.any(|entry| entry.creator == creator_id && entry.starts_at == starts_at),
+				Error::<T>::DropAlreadyScheduled
+			);
+
+			// reserve deposit from depositor
+			let deposit = T::CalendarDeposit::get();
+			T::Currency::reserve(depositor, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			entries
+				.try_push(CalendarEntry {
+					creator: creator_id,
+					title,
+					starts_at,
+					launch_id: None,
+					depositor: depositor.clone(),
+					deposit,
+				})
+				.map_err(|_| Error::<T>::MaxCalendarEntriesReached)
+		})
+	}
+
+	/// Set launch token of drop of creator account starting at block.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update entry of calendar period `LaunchCalendar<T>`
+	pub fn unchecked_link_drop(
+		creator_id: &CreatorId,
+		starts_at: T::BlockNumber,
+		launch_id: TokenId,
+	) -> Result<(), Error<T>> {
+		LaunchCalendar::<T>::try_mutate(Self::calendar_period_of(starts_at), |entries| {
+			let entry = entries
+				.iter_mut()
+				.find(|entry| entry.creator == *creator_id && entry.starts_at == starts_at)
+				.ok_or(Error::<T>::DropNotFound)?;
+
+			entry.launch_id = Some(launch_id);
+
+			Ok(())
+		})
+	}
+
+	/// Remove drop of creator account starting at block from the launch calendar and return
+	/// deposit to depositor.
+	///
+	/// Returns removed entry.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove entry from calendar period `LaunchCalendar<T>`
+	/// - One storage read-write to unreserve deposit `Balances`
+	pub fn unchecked_cancel_drop(
+		creator_id: &CreatorId,
+		starts_at: T::BlockNumber,
+	) -> Result<CalendarEntryOf<T>, Error<T>> {
+		let entry =
+			LaunchCalendar::<T>::try_mutate(Self::calendar_period_of(starts_at), |entries| {
+				let index = entries
+					.iter()
+					.position(|entry| entry.creator == *creator_id && entry.starts_at == starts_at)
+					.ok_or(Error::<T>::DropNotFound)?;
+
+				Ok::<_, Error<T>>(entries.remove(index))
+			})?;
+
+		// return deposit to depositor
+		T::Currency::unreserve(&entry.depositor, entry.deposit);
+
+		Ok(entry)
+	}
+
+	/// Get drops registered on the launch calendar for calendar period.
+	pub fn launch_calendar_of(period: T::BlockNumber) -> Vec<CalendarEntryOf<T>> {
+		Self::launch_calendar(period).into_inner()
+	}
+}
@@ -0,0 +1,78 @@
+use crate::{
+	BalanceOf, Config, DripSchedule, DripSchedules, Error, LaunchCloneOverridesOf,
+	LaunchTokenMetadata, Pallet, TokenId,
+};
+use frame_support::{pallet_prelude::*, sp_runtime::traits::One};
+
+impl<T: Config> Pallet<T> {
+	/// Get price and metadata of a clone of launch token with overrides applied.
+	///
+	/// The clone copies the original supply of the source launch token. Content hash and on-chain
+	/// content are not copied.
+	///
+	/// **Storage ops**
+	/// - One storage read to get source launch token `LaunchTokens<T>`
+	/// - One storage read to get source launch token state `LaunchTokenStates<T>`
+	/// - Storage ops of `max_royalty`
+	pub fn get_clone_metadata(
+		source_launch_id: &TokenId,
+		overrides: &LaunchCloneOverridesOf<T>,
+	) -> Result<(BalanceOf<T>, LaunchTokenMetadata), Error<T>> {
+		let source = Self::launch_token(source_launch_id).ok_or(Error::<T>::TokenNotFound)?;
+		let source_state =
+			Self::launch_token_state(source_launch_id).ok_or(Error::<T>::TokenNotFound)?;
+
+		let metadata = LaunchTokenMetadata {
+			name: source.name,
+			mime_type: source.mime_type,
+			metadata_uri: source.metadata_uri,
+			tags: source.tags,
+			content_hash: None,
+			supply: overrides.supply.unwrap_or_else(|| source_state.total_supply()),
+			gift_supply: overrides.gift_supply.unwrap_or(source_state.gift_supply),
+			price_mode: source.price_mode,
+			redeemable: source.redeemable,
+			// royalty of source may be pending a clamp to a lowered max royalty
+			royalty: Some(source.royalty.min(Self::max_royalty())),
+			royalty_enforced: source.royalty_enforced,
+			receipts: source.receipts,
+			lockup: source.lockup,
+			curated: source.curated,
+			draft: false,
+			inline_data: None,
+		};
+
+		Ok((overrides.price.unwrap_or(source.price), metadata))
+	}
+
+	/// Set drip schedule of cloned launch token.
+	///
+	/// The drip schedule of the source launch token is copied starting at the sale start, or the
+	/// current block if none is given. Without a source drip schedule the whole supply is released
+	/// at the sale start.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get drip schedule of source launch token `DripSchedules<T>`
+	/// - One storage write to save drip schedule of clone `DripSchedules<T>`
+	pub fn unchecked_clone_drip_schedule(
+		source_launch_id: &TokenId,
+		launch_token_id: &TokenId,
+		metadata: &LaunchTokenMetadata,
+		sale_start: Option<T::BlockNumber>,
+	) -> Option<DripSchedule<T>> {
+		let schedule = match (Self::drip_schedule(source_launch_id), sale_start) {
+			(Some(schedule), sale_start) => Some(DripSchedule {
+				start: sale_start.unwrap_or_else(frame_system::Pallet::<T>::block_number),
+				..schedule
+			}),
+			(None, Some(start)) =>
+				Some(DripSchedule { amount: metadata.supply, period: One::one(), start }),
+			(None, None) => None,
+		};
+		DripSchedules::<T>::set(launch_token_id, schedule.clone());
+
+		schedule
+	}
+}
@@ -0,0 +1,113 @@
+use crate::{BalanceOf, CollectionOffer, CollectionOffers, Config, Error, Pallet, Token, TokenId};
+use frame_support::{ensure, sp_runtime::traits::Saturating, traits::ReservableCurrency};
+
+impl<T: Config> Pallet<T> {
+	/// Place offer for tokens of launch token, replacing any standing collection offer of buyer for
+	/// the launch token.
+	///
+	/// Offer amount for the whole quantity is reserved from buyer until tokens are sold or the
+	/// offer is cancelled. The offer deposit is reserved for new offers and carried over when
+	/// replacing an offer.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update collection offer `CollectionOffers<T>`
+	/// - One storage read-write to unreserve previous offer amount `Balances`
+	/// - One storage read-write to reserve offer deposit `Balances`
+	/// - One storage read-write to reserve offer amount `Balances`
+	pub fn unchecked_make_collection_offer(
+		buyer: &T::AccountId,
+		launch_id: &TokenId,
+		mut offer: CollectionOffer<T>,
+	) -> Result<(), Error<T>> {
+		CollectionOffers::<T>::try_mutate(launch_id, buyer, |standing| {
+			match standing.take() {
+				// return previous offer amount to buyer and keep its deposit
+				Some(previous) => {
+					T::Currency::unreserve(buyer, previous.reserved());
+					offer.deposit = previous.deposit;
+				},
+				// reserve deposit for new offer
+				None => T::Currency::reserve(buyer, offer.deposit)
+					.map_err(|_| Error::<T>::InsufficientFunds)?,
+			}
+
+			// reserve offer amount from buyer
+			T::Currency::reserve(buyer, offer.reserved())
+				.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			*standing = Some(offer);
+
+			Ok(())
+		})
+	}
+
+	/// Cancel collection offer for launch token and return reserved amount and deposit to buyer.
+	///
+	/// Returns cancelled offer.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove collection offer `CollectionOffers<T>`
+	/// - One storage read-write to unreserve offer amount and deposit `Balances`
+	pub fn unchecked_cancel_collection_offer(
+		buyer: &T::AccountId,
+		launch_id: &TokenId,
+	) -> Result<CollectionOffer<T>, Error<T>> {
+		let offer = CollectionOffers::<T>::take(launch_id, buyer)
+			.ok_or(Error::<T>::CollectionOfferNotFound)?;
+
+		// return reserved amount and deposit to buyer
+		T::Currency::unreserve(buyer, offer.reserved().saturating_add(offer.deposit));
+
+		Ok(offer)
+	}
+
+	/// Sell token to collection offer of buyer for its launch token.
+	///
+	/// The offer is removed and its deposit returned to buyer once its quantity is filled.
+	///
+	/// Returns accepted offer amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update collection offer `CollectionOffers<T>`
+	/// - One storage read-write to unreserve offer deposit `Balances` if offer is filled
+	/// - Storage ops of `unchecked_transfer`
+	/// - Storage ops of `unchecked_pay_reserved_sale`
+	/// - Storage ops of `record_sale`
+	pub fn unchecked_accept_collection_offer(
+		token: &Token<T>,
+		buyer: &T::AccountId,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amount = CollectionOffers::<T>::try_mutate_exists(&token.launch_id, buyer, |offer| {
+			let standing = offer.as_mut().ok_or(Error::<T>::CollectionOfferNotFound)?;
+
+			// ensure offer has not expired
+			ensure!(!standing.is_expired(), Error::<T>::CollectionOfferExpired);
+
+			let amount = standing.amount;
+			standing.quantity = standing.quantity.saturating_sub(1);
+			if standing.quantity == 0 {
+				// return deposit of filled offer to buyer
+				T::Currency::unreserve(buyer, standing.deposit);
+				*offer = None;
+			}
+
+			Ok::<_, Error<T>>(amount)
+		})?;
+
+		// transfer token from owner to buyer
+		Self::unchecked_transfer(&token.owner, buyer, &token.id)?;
+
+		// transfer reserved amount to owner, less royalty
+		Self::unchecked_pay_reserved_sale(buyer, &token.owner, token, amount)?;
+
+		Self::record_sale(&token.launch_id, token.id, token.owner.clone(), buyer.clone(), amount)?;
+
+		Ok(amount)
+	}
+}
@@ -0,0 +1,63 @@
+use crate::{Config, Error, Event, LaunchToken, LaunchTokenIdsByContent, Pallet};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Check launch token for content minted before by its creator and add it to the content
+	/// index.
+	///
+	/// Duplicates are rejected if the runtime rejects duplicate content, otherwise they are
+	/// flagged with an event.
+	///
+	/// **Storage ops**
+	/// - One storage read per launch token with the same content `LaunchTokenIdsByContent<T>`
+	/// - One storage read per launch token with the same content to get its creator
+	///   `LaunchTokens<T>`
+	/// - Storage ops of `index_launch_content`
+	pub fn check_duplicate_content(launch_token: &LaunchToken<T>) -> Result<(), Error<T>> {
+		let content_hash = match launch_token.content_hash {
+			Some(content_hash) => content_hash,
+			None => return Ok(()),
+		};
+
+		let original = LaunchTokenIdsByContent::<T>::iter_key_prefix(content_hash).find(|id| {
+			*id != launch_token.id &&
+				Self::launch_token(id)
+					.map_or(false, |original| original.creator == launch_token.creator)
+		});
+
+		if let Some(original) = original {
+			// ensure runtime does not reject duplicate content
+			ensure!(!T::RejectDuplicateContent::get(), Error::<T>::DuplicateContent);
+
+			Self::deposit_event(Event::<T>::DuplicateContentDetected(
+				launch_token.creator.clone(),
+				launch_token.id,
+				original,
+			));
+		}
+
+		Self::index_launch_content(launch_token);
+
+		Ok(())
+	}
+
+	/// Add launch token to the content index.
+	///
+	/// **Storage ops**
+	/// - One storage write to index launch token by content `LaunchTokenIdsByContent<T>`
+	pub fn index_launch_content(launch_token: &LaunchToken<T>) {
+		if let Some(content_hash) = launch_token.content_hash {
+			LaunchTokenIdsByContent::<T>::insert(content_hash, launch_token.id, ());
+		}
+	}
+
+	/// Remove launch token from the content index.
+	///
+	/// **Storage ops**
+	/// - One storage write to remove launch token from content index `LaunchTokenIdsByContent<T>`
+	pub fn unindex_launch_content(launch_token: &LaunchToken<T>) {
+		if let Some(content_hash) = launch_token.content_hash {
+			LaunchTokenIdsByContent::<T>::remove(content_hash, launch_token.id);
+		}
+	}
+}
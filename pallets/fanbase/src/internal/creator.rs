@@ -1,19 +1,59 @@
-use crate::{Config, Creator, CreatorId, CreatorIdsForAccount, Creators, Error, Pallet};
-use frame_support::pallet_prelude::*;
+use crate::{
+	CollaboratorRole, Collaborators, Config, Creator, CreatorId, CreatorIdsForAccount,
+	CreatorOperators, Creators, Error, LaunchBeneficiaries, Pallet, RoyaltyBeneficiaries,
+	SubCreatorIdsForCreator, SUB_CREATOR_SEPARATOR,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_io::hashing::blake2_256,
+	sp_runtime::traits::{Saturating, TrailingZeroInput},
+};
 
 impl<T: Config> Pallet<T> {
 	/// Create new creator account with given id and add to account.
 	///
+	/// Creator accounts whose registration and grace period have ended are reclaimed, launch tokens
+	/// created by the creator account keep referencing it.
+	///
 	/// **Storage ops**
 	/// - One storage read to get creator by id `Creators<T>`
+	/// - One storage read-write to remove reclaimed creator id from previous owner
+	///   `CreatorIdsForAccount<T>`
+	/// - Storage ops of `clear_primary_creator` for previous owner
+	/// - One storage write to remove collaborators of reclaimed creator `Collaborators<T>`
+	/// - One storage write to remove operators of reclaimed creator `CreatorOperators<T>`
+	/// - Storage ops of `clear_proceeds_routing` for reclaimed creator
+	/// - Storage ops of `clear_succession` for reclaimed creator
+	/// - Storage ops of `clear_social_attestations` for reclaimed creator
 	/// - One storage read-write to add creator id to account `CreatorIdsForAccount<T>`
 	/// - One storage write to save creator `Creators<T>`
 	pub fn add_new_creator_to_account(
 		creator_id: CreatorId,
 		account: T::AccountId,
 	) -> Result<(), Error<T>> {
-		// verify creator account does not exist
-		ensure!(Self::creators(&creator_id).is_none(), Error::<T>::CreatorAccountTaken);
+		if let Some(creator) = Self::creator(&creator_id) {
+			// verify existing creator account can be claimed
+			ensure!(Self::is_creator_claimable(&creator), Error::<T>::CreatorAccountTaken);
+
+			// remove creator id from previous owner
+			if let Some(previous_owner) = &creator.owner {
+				CreatorIdsForAccount::<T>::mutate(previous_owner, |creator_ids| {
+					if let Some(index) = creator_ids.iter().position(|id| *id == creator_id) {
+						// `swap_remove` because we do not care about ordering and it is faster than `remove`
+						creator_ids.swap_remove(index);
+					}
+				});
+				Self::clear_primary_creator(previous_owner, &creator_id);
+			}
+
+			// collaborators, operators, proceeds routing, succession and social attestations do
+			// not carry over to the new owner
+			Collaborators::<T>::remove(&creator_id);
+			CreatorOperators::<T>::remove(&creator_id);
+			Self::clear_proceeds_routing(&creator_id);
+			Self::clear_succession(&creator_id);
+			Self::clear_social_attestations(&creator_id);
+		}
 
 		// add creator id to account
 		CreatorIdsForAccount::<T>::try_mutate(&account, |creator_ids| {
@@ -24,30 +64,81 @@ impl<T: Config> Pallet<T> {
 		})?;
 
 		// connect and save creator account
-		Creators::<T>::insert(&creator_id, Creator::new(creator_id.clone(), account));
+		let expires_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::CreatorRegistrationPeriod::get());
+		Creators::<T>::insert(&creator_id, Creator::new(creator_id.clone(), account, expires_at));
 
 		Ok(())
 	}
 
+	/// Create new sub-creator under parent creator account.
+	///
+	/// *Unchecked!*
+	///
+	/// Returns the sub-creator id.
+	///
+	/// **Storage ops**
+	/// - One storage read to get sub-creator by id `Creators<T>`
+	/// - One storage read-write to add sub-creator id to parent `SubCreatorIdsForCreator<T>`
+	/// - One storage write to save sub-creator `Creators<T>`
+	pub fn unchecked_add_sub_creator(
+		parent: &Creator<T>,
+		name: &CreatorId,
+	) -> Result<CreatorId, Error<T>> {
+		// build sub-creator id as `parent/name`
+		let mut sub_creator_id = parent.id.to_vec();
+		sub_creator_id.push(SUB_CREATOR_SEPARATOR);
+		sub_creator_id.extend_from_slice(name);
+		let sub_creator_id =
+			CreatorId::try_from(sub_creator_id).map_err(|_| Error::<T>::CreatorIdTooLong)?;
+
+		// verify sub-creator does not exist
+		ensure!(Self::creator(&sub_creator_id).is_none(), Error::<T>::CreatorAccountTaken);
+
+		// add sub-creator id to parent
+		SubCreatorIdsForCreator::<T>::try_mutate(&parent.id, |sub_creator_ids| {
+			sub_creator_ids
+				.try_push(sub_creator_id.clone())
+				.map_err(|_| Error::<T>::MaxSubCreatorsReached)
+		})?;
+
+		// save sub-creator, registration follows the parent
+		Creators::<T>::insert(
+			&sub_creator_id,
+			Creator::new_sub(sub_creator_id.clone(), parent.id.clone(), parent.expires_at),
+		);
+
+		Ok(sub_creator_id)
+	}
+
 	/// Remove creator account with given id from account.
 	///
-	/// Remove permanently if there are no token references to it.
+	/// Remove permanently if there are no token or sub-creator references to it.
 	///
 	/// **Storage ops**
 	/// - One storage read to get creator by id `Creators<T>`
 	/// - One storage read to get launch tokens ids for creator `LaunchTokenIdsForCreator<T>`
+	/// - One storage read to get sub-creator ids for creator `SubCreatorIdsForCreator<T>`
 	/// - One storage write to either disconnect or remove creator `Creators<T>`
+	/// - One storage write to remove collaborators of creator `Collaborators<T>`
+	/// - One storage write to remove operators of creator `CreatorOperators<T>`
+	/// - Storage ops of `clear_proceeds_routing`
+	/// - Storage ops of `clear_succession`
+	/// - Storage ops of `clear_social_attestations`
 	/// - One storage read-write to remove creator id from account `CreatorIdsForAccount<T>`
+	/// - Storage ops of `clear_primary_creator`
 	pub fn remove_creator_from_account(
 		creator_id: CreatorId,
 		account: T::AccountId,
 	) -> Result<(), Error<T>> {
-		// verify account owns creator account
-		Self::ensure_account_owns_creator(&account, &creator_id)?;
+		// verify account owns creator account, expired creator accounts can still be dropped
+		Self::ensure_account_holds_creator(&account, &creator_id)?;
 
-		// remove if no token references to this creator
-		if Self::launch_token_ids_for_creator(&creator_id).len() == 0 {
-			// remove since no launch tokens created by this creator
+		// remove if no token or sub-creator references to this creator
+		if Self::launch_token_ids_for_creator(&creator_id).len() == 0 &&
+			Self::sub_creator_ids_for_creator(&creator_id).len() == 0
+		{
+			// remove since no launch tokens or sub-creators created by this creator
 			Creators::<T>::remove(&creator_id);
 		} else {
 			// disconnect owner from creator
@@ -57,6 +148,14 @@ impl<T: Config> Pallet<T> {
 			})
 		}
 
+		// collaborators, operators, proceeds routing, succession and social attestations do not
+		// outlive the owner
+		Collaborators::<T>::remove(&creator_id);
+		CreatorOperators::<T>::remove(&creator_id);
+		Self::clear_proceeds_routing(&creator_id);
+		Self::clear_succession(&creator_id);
+		Self::clear_social_attestations(&creator_id);
+
 		// remove creator id from account
 		CreatorIdsForAccount::<T>::mutate(&account, |creator_ids| {
 			if let Some(index) = creator_ids.iter().position(|id| *id == creator_id) {
@@ -64,24 +163,305 @@ impl<T: Config> Pallet<T> {
 				creator_ids.swap_remove(index);
 			}
 		});
+		Self::clear_primary_creator(&account, &creator_id);
 
 		Ok(())
 	}
 
-	/// Ensure account owns creator account.
+	/// Ensure account owns creator account, sub-creators are owned by the owner of their parent.
 	///
 	/// **Storage ops**
-	/// - One storage read to get creator by id `Creators<T>`
+	/// - Two storage reads to resolve creator by id `Creators<T>`
 	pub fn ensure_account_owns_creator(
 		account: &T::AccountId,
 		creator_id: &CreatorId,
 	) -> Result<(), Error<T>> {
+		let creator = Self::resolve_creator(creator_id).ok_or(Error::<T>::NotOwner)?;
+
+		ensure!(creator.owner.as_ref() == Some(account), Error::<T>::NotOwner);
+
+		// verify creator registration has not expired
+		ensure!(!Self::is_creator_expired(&creator), Error::<T>::CreatorExpired);
+
+		Ok(())
+	}
+
+	/// Ensure account directly owns creator account regardless of its registration expiry.
+	///
+	/// Sub-creators are never held directly.
+	///
+	/// Returns the creator account.
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator by id `Creators<T>`
+	pub fn ensure_account_holds_creator(
+		account: &T::AccountId,
+		creator_id: &CreatorId,
+	) -> Result<Creator<T>, Error<T>> {
+		let creator = Self::creator(creator_id).ok_or(Error::<T>::NotOwner)?;
+
+		ensure!(creator.owner.as_ref() == Some(account), Error::<T>::NotOwner);
+
+		Ok(creator)
+	}
+
+	/// Extend creator registration by the registration period.
+	///
+	/// Registrations are extended from their expiry, or from the current block if already expired.
+	///
+	/// Returns the new expiry.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator expiry `Creators<T>`
+	pub fn unchecked_renew_creator(creator_id: &CreatorId) -> Result<T::BlockNumber, Error<T>> {
+		Creators::<T>::try_mutate(creator_id, |creator| {
+			// check if creator exists
+			let creator = creator.as_mut().ok_or(Error::<T>::CreatorNotFound)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			creator.expires_at =
+				creator.expires_at.max(now).saturating_add(T::CreatorRegistrationPeriod::get());
+
+			Ok(creator.expires_at)
+		})
+	}
+
+	/// Ensure creator id is valid for a top-level or sub-creator name.
+	pub fn ensure_valid_creator_id(creator_id: &CreatorId) -> Result<(), Error<T>> {
+		ensure!(
+			!creator_id.is_empty() && !creator_id.contains(&SUB_CREATOR_SEPARATOR),
+			Error::<T>::InvalidCreatorId
+		);
+
+		Ok(())
+	}
+
+	/// Get creator account holding ownership of creator id, sub-creators resolve to their parent.
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator by id `Creators<T>`
+	/// - One storage read to get parent of sub-creator `Creators<T>`
+	pub fn resolve_creator(creator_id: &CreatorId) -> Option<Creator<T>> {
+		let creator = Self::creator(creator_id)?;
+
+		match &creator.parent {
+			Some(parent_id) => Self::creator(parent_id),
+			None => Some(creator),
+		}
+	}
+
+	/// Returns `true` if creator registration has expired.
+	pub fn is_creator_expired(creator: &Creator<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >= creator.expires_at
+	}
+
+	/// Returns `true` if creator registration and grace period have ended and the creator id can
+	/// be claimed by another account.
+	pub fn is_creator_claimable(creator: &Creator<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >=
+			creator.expires_at.saturating_add(T::CreatorGracePeriod::get())
+	}
+
+	/// Ensure account owns creator account or is one of its collaborators or unexpired operators.
+	///
+	/// **Storage ops**
+	/// - Two storage reads to resolve creator by id `Creators<T>`
+	/// - One storage read to get collaborators of creator `Collaborators<T>`
+	/// - Storage ops of `is_creator_operator`
+	pub fn ensure_account_manages_creator(
+		account: &T::AccountId,
+		creator_id: &CreatorId,
+	) -> Result<(), Error<T>> {
+		let creator = Self::resolve_creator(creator_id).ok_or(Error::<T>::NotOwner)?;
+
+		// verify creator registration has not expired
+		ensure!(!Self::is_creator_expired(&creator), Error::<T>::CreatorExpired);
+
+		if creator.owner.as_ref() == Some(account) {
+			return Ok(())
+		}
+
 		ensure!(
-			Self::creators(creator_id)
-				.map_or(false, |creator| creator.owner == Some(account.clone())),
+			Self::get_collaborator_role(creator_id, account).is_some() ||
+				Self::is_creator_operator(creator_id, account),
 			Error::<T>::NotOwner
 		);
 
 		Ok(())
 	}
+
+	/// Ensure account is an admin collaborator of a creator account owned by its keyless account.
+	///
+	/// Returns the keyless creator account.
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator by id `Creators<T>`
+	/// - One storage read to get collaborators of creator `Collaborators<T>`
+	pub fn ensure_keyless_creator_admin(
+		account: &T::AccountId,
+		creator_id: &CreatorId,
+	) -> Result<T::AccountId, Error<T>> {
+		let keyless_account = Self::keyless_creator_account(creator_id);
+
+		// verify keyless account owns creator account, expired registrations can still be renewed
+		Self::ensure_account_holds_creator(&keyless_account, creator_id)
+			.map_err(|_| Error::<T>::CreatorNotKeyless)?;
+
+		ensure!(
+			Self::get_collaborator_role(creator_id, account) == Some(CollaboratorRole::Admin),
+			Error::<T>::NotCollaborator
+		);
+
+		Ok(keyless_account)
+	}
+
+	/// Add collaborator to creator account or update role of existing collaborator.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update collaborators of creator `Collaborators<T>`
+	pub fn unchecked_add_collaborator(
+		creator_id: &CreatorId,
+		collaborator: &T::AccountId,
+		role: CollaboratorRole,
+	) -> Result<(), Error<T>> {
+		Collaborators::<T>::try_mutate(creator_id, |collaborators| {
+			match collaborators.iter_mut().find(|(account, _)| account == collaborator) {
+				// update role of existing collaborator
+				Some((_, existing_role)) => {
+					*existing_role = role;
+					Ok(())
+				},
+				None => collaborators
+					.try_push((collaborator.clone(), role))
+					.map_err(|_| Error::<T>::MaxCollaboratorsReached),
+			}
+		})
+	}
+
+	/// Remove collaborator from creator account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update collaborators of creator `Collaborators<T>`
+	pub fn unchecked_remove_collaborator(
+		creator_id: &CreatorId,
+		collaborator: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		Collaborators::<T>::try_mutate(creator_id, |collaborators| {
+			let index = collaborators
+				.iter()
+				.position(|(account, _)| account == collaborator)
+				.ok_or(Error::<T>::NotCollaborator)?;
+
+			// `swap_remove` because we do not care about ordering and it is faster than `remove`
+			collaborators.swap_remove(index);
+
+			Ok(())
+		})
+	}
+
+	/// Move creator account to new owner.
+	///
+	/// Launch token beneficiaries, the default beneficiary, the succession and the social
+	/// attestations of the previous owner are cleared.
+	///
+	/// Returns the previous owner if creator account was connected.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator owner `Creators<T>`
+	/// - Storage ops of `clear_proceeds_routing`
+	/// - One storage write to clear operators of creator `CreatorOperators<T>`
+	/// - One storage read-write to add creator id to new owner `CreatorIdsForAccount<T>`
+	/// - One storage read-write to remove creator id from previous owner `CreatorIdsForAccount<T>`
+	/// - Storage ops of `clear_primary_creator` for previous owner
+	/// - Storage ops of `clear_succession`
+	/// - Storage ops of `clear_social_attestations`
+	pub fn unchecked_set_creator_owner(
+		creator_id: &CreatorId,
+		new_owner: T::AccountId,
+	) -> Result<Option<T::AccountId>, Error<T>> {
+		Creators::<T>::try_mutate(creator_id, |creator| {
+			// check if creator exists
+			let creator = creator.as_mut().ok_or(Error::<T>::CreatorNotFound)?;
+
+			// sub-creators are owned through their parent
+			ensure!(!creator.is_sub_creator(), Error::<T>::IsSubCreator);
+
+			// add creator id to new owner
+			CreatorIdsForAccount::<T>::try_mutate(&new_owner, |creator_ids| {
+				creator_ids
+					.try_push(creator_id.clone())
+					.map_err(|_| Error::<T>::MaxCreatorAccountsReached)
+			})?;
+
+			// remove creator id from previous owner
+			if let Some(previous_owner) = &creator.owner {
+				CreatorIdsForAccount::<T>::mutate(previous_owner, |creator_ids| {
+					if let Some(index) = creator_ids.iter().position(|id| id == creator_id) {
+						// `swap_remove` because we do not care about ordering and it is faster than `remove`
+						creator_ids.swap_remove(index);
+					}
+				});
+				Self::clear_primary_creator(previous_owner, creator_id);
+			}
+
+			// proceeds routing, succession and social attestations do not carry over to the new
+			// owner
+			Self::clear_proceeds_routing(creator_id);
+			Self::clear_succession(creator_id);
+			Self::clear_social_attestations(creator_id);
+
+			// operator keys belong to the previous owner
+			CreatorOperators::<T>::remove(creator_id);
+
+			Ok(creator.owner.replace(new_owner))
+		})
+	}
+
+	/// Clear launch token beneficiaries, royalty beneficiaries and the default beneficiary of
+	/// creator account, proceeds routing set by an owner does not carry over to the next owner.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token ids for creator `LaunchTokenIdsForCreator<T>`
+	/// - One storage write per launch token to clear beneficiary `LaunchBeneficiaries<T>`
+	/// - One storage write per launch token to clear royalty beneficiary `RoyaltyBeneficiaries<T>`
+	/// - Storage ops of `clear_default_beneficiary`
+	pub fn clear_proceeds_routing(creator_id: &CreatorId) {
+		for launch_token_id in Self::launch_token_ids_for_creator(creator_id) {
+			LaunchBeneficiaries::<T>::remove(launch_token_id);
+			RoyaltyBeneficiaries::<T>::remove(launch_token_id);
+		}
+		Self::clear_default_beneficiary(creator_id);
+	}
+
+	/// Get role of collaborator on creator account.
+	///
+	/// **Storage ops**
+	/// - One storage read to get collaborators of creator `Collaborators<T>`
+	pub fn get_collaborator_role(
+		creator_id: &CreatorId,
+		account: &T::AccountId,
+	) -> Option<CollaboratorRole> {
+		Self::collaborators(creator_id)
+			.into_iter()
+			.find(|(collaborator, _)| collaborator == account)
+			.map(|(_, role)| role)
+	}
+
+	/// Get keyless account derived from creator id.
+	///
+	/// The account is derived from a hash of the full creator id, so creator ids sharing a prefix
+	/// never resolve to the same account.
+	pub fn keyless_creator_account(creator_id: &CreatorId) -> T::AccountId {
+		let entropy = (b"fanbase/keyless", creator_id).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
 }
@@ -0,0 +1,30 @@
+use crate::{Config, Error, ListingRequests, Pallet, Token};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Ensure token of curated launch token has listing approved by the creator for its owner and
+	/// consume the approval.
+	///
+	/// Tokens of launch tokens that are not curated need no approval.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read-write to consume listing request `ListingRequests<T>`
+	pub fn consume_listing_approval(token: &Token<T>) -> Result<(), Error<T>> {
+		let curated =
+			Self::launch_token(&token.launch_id).map_or(false, |launch_token| launch_token.curated);
+		if !curated {
+			return Ok(())
+		}
+
+		let request =
+			Self::listing_request(&token.id).ok_or(Error::<T>::ListingApprovalRequired)?;
+		ensure!(
+			request.approved && request.owner == token.owner,
+			Error::<T>::ListingApprovalRequired
+		);
+		ListingRequests::<T>::remove(&token.id);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,24 @@
+use crate::{Config, CreatorId, Error, Pallet, TokenId};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Ensure creator account owns launch token or manages it by delegation.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read to get launch token delegate `LaunchDelegates<T>`
+	pub fn ensure_creator_operates_launch_token(
+		creator_id: &CreatorId,
+		launch_token_id: &TokenId,
+	) -> Result<(), Error<T>> {
+		if Self::ensure_creator_owns_launch_token(creator_id, launch_token_id).is_ok() {
+			return Ok(())
+		}
+		ensure!(
+			Self::launch_delegate(launch_token_id).as_ref() == Some(creator_id),
+			Error::<T>::NotOwner
+		);
+
+		Ok(())
+	}
+}
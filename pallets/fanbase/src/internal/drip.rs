@@ -0,0 +1,22 @@
+use crate::{Config, LaunchTokenState, Pallet, TokenId, TokenSupply};
+use frame_support::sp_runtime::traits::Saturating;
+
+impl<T: Config> Pallet<T> {
+	/// Get purchasable supply of launch token unlocked by its drip schedule and not yet bought.
+	///
+	/// Launch tokens without a drip schedule are not limited.
+	///
+	/// **Storage ops**
+	/// - One storage read to get drip schedule of launch token `DripSchedules<T>`
+	pub fn get_drip_available(
+		launch_token_id: &TokenId,
+		launch_token_state: &LaunchTokenState,
+	) -> TokenSupply {
+		Self::drip_schedule(launch_token_id).map_or(TokenSupply::MAX, |schedule| {
+			let bought = launch_token_state.issued.saturating_sub(launch_token_state.gifted);
+			schedule
+				.unlocked(frame_system::Pallet::<T>::block_number())
+				.saturating_sub(bought)
+		})
+	}
+}
@@ -0,0 +1,107 @@
+use crate::{
+	Auctions, BalanceOf, BridgeCustodies, Config, Error, Pallet, ProtectedSale, ProtectedSales,
+	RetiredTokens, TokenId,
+};
+use frame_support::{
+	pallet_prelude::*, sp_runtime::traits::Saturating, traits::ReservableCurrency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Reserve price from buyer and hold token sale in escrow until the dispute period ends.
+	///
+	/// Token is unlisted and locked while in escrow.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to reserve funds from buyer `Balances`
+	/// - One storage read-write to unlist token `Tokens<T>`
+	/// - One storage write to save protected sale `ProtectedSales<T>`
+	pub fn unchecked_open_protected_sale(
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		token_id: &TokenId,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		// reserve funds from buyer
+		T::Currency::reserve(&buyer, price).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		// unlist token
+		Self::unchecked_set_price(token_id, None)?;
+
+		// save protected sale
+		let dispute_ends =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::DisputePeriod::get());
+		ProtectedSales::<T>::insert(token_id, ProtectedSale { seller, buyer, price, dispute_ends });
+
+		Ok(())
+	}
+
+	/// Complete protected sale by transferring token to buyer and reserved funds to seller.
+	///
+	/// Returns completed protected sale.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove protected sale `ProtectedSales<T>`
+	/// - One storage read to get token `Tokens<T>`
+	/// - Storage ops of `unchecked_transfer`
+	/// - Storage ops of `unchecked_pay_reserved_sale`
+	pub fn unchecked_finalize_protected_sale(
+		token_id: &TokenId,
+	) -> Result<ProtectedSale<T>, Error<T>> {
+		let sale = ProtectedSales::<T>::take(token_id).ok_or(Error::<T>::ProtectedSaleNotFound)?;
+
+		let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+		// transfer token to buyer
+		Self::unchecked_transfer(&sale.seller, &sale.buyer, token_id)?;
+
+		// transfer reserved funds to seller, less royalty
+		Self::unchecked_pay_reserved_sale(&sale.buyer, &sale.seller, &token, sale.price)?;
+
+		Ok(sale)
+	}
+
+	/// Cancel protected sale and return reserved funds to buyer, token remains with seller.
+	///
+	/// Returns cancelled protected sale.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove protected sale `ProtectedSales<T>`
+	/// - One storage read-write to unreserve buyer funds `Balances`
+	pub fn unchecked_reverse_protected_sale(
+		token_id: &TokenId,
+	) -> Result<ProtectedSale<T>, Error<T>> {
+		let sale = ProtectedSales::<T>::take(token_id).ok_or(Error::<T>::ProtectedSaleNotFound)?;
+
+		// return reserved funds to buyer
+		T::Currency::unreserve(&sale.buyer, sale.price);
+
+		Ok(sale)
+	}
+
+	/// Ensure token is not locked in escrow, auction or bridge custody and is not retired.
+	///
+	/// **Storage ops**
+	/// - One storage read to check protected sale for token `ProtectedSales<T>`
+	/// - One storage read to check auction for token `Auctions<T>`
+	/// - One storage read to check bridge custody for token `BridgeCustodies<T>`
+	/// - One storage read to check if token is retired `RetiredTokens<T>`
+	pub fn ensure_token_unlocked(token_id: &TokenId) -> Result<(), Error<T>> {
+		ensure!(!ProtectedSales::<T>::contains_key(token_id), Error::<T>::TokenLocked);
+		ensure!(!Auctions::<T>::contains_key(token_id), Error::<T>::TokenLocked);
+		ensure!(!BridgeCustodies::<T>::contains_key(token_id), Error::<T>::TokenLocked);
+		ensure!(!RetiredTokens::<T>::contains_key(token_id), Error::<T>::TokenRetired);
+
+		Ok(())
+	}
+
+	/// Returns `true` if the dispute period of protected sale has ended.
+	pub fn is_dispute_period_over(sale: &ProtectedSale<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >= sale.dispute_ends
+	}
+}
@@ -0,0 +1,19 @@
+use crate::{Config, Error, Pallet, TokenId};
+use frame_support::{pallet_prelude::*, sp_std::prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// Ensure new order contains exactly the currently featured launch tokens.
+	///
+	/// **Storage ops**
+	/// - One storage read to get featured launch tokens `FeaturedLaunches<T>`
+	pub fn ensure_valid_featured_order(order: &[TokenId]) -> Result<(), Error<T>> {
+		let mut featured = Self::featured_launches().into_inner();
+		let mut order = order.to_vec();
+		featured.sort_unstable();
+		order.sort_unstable();
+
+		ensure!(featured == order, Error::<T>::InvalidFeaturedOrder);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,153 @@
+use crate::{
+	Config, Creator, CreatorIdsForAccount, Creators, Error, IssuanceNonce, LaunchIssuanceNonce,
+	LaunchToken, LaunchTokenIdsForCreator, LaunchTokenState, LaunchTokenStates, LaunchTokens,
+	LaunchTokensByState, Pallet, SubCreatorIdsForCreator, Token, TokenIdsForAccount, Tokens,
+};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Save creator as given, replacing any creator with the same id.
+	///
+	/// Owner and parent references are kept in sync, expiry and other fields are not checked.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get replaced creator `Creators<T>`
+	/// - One storage read-write to remove creator id from previous owner `CreatorIdsForAccount<T>`
+	/// - One storage read-write to add creator id to owner `CreatorIdsForAccount<T>`
+	/// - One storage read-write to add sub-creator id to parent `SubCreatorIdsForCreator<T>`
+	/// - One storage write to save creator `Creators<T>`
+	pub fn unchecked_force_set_creator(creator: Creator<T>) -> Result<(), Error<T>> {
+		// remove creator id from previous owner
+		if let Some(previous_owner) = Self::creator(&creator.id).and_then(|previous| previous.owner)
+		{
+			CreatorIdsForAccount::<T>::mutate(&previous_owner, |creator_ids| {
+				creator_ids.retain(|id| *id != creator.id);
+			});
+		}
+
+		// add creator id to owner
+		if let Some(owner) = &creator.owner {
+			CreatorIdsForAccount::<T>::try_mutate(owner, |creator_ids| {
+				creator_ids
+					.try_push(creator.id.clone())
+					.map_err(|_| Error::<T>::MaxCreatorAccountsReached)
+			})?;
+		}
+
+		// add sub-creator id to parent
+		if let Some(parent) = &creator.parent {
+			SubCreatorIdsForCreator::<T>::try_mutate(parent, |sub_creator_ids| {
+				if sub_creator_ids.contains(&creator.id) {
+					return Ok(())
+				}
+				sub_creator_ids
+					.try_push(creator.id.clone())
+					.map_err(|_| Error::<T>::MaxSubCreatorsReached)
+			})?;
+		}
+
+		Creators::<T>::insert(&creator.id.clone(), creator);
+
+		Ok(())
+	}
+
+	/// Save launch token and its state as given, replacing any launch token with the same id.
+	///
+	/// Creator references and the state index are kept in sync and the launch issuance nonce is
+	/// advanced past the launch token id.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator by id `Creators<T>`
+	/// - One storage read to get replaced launch token `LaunchTokens<T>`
+	/// - One storage read to get replaced launch token state `LaunchTokenStates<T>`
+	/// - One storage read-write to remove launch token id from previous creator
+	///   `LaunchTokenIdsForCreator<T>`
+	/// - One storage write to unindex previous state `LaunchTokensByState<T>`
+	/// - Storage ops of `unindex_launch_name` and `unindex_launch_content`
+	/// - One storage read-write to add launch token id to creator `LaunchTokenIdsForCreator<T>`
+	/// - Storage ops of `index_launch_state`, `index_launch_name` and `index_launch_content`
+	/// - One storage write to save launch token `LaunchTokens<T>`
+	/// - One storage write to save launch token state `LaunchTokenStates<T>`
+	/// - One storage read-write to update launch token issuance `LaunchIssuanceNonce<T>`
+	pub fn unchecked_force_set_launch_token(
+		launch_token: LaunchToken<T>,
+		launch_token_state: LaunchTokenState,
+	) -> Result<(), Error<T>> {
+		// ensure creator account exists
+		ensure!(Self::creator(&launch_token.creator).is_some(), Error::<T>::CreatorNotFound);
+
+		// remove replaced launch token from creator and state index
+		if let Some(previous) = Self::launch_token(&launch_token.id) {
+			LaunchTokenIdsForCreator::<T>::mutate(&previous.creator, |launch_token_ids| {
+				launch_token_ids.retain(|id| *id != previous.id);
+			});
+			if let Some(previous_state) = Self::launch_token_state(&previous.id) {
+				LaunchTokensByState::<T>::remove(previous_state.state, previous.id);
+			}
+			Self::unindex_launch_name(&previous);
+			Self::unindex_launch_content(&previous);
+		}
+
+		// add launch token id to creator
+		LaunchTokenIdsForCreator::<T>::try_mutate(&launch_token.creator, |launch_token_ids| {
+			launch_token_ids
+				.try_push(launch_token.id)
+				.map_err(|_| Error::<T>::MaxLaunchTokensReached)
+		})?;
+
+		Self::index_launch_state(&launch_token.id, &launch_token_state);
+		Self::index_launch_name(&launch_token);
+		Self::index_launch_content(&launch_token);
+		LaunchIssuanceNonce::<T>::mutate(|nonce| *nonce = (*nonce).max(launch_token.id));
+		LaunchTokenStates::<T>::insert(&launch_token.id, launch_token_state);
+		LaunchTokens::<T>::insert(&launch_token.id.clone(), launch_token);
+
+		Ok(())
+	}
+
+	/// Save token as given, replacing any token with the same id.
+	///
+	/// Owner references, holder counts and the listing index are kept in sync and the issuance
+	/// nonce is advanced past the token id. Launch token supply counters are not changed.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read to get replaced token `Tokens<T>`
+	/// - One storage read-write to remove token id from previous owner `TokenIdsForAccount<T>`
+	/// - Storage ops of `track_released` and `unindex_listing`
+	/// - One storage read-write to add token id to owner `TokenIdsForAccount<T>`
+	/// - Storage ops of `track_acquired` and `index_listing`
+	/// - One storage write to save token `Tokens<T>`
+	/// - One storage read-write to update token issuance `IssuanceNonce<T>`
+	pub fn unchecked_force_set_token(token: Token<T>) -> Result<(), Error<T>> {
+		// ensure launch token exists
+		ensure!(Self::launch_token(&token.launch_id).is_some(), Error::<T>::TokenNotFound);
+
+		// remove replaced token from owner and listing index
+		if let Some(previous) = Self::token(&token.id) {
+			TokenIdsForAccount::<T>::mutate(&previous.owner, |token_ids| {
+				token_ids.retain(|id| *id != previous.id);
+			});
+			Self::track_released(&previous.launch_id, &previous.owner, &previous.id);
+			Self::unindex_listing(&previous);
+		}
+
+		// add token id to owner
+		TokenIdsForAccount::<T>::try_mutate(&token.owner, |token_ids| {
+			token_ids.try_push(token.id).map_err(|_| Error::<T>::MaxTokensReached)
+		})?;
+		Self::track_acquired(&token.launch_id, &token.owner, &token.id);
+
+		Self::index_listing(&token);
+		IssuanceNonce::<T>::mutate(|nonce| *nonce = (*nonce).max(token.id));
+		Tokens::<T>::insert(&token.id.clone(), token);
+
+		Ok(())
+	}
+}
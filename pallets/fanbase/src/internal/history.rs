@@ -0,0 +1,42 @@
+use super::MAX_PAGE_SIZE;
+use crate::{BalanceOf, Config, Error, Pallet, Sale, SaleCount, SaleOf, Sales, TokenId};
+use frame_support::{sp_runtime::traits::Saturating, sp_std::prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// Record completed sale of token minted from launch token.
+	///
+	/// **Storage ops**
+	/// - Storage ops of `check_wash_trade`
+	/// - One storage read-write to increment sale count of launch token `SaleCount<T>`
+	/// - One storage write to save sale `Sales<T>`
+	pub fn record_sale(
+		launch_id: &TokenId,
+		token_id: TokenId,
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		// flag or reject wash trades
+		Self::check_wash_trade(token_id, &seller, &buyer)?;
+
+		let index = SaleCount::<T>::mutate(launch_id, |count| {
+			let index = *count;
+			*count = count.saturating_add(1);
+			index
+		});
+
+		let block = frame_system::Pallet::<T>::block_number();
+		Sales::<T>::insert(launch_id, index, Sale { token_id, seller, buyer, price, block });
+
+		Ok(())
+	}
+
+	/// Get page of sales of tokens minted from launch token, oldest first.
+	///
+	/// Pages are limited to `MAX_PAGE_SIZE` entries.
+	pub fn sales_of(launch_id: TokenId, offset: u32, limit: u32) -> Vec<SaleOf<T>> {
+		let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(Self::sale_count(launch_id));
+
+		(offset..end).filter_map(|index| Self::sale(launch_id, index)).collect()
+	}
+}
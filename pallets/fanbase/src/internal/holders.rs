@@ -0,0 +1,95 @@
+use super::MAX_PAGE_SIZE;
+use crate::{
+	Config, HeldTokens, Pallet, TokenId, TokenIdsForAccountByLaunch, TokensOwned, UniqueHolders,
+};
+use frame_support::{sp_runtime::traits::Saturating, sp_std::prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// Count token of launch token acquired by account.
+	///
+	/// Accounts acquiring their first token of the launch token are counted as new holders.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update tokens held by account `HeldTokens<T>`
+	/// - One storage read-write to update unique holders if account is a new holder
+	///   `UniqueHolders<T>`
+	/// - One storage write to index token id under launch token `TokenIdsForAccountByLaunch<T>`
+	/// - One storage write to index token ownership `TokensOwned<T>`
+	pub fn track_acquired(launch_id: &TokenId, account: &T::AccountId, token_id: &TokenId) {
+		let held = HeldTokens::<T>::mutate(launch_id, account, |held| {
+			*held = held.saturating_add(1);
+			*held
+		});
+
+		if held == 1 {
+			UniqueHolders::<T>::mutate(launch_id, |holders| *holders = holders.saturating_add(1));
+		}
+
+		TokenIdsForAccountByLaunch::<T>::insert((account, launch_id, token_id), ());
+		TokensOwned::<T>::insert(account, token_id, ());
+	}
+
+	/// Count token of launch token released by account.
+	///
+	/// Accounts releasing their last token of the launch token are no longer counted as holders.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update tokens held by account `HeldTokens<T>`
+	/// - One storage read-write to update unique holders if account is no longer a holder
+	///   `UniqueHolders<T>`
+	/// - One storage write to remove token id from launch token index
+	///   `TokenIdsForAccountByLaunch<T>`
+	/// - One storage write to remove token ownership `TokensOwned<T>`
+	pub fn track_released(launch_id: &TokenId, account: &T::AccountId, token_id: &TokenId) {
+		let held = HeldTokens::<T>::get(launch_id, account).saturating_sub(1);
+
+		if held == 0 {
+			HeldTokens::<T>::remove(launch_id, account);
+			UniqueHolders::<T>::mutate(launch_id, |holders| *holders = holders.saturating_sub(1));
+		} else {
+			HeldTokens::<T>::insert(launch_id, account, held);
+		}
+
+		TokenIdsForAccountByLaunch::<T>::remove((account, launch_id, token_id));
+		TokensOwned::<T>::remove(account, token_id);
+	}
+
+	/// Get up to `limit` token ids of launch token owned by account.
+	///
+	/// **Storage ops**
+	/// - One storage read per token id `TokenIdsForAccountByLaunch<T>`
+	pub fn token_ids_of_launch_for_account(
+		account: &T::AccountId,
+		launch_id: &TokenId,
+		limit: usize,
+	) -> Vec<TokenId> {
+		TokenIdsForAccountByLaunch::<T>::iter_key_prefix((account, launch_id))
+			.take(limit)
+			.collect()
+	}
+
+	/// Get page of token ids owned by account in stable order, starting after `cursor`.
+	///
+	/// Returns the cursor of the next page if the page is full. Pages are limited to
+	/// `MAX_PAGE_SIZE` entries.
+	pub fn tokens_of_paged(
+		account: &T::AccountId,
+		cursor: Option<TokenId>,
+		limit: u32,
+	) -> (Vec<TokenId>, Option<TokenId>) {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let token_ids: Vec<TokenId> = match cursor {
+			Some(cursor) => TokensOwned::<T>::iter_key_prefix_from(
+				account,
+				TokensOwned::<T>::hashed_key_for(account, cursor),
+			)
+			.take(limit)
+			.collect(),
+			None => TokensOwned::<T>::iter_key_prefix(account).take(limit).collect(),
+		};
+		let next =
+			if limit > 0 && token_ids.len() == limit { token_ids.last().copied() } else { None };
+
+		(token_ids, next)
+	}
+}
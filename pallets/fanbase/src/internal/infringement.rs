@@ -0,0 +1,77 @@
+use crate::{
+	types::LaunchState, BalanceOf, Config, Error, InfringementClaim, InfringementClaims,
+	LaunchTokenStates, Pallet, TokenId,
+};
+use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+
+impl<T: Config> Pallet<T> {
+	/// File infringement claim against launch token and reserve deposit from claimant.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to check open claim of launch token `InfringementClaims<T>`
+	/// - One storage read-write to reserve deposit `Balances`
+	/// - One storage write to save claim `InfringementClaims<T>`
+	pub fn unchecked_claim_infringement(
+		claimant: &T::AccountId,
+		launch_token_id: &TokenId,
+		evidence_hash: T::Hash,
+		deposit: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		// ensure launch token has no open claim
+		ensure!(
+			!InfringementClaims::<T>::contains_key(launch_token_id),
+			Error::<T>::InfringementClaimExists
+		);
+
+		T::Currency::reserve(claimant, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		InfringementClaims::<T>::insert(
+			launch_token_id,
+			InfringementClaim { claimant: claimant.clone(), evidence_hash, deposit },
+		);
+
+		Ok(())
+	}
+
+	/// Resolve infringement claim against launch token.
+	///
+	/// Upheld claims freeze the launch token and return the deposit, dismissed claims slash the
+	/// deposit.
+	///
+	/// Returns the resolved claim.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove claim `InfringementClaims<T>`
+	/// - One storage read-write to unreserve or slash deposit `Balances`
+	/// - One storage read-write to freeze launch token if upheld `LaunchTokenStates<T>`
+	/// - Storage ops of `unchecked_set_launch_state` if upheld
+	pub fn unchecked_resolve_infringement(
+		launch_token_id: &TokenId,
+		upheld: bool,
+	) -> Result<InfringementClaim<T>, Error<T>> {
+		let claim = InfringementClaims::<T>::take(launch_token_id)
+			.ok_or(Error::<T>::InfringementClaimNotFound)?;
+
+		if upheld {
+			T::Currency::unreserve(&claim.claimant, claim.deposit);
+
+			LaunchTokenStates::<T>::mutate(launch_token_id, |launch_token_state| {
+				if let Some(launch_token_state) = launch_token_state {
+					Self::unchecked_set_launch_state(
+						launch_token_id,
+						launch_token_state,
+						LaunchState::Frozen,
+					);
+				}
+			});
+		} else {
+			let _ = T::Currency::slash_reserved(&claim.claimant, claim.deposit);
+		}
+
+		Ok(claim)
+	}
+}
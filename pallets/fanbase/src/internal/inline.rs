@@ -0,0 +1,45 @@
+use crate::{BalanceOf, Config, Error, InlineContent, InlineContents, InlineData, Pallet, TokenId};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ReservableCurrency},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Replace on-chain content of launch token, reserving deposit from depositor.
+	///
+	/// Deposit of the replaced content is returned to its depositor.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update content of launch token `InlineContents<T>`
+	/// - One storage read-write to return replaced deposit `Balances`
+	/// - One storage read-write to reserve deposit from depositor `Balances`
+	pub fn unchecked_set_inline_data(
+		depositor: &T::AccountId,
+		launch_token_id: &TokenId,
+		data: Option<InlineData>,
+	) -> Result<(), Error<T>> {
+		InlineContents::<T>::try_mutate_exists(launch_token_id, |content| {
+			// return deposit of replaced content
+			if let Some(previous) = content.take() {
+				T::Currency::unreserve(&previous.depositor, previous.deposit);
+			}
+
+			if let Some(data) = data {
+				let deposit = Self::inline_data_deposit(data.len());
+				T::Currency::reserve(depositor, deposit)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+				*content = Some(InlineContent { data, depositor: depositor.clone(), deposit });
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Deposit required to store content of given length on-chain.
+	pub fn inline_data_deposit(len: usize) -> BalanceOf<T> {
+		T::InlineDataDepositPerByte::get().saturating_mul((len as u32).into())
+	}
+}
@@ -0,0 +1,153 @@
+use crate::{
+	traits::EnsureTransferAllowed, weights, BalanceOf, Config, CreatorId, Error, Event, Pallet,
+	PurchaseIntent, PurchaseIntents, TokenId,
+};
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::ReservableCurrency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Queue launch purchase intent and reserve its max price.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to reserve max price `Balances`
+	/// - One storage read-write to queue intent `PurchaseIntents<T>`
+	pub fn unchecked_queue_purchase_intent(
+		buyer: &T::AccountId,
+		launch_token_id: &TokenId,
+		max_price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		T::Currency::reserve(buyer, max_price).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		PurchaseIntents::<T>::try_mutate(|intents| {
+			intents
+				.try_push(PurchaseIntent {
+					buyer: buyer.clone(),
+					launch_token_id: *launch_token_id,
+					max_price,
+				})
+				.map_err(|_| Error::<T>::MaxPurchaseIntentsReached)
+		})
+	}
+
+	/// Weight of settling a single purchase intent.
+	pub fn purchase_intent_weight() -> Weight {
+		weights::MID.saturating_add(T::DbWeight::get().reads_writes(11, 11))
+	}
+
+	/// Settle queued purchase intents in submission order within `max_weight`.
+	///
+	/// Failed intents are dropped and their reserve released.
+	/// Intents that do not fit in `max_weight` stay queued for the next block.
+	///
+	/// Returns consumed weight.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update queued intents `PurchaseIntents<T>`
+	/// - Storage ops of `settle_purchase_intent` for each settled intent
+	pub fn settle_purchase_intents(max_weight: Weight) -> Weight {
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+		let intent_weight = Self::purchase_intent_weight();
+
+		// number of intents that fit in the remaining weight
+		let count = max_weight.saturating_sub(consumed) / intent_weight.max(1);
+		if count == 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut intents = PurchaseIntents::<T>::take().into_inner();
+		let count = intents.len().min(count as usize);
+
+		// carry over intents that do not fit in this block
+		let remaining = intents.split_off(count);
+		if let Ok(remaining) = BoundedVec::try_from(remaining) {
+			PurchaseIntents::<T>::put(remaining);
+		}
+
+		for intent in intents {
+			consumed = consumed.saturating_add(intent_weight);
+
+			T::Currency::unreserve(&intent.buyer, intent.max_price);
+
+			// settle each intent atomically so failed intents leave no partial changes
+			let result = with_transaction(|| match Self::settle_purchase_intent(&intent) {
+				Ok(settled) => TransactionOutcome::Commit(Ok(settled)),
+				Err(err) => TransactionOutcome::Rollback(Err(err)),
+			});
+
+			match result {
+				Ok((creator_id, token_id)) => Self::deposit_event(
+					Event::<T>::TokenInitialCollection(intent.buyer, creator_id, token_id),
+				),
+				Err(err) => Self::deposit_event(Event::<T>::PurchaseIntentFailed(
+					intent.buyer,
+					intent.launch_token_id,
+					err,
+				)),
+			}
+		}
+
+		consumed
+	}
+
+	/// Buy token from launch token for purchase intent.
+	///
+	/// Returns the creator and issued token id.
+	///
+	/// **Storage ops**
+	/// - Storage ops of `launch_buy`
+	pub fn settle_purchase_intent(
+		intent: &PurchaseIntent<T>,
+	) -> Result<(CreatorId, TokenId), DispatchError> {
+		// ensure account is not blocked
+		Self::ensure_account_not_blocked(&intent.buyer)?;
+
+		let launch_token =
+			Self::launch_token(intent.launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+		// get launch token owner
+		let (launch_token_owner, launch_token_creator) =
+			Self::get_launch_token_owner(&intent.launch_token_id)
+				.ok_or(Error::<T>::TokenUnavailable)?;
+
+		// ensure creator account is not banned
+		Self::ensure_creator_not_banned(&launch_token_creator)?;
+
+		// ensure drip schedule has unlocked supply
+		let launch_token_state =
+			Self::launch_token_state(intent.launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+		ensure!(
+			Self::get_drip_available(&intent.launch_token_id, &launch_token_state) > 0,
+			Error::<T>::DripSupplyLocked
+		);
+
+		// get launch price in native currency
+		let launch_price = Self::get_launch_price(&launch_token)?;
+
+		// ensure price does not exceed max price
+		ensure!(intent.max_price >= launch_price, Error::<T>::BidPriceTooLow);
+
+		// ensure transfer is allowed
+		ensure!(
+			T::TransferFilter::is_transfer_allowed(
+				&launch_token_owner,
+				&intent.buyer,
+				&intent.launch_token_id
+			),
+			Error::<T>::TransferNotAllowed
+		);
+
+		let token_id = Self::unchecked_launch_buy(
+			&intent.buyer,
+			&launch_token_owner,
+			&launch_token,
+			launch_price,
+		)?;
+
+		Ok((launch_token_creator, token_id))
+	}
+}
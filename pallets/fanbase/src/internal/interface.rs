@@ -0,0 +1,98 @@
+use crate::{
+	traits::FanbaseInterface, BalanceOf, Config, CreatorId, Error, Event, LaunchTokenMetadata,
+	Pallet, TokenId,
+};
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero};
+
+impl<T: Config> FanbaseInterface<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn mint(
+		creator_id: CreatorId,
+		price: BalanceOf<T>,
+		mut metadata: LaunchTokenMetadata,
+	) -> Result<TokenId, DispatchError> {
+		// ensure creator account exists
+		let creator = Self::resolve_creator(&creator_id).ok_or(Error::<T>::CreatorNotFound)?;
+
+		// ensure creator account is not banned
+		Self::ensure_creator_not_banned(&creator_id)?;
+
+		// ensure price and metadata are valid
+		Self::ensure_valid_launch_metadata(&price, &metadata)?;
+
+		let inline_data = metadata.inline_data.take();
+		let token_id = Self::unchecked_mint(creator_id.clone(), price, metadata)?;
+
+		// on-chain content deposit is reserved from the creator's owner
+		if inline_data.is_some() {
+			let owner = creator.owner.ok_or(Error::<T>::CreatorNotFound)?;
+			Self::unchecked_set_inline_data(&owner, &token_id, inline_data)?;
+		}
+
+		Self::deposit_event(Event::<T>::TokenCreated(creator_id, token_id));
+
+		Ok(token_id)
+	}
+
+	fn issue(receiver: &T::AccountId, launch_token_id: &TokenId) -> Result<TokenId, DispatchError> {
+		let launch_token = Self::launch_token(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+		// ensure creator account of launch token is not banned
+		Self::ensure_creator_not_banned(&launch_token.creator)?;
+
+		let token_id = Self::unchecked_launch_transfer(receiver, launch_token_id)?;
+
+		Self::deposit_event(Event::<T>::TokenInitialCollection(
+			receiver.clone(),
+			launch_token.creator,
+			token_id,
+		));
+
+		Ok(token_id)
+	}
+
+	fn transfer(
+		owner: &T::AccountId,
+		receiver: &T::AccountId,
+		token_id: &TokenId,
+	) -> DispatchResult {
+		// ensure owner can transfer token to receiver
+		Self::ensure_token_transferable(owner, token_id, receiver)?;
+
+		// ensure token is not transferred to owner
+		ensure!(owner != receiver, Error::<T>::TransferToSelf);
+
+		Self::unchecked_transfer(owner, receiver, token_id)?;
+
+		Self::deposit_event(Event::<T>::TokenTransferred(
+			owner.clone(),
+			receiver.clone(),
+			*token_id,
+		));
+
+		Ok(())
+	}
+
+	fn burn(owner: &T::AccountId, token_id: &TokenId) -> DispatchResult {
+		// ensure owner owns token
+		Self::ensure_account_owns_token(owner, token_id)?;
+
+		// ensure token is not locked
+		Self::ensure_token_unlocked(token_id)?;
+
+		// refund redeemable reserve to owner
+		let redeemed = Self::unchecked_redeem(owner, token_id)?;
+
+		Self::unchecked_burn(token_id)?;
+
+		if !redeemed.is_zero() {
+			Self::deposit_event(Event::<T>::TokenRedeemed(owner.clone(), *token_id, redeemed));
+		}
+		Self::deposit_event(Event::<T>::TokenDestroyed(owner.clone(), *token_id));
+
+		Ok(())
+	}
+
+	fn owner_of(token_id: &TokenId) -> Option<T::AccountId> {
+		Self::token(token_id).map(|token| token.owner)
+	}
+}
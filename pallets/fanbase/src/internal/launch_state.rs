@@ -0,0 +1,46 @@
+use super::MAX_PAGE_SIZE;
+use crate::{Config, LaunchState, LaunchTokenState, LaunchTokensByState, Pallet, TokenId};
+use frame_support::sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Add launch token to the index of its current state.
+	///
+	/// **Storage ops**
+	/// - One storage write to index launch token `LaunchTokensByState<T>`
+	pub fn index_launch_state(launch_token_id: &TokenId, launch_token_state: &LaunchTokenState) {
+		LaunchTokensByState::<T>::insert(launch_token_state.state, launch_token_id, ());
+	}
+
+	/// Move launch token to state and update the state index.
+	///
+	/// Launch token state is not saved.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage write to unindex previous state `LaunchTokensByState<T>`
+	/// - One storage write to index new state `LaunchTokensByState<T>`
+	pub fn unchecked_set_launch_state(
+		launch_token_id: &TokenId,
+		launch_token_state: &mut LaunchTokenState,
+		state: LaunchState,
+	) {
+		if launch_token_state.state == state {
+			return
+		}
+
+		LaunchTokensByState::<T>::remove(launch_token_state.state, launch_token_id);
+		launch_token_state.state = state;
+		Self::index_launch_state(launch_token_id, launch_token_state);
+	}
+
+	/// Get page of launch tokens in state.
+	///
+	/// Pages are limited to `MAX_PAGE_SIZE` entries.
+	pub fn launch_tokens_in_state(state: LaunchState, offset: u32, limit: u32) -> Vec<TokenId> {
+		LaunchTokensByState::<T>::iter_key_prefix(state)
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+}
@@ -0,0 +1,175 @@
+use super::MAX_PAGE_SIZE;
+use crate::{
+	BalanceOf, Config, CreatorId, ListedTokens, ListedTokensForCreator, ListedTokensForLaunch,
+	ListingsByPrice, Pallet, Token, TokenId,
+};
+use frame_support::{
+	sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto},
+	sp_std::prelude::*,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Add token to or remove token from the listing index according to its price.
+	///
+	/// Listings are indexed by price floor, the end price for dutch listings, so dutch listings
+	/// must be indexed after their schedule is stored.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update listing `ListedTokens<T>`
+	/// - One storage write to update creator listing `ListedTokensForCreator<T>`
+	/// - One storage read-write to update launch listing `ListedTokensForLaunch<T>`
+	/// - One storage read to get dutch listing `DutchListings<T>`
+	/// - Two storage writes to update price ordered listing `ListingsByPrice<T>`
+	/// - Storage ops of `update_creator_stats` if token was not listed
+	pub fn index_listing(token: &Token<T>) {
+		match token.price {
+			Some(price) => {
+				if !ListedTokens::<T>::contains_key(&token.id) {
+					Self::update_creator_stats(&token.creator, |stats| {
+						stats.listed = stats.listed.saturating_add(1);
+					});
+				}
+				let floor =
+					Self::dutch_listing(&token.id).map_or(price, |listing| listing.end_price);
+				if let Some(previous) = ListedTokensForLaunch::<T>::get(&token.launch_id, &token.id)
+				{
+					ListingsByPrice::<T>::remove(
+						&token.launch_id,
+						(Self::listing_price_key(previous), token.id),
+					);
+				}
+				ListedTokens::<T>::insert(&token.id, price);
+				ListedTokensForCreator::<T>::insert(&token.creator, &token.id, price);
+				ListedTokensForLaunch::<T>::insert(&token.launch_id, &token.id, floor);
+				ListingsByPrice::<T>::insert(
+					&token.launch_id,
+					(Self::listing_price_key(floor), token.id),
+					(),
+				);
+			},
+			None => Self::unindex_listing(token),
+		}
+	}
+
+	/// Remove token from the listing index.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove listing `ListedTokens<T>`
+	/// - One storage write to remove creator listing `ListedTokensForCreator<T>`
+	/// - One storage read-write to remove launch listing `ListedTokensForLaunch<T>`
+	/// - One storage write to remove price ordered listing `ListingsByPrice<T>`
+	/// - Storage ops of `update_creator_stats` if token was listed
+	pub fn unindex_listing(token: &Token<T>) {
+		if ListedTokens::<T>::take(&token.id).is_some() {
+			Self::update_creator_stats(&token.creator, |stats| {
+				stats.listed = stats.listed.saturating_sub(1);
+			});
+		}
+		ListedTokensForCreator::<T>::remove(&token.creator, &token.id);
+		if let Some(floor) = ListedTokensForLaunch::<T>::take(&token.launch_id, &token.id) {
+			ListingsByPrice::<T>::remove(
+				&token.launch_id,
+				(Self::listing_price_key(floor), token.id),
+			);
+		}
+	}
+
+	/// Get up to `max_scan` listed tokens minted from launch token sorted by current price,
+	/// cheapest first.
+	///
+	/// Listings are scanned from the lowest price floor, dutch listings priced above their floor
+	/// are sorted by their current price.
+	///
+	/// **Storage ops**
+	/// - One storage read per scanned listing of launch token `ListingsByPrice<T>`
+	/// - One storage read per scanned listing to get dutch listing `DutchListings<T>`
+	pub fn get_floor_listings(launch_id: &TokenId, max_scan: u32) -> Vec<(TokenId, BalanceOf<T>)> {
+		let mut listings: Vec<_> = ListingsByPrice::<T>::iter_key_prefix(launch_id)
+			.take(max_scan as usize)
+			.map(|(key, token_id)| {
+				let floor = BalanceOf::<T>::unique_saturated_from(u128::from_be_bytes(key));
+				(token_id, Self::get_listing_price(&token_id, floor))
+			})
+			.collect();
+		listings.sort_by(|(_, a), (_, b)| a.cmp(b));
+		listings
+	}
+
+	/// Get key of price in the price ordered listing index, big-endian so keys sort by price.
+	fn listing_price_key(price: BalanceOf<T>) -> [u8; 16] {
+		UniqueSaturatedInto::<u128>::unique_saturated_into(price).to_be_bytes()
+	}
+
+	/// Get current price of listing from its indexed price, dutch listings are priced by block.
+	///
+	/// **Storage ops**
+	/// - One storage read to get dutch listing `DutchListings<T>`
+	pub fn get_listing_price(token_id: &TokenId, price: BalanceOf<T>) -> BalanceOf<T> {
+		Self::dutch_listing(token_id).map_or(price, |listing| Self::get_dutch_price(&listing))
+	}
+
+	/// Get page of listed tokens with their current prices in stable order, starting after
+	/// `cursor`.
+	///
+	/// Returns the cursor of the next page if the page is full. Pages are limited to
+	/// `MAX_PAGE_SIZE` entries.
+	pub fn listed_tokens(
+		cursor: Option<TokenId>,
+		limit: u32,
+	) -> (Vec<(TokenId, BalanceOf<T>)>, Option<TokenId>) {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let listings: Vec<_> = match cursor {
+			Some(cursor) => ListedTokens::<T>::iter_from(ListedTokens::<T>::hashed_key_for(cursor))
+				.take(limit)
+				.collect(),
+			None => ListedTokens::<T>::iter().take(limit).collect(),
+		};
+
+		Self::listing_page(listings, limit)
+	}
+
+	/// Get page of listed tokens minted from launch tokens of creator with their current prices
+	/// in stable order, starting after `cursor`.
+	///
+	/// Returns the cursor of the next page if the page is full. Pages are limited to
+	/// `MAX_PAGE_SIZE` entries.
+	pub fn listed_tokens_of_creator(
+		creator_id: CreatorId,
+		cursor: Option<TokenId>,
+		limit: u32,
+	) -> (Vec<(TokenId, BalanceOf<T>)>, Option<TokenId>) {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let listings: Vec<_> = match cursor {
+			Some(cursor) => ListedTokensForCreator::<T>::iter_prefix_from(
+				&creator_id,
+				ListedTokensForCreator::<T>::hashed_key_for(&creator_id, cursor),
+			)
+			.take(limit)
+			.collect(),
+			None => ListedTokensForCreator::<T>::iter_prefix(&creator_id).take(limit).collect(),
+		};
+
+		Self::listing_page(listings, limit)
+	}
+
+	/// Price page of listings and get the cursor of the next page if the page is full.
+	///
+	/// **Storage ops**
+	/// - One storage read per listing to get dutch listing `DutchListings<T>`
+	fn listing_page(
+		listings: Vec<(TokenId, BalanceOf<T>)>,
+		limit: usize,
+	) -> (Vec<(TokenId, BalanceOf<T>)>, Option<TokenId>) {
+		let next = if limit > 0 && listings.len() == limit {
+			listings.last().map(|(token_id, _)| *token_id)
+		} else {
+			None
+		};
+		let listings = listings
+			.into_iter()
+			.map(|(token_id, price)| (token_id, Self::get_listing_price(&token_id, price)))
+			.collect();
+
+		(listings, next)
+	}
+}
@@ -0,0 +1,18 @@
+use crate::{Config, Error, Pallet, TokenId};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Ensure transfer lockup of token issued from launch token is over.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token by id `Tokens<T>`
+	pub fn ensure_lockup_over(token_id: &TokenId) -> Result<(), Error<T>> {
+		let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= token.locked_until,
+			Error::<T>::TokenInLockup
+		);
+
+		Ok(())
+	}
+}
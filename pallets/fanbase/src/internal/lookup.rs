@@ -0,0 +1,25 @@
+use crate::{traits::CreatorLookup, Config, CreatorId, Pallet};
+
+impl<T: Config> CreatorLookup<T::AccountId> for Pallet<T> {
+	/// **Storage ops**
+	/// - Storage ops of `resolve_creator`
+	fn lookup(creator_id: &CreatorId) -> Option<T::AccountId> {
+		Self::resolve_creator(creator_id)
+			.filter(|creator| !Self::is_creator_expired(creator))
+			.and_then(|creator| creator.owner)
+	}
+
+	/// Falls back to the first creator id of account if no primary creator id is set.
+	///
+	/// **Storage ops**
+	/// - One storage read to get primary creator id of account `PrimaryCreatorForAccount<T>`
+	/// - Storage ops of `lookup` to verify primary creator id still resolves to account
+	/// - One storage read to get creator ids for account `CreatorIdsForAccount<T>`
+	/// - Storage ops of `lookup` for each creator id until one resolves to account
+	fn reverse_lookup(account: &T::AccountId) -> Option<CreatorId> {
+		Self::primary_creator_for_account(account)
+			.into_iter()
+			.chain(Self::creator_ids_for_account(account))
+			.find(|creator_id| Self::lookup(creator_id).as_ref() == Some(account))
+	}
+}
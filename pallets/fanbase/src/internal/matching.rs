@@ -0,0 +1,80 @@
+use crate::{BalanceOf, Config, CreatorId, Error, Event, MatchingPool, MatchingPools, Pallet};
+use frame_support::{
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{BalanceStatus, ReservableCurrency},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Match purchase from the active matching pool of creator, if any.
+	///
+	/// Matched amount is capped at the amount left in the pool.
+	///
+	/// Returns matched amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update matching pool `MatchingPools<T>`
+	/// - One storage read-write to transfer reserved funds to beneficiary `Balances`
+	pub fn unchecked_match_purchase(
+		creator_id: &CreatorId,
+		beneficiary: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		MatchingPools::<T>::mutate(creator_id, |pool| {
+			let pool = match pool {
+				Some(pool) if Self::is_matching_active(pool) => pool,
+				_ => return Zero::zero(),
+			};
+
+			let amount = (pool.ratio * price).min(pool.remaining);
+			if amount.is_zero() {
+				return amount
+			}
+
+			// transfer reserved funds of sponsor, the unmatched amount is returned
+			let unmatched = T::Currency::repatriate_reserved(
+				&pool.sponsor,
+				beneficiary,
+				amount,
+				BalanceStatus::Free,
+			)
+			.unwrap_or(amount);
+			let matched = amount.saturating_sub(unmatched);
+
+			pool.remaining = pool.remaining.saturating_sub(matched);
+
+			Self::deposit_event(Event::<T>::PurchaseMatched(
+				creator_id.clone(),
+				beneficiary.clone(),
+				matched,
+			));
+
+			matched
+		})
+	}
+
+	/// Close matching pool of creator and return the amount left to the sponsor.
+	///
+	/// Returns closed matching pool.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove matching pool `MatchingPools<T>`
+	/// - One storage read-write to unreserve funds of sponsor `Balances`
+	pub fn unchecked_close_matching_pool(
+		creator_id: &CreatorId,
+	) -> Result<MatchingPool<T>, Error<T>> {
+		let pool = MatchingPools::<T>::take(creator_id).ok_or(Error::<T>::MatchingPoolNotFound)?;
+
+		T::Currency::unreserve(&pool.sponsor, pool.remaining);
+
+		Ok(pool)
+	}
+
+	/// Returns `true` if the matching window of pool is open.
+	pub fn is_matching_active(pool: &MatchingPool<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() < pool.ends_at
+	}
+}
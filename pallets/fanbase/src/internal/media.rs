@@ -0,0 +1,107 @@
+use crate::{Config, Error, MediaChunk, MediaChunks, MediaUpload, MediaUploads, Pallet, TokenId};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Hash, Saturating},
+	traits::ReservableCurrency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Save media chunk of launch token at index, replacing any chunk already at index.
+	///
+	/// Deposit for the chunk is reserved from uploader and the deposit of the replaced chunk is
+	/// returned.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update media upload of launch token `MediaUploads<T>`
+	/// - One storage read-write to save chunk `MediaChunks<T>`
+	/// - One storage read-write to return replaced deposit `Balances`
+	/// - One storage read-write to reserve deposit from uploader `Balances`
+	pub fn unchecked_upload_media_chunk(
+		uploader: &T::AccountId,
+		launch_token_id: &TokenId,
+		index: u32,
+		chunk: MediaChunk,
+	) -> Result<(), Error<T>> {
+		// ensure chunk index is within max chunks
+		ensure!(index < T::MaxMediaChunks::get(), Error::<T>::InvalidChunkIndex);
+
+		MediaUploads::<T>::try_mutate(launch_token_id, |upload| {
+			let upload = upload.get_or_insert_with(|| MediaUpload::new(uploader.clone()));
+
+			// ensure account started the upload
+			ensure!(&upload.uploader == uploader, Error::<T>::NotOwner);
+			// ensure media is not finalized
+			ensure!(!upload.is_finalized(), Error::<T>::MediaFinalized);
+
+			// return deposit of replaced chunk
+			if let Some(previous) = Self::media_chunk(launch_token_id, index) {
+				let refund = Self::inline_data_deposit(previous.len());
+				T::Currency::unreserve(uploader, refund);
+				upload.deposit = upload.deposit.saturating_sub(refund);
+				upload.size = upload.size.saturating_sub(previous.len() as u32);
+			} else {
+				upload.chunks = upload.chunks.saturating_add(1);
+			}
+
+			// reserve deposit for chunk
+			let deposit = Self::inline_data_deposit(chunk.len());
+			T::Currency::reserve(uploader, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+			upload.deposit = upload.deposit.saturating_add(deposit);
+			upload.size = upload.size.saturating_add(chunk.len() as u32);
+
+			MediaChunks::<T>::insert(launch_token_id, index, chunk);
+
+			Ok(())
+		})
+	}
+
+	/// Finalize media of launch token, fixing its content hash.
+	///
+	/// The content hash chains chunk hashes in order, `hash(hash(..), chunk)` starting from the
+	/// default hash, so that clients can verify media while streaming it chunk by chunk.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update media upload of launch token `MediaUploads<T>`
+	/// - One storage read per chunk `MediaChunks<T>`
+	pub fn unchecked_finalize_media(launch_token_id: &TokenId) -> Result<T::Hash, Error<T>> {
+		MediaUploads::<T>::try_mutate(launch_token_id, |upload| {
+			let upload = upload.as_mut().ok_or(Error::<T>::MediaNotFound)?;
+
+			// ensure media is not finalized
+			ensure!(!upload.is_finalized(), Error::<T>::MediaFinalized);
+
+			let mut hash = T::Hash::default();
+			for index in 0..upload.chunks {
+				// ensure chunks are contiguous from the first index
+				let chunk =
+					Self::media_chunk(launch_token_id, index).ok_or(Error::<T>::MediaIncomplete)?;
+				hash = T::Hashing::hash_of(&(hash, chunk));
+			}
+
+			upload.hash = Some(hash);
+
+			Ok(hash)
+		})
+	}
+
+	/// Remove media of launch token and return its deposit.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove media upload of launch token `MediaUploads<T>`
+	/// - One storage write per chunk to remove chunks `MediaChunks<T>`
+	/// - One storage read-write to return deposit `Balances`
+	pub fn unchecked_clear_media(launch_token_id: &TokenId) -> Result<(), Error<T>> {
+		let upload = MediaUploads::<T>::take(launch_token_id).ok_or(Error::<T>::MediaNotFound)?;
+
+		let _ = MediaChunks::<T>::clear_prefix(launch_token_id, T::MaxMediaChunks::get(), None);
+		T::Currency::unreserve(&upload.uploader, upload.deposit);
+
+		Ok(())
+	}
+}
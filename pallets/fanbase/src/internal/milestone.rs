@@ -0,0 +1,68 @@
+use crate::{traits::Payment, BalanceOf, Config, Error, MilestoneEscrows, Pallet, TokenId};
+use frame_support::{
+	sp_runtime::traits::{Saturating, Zero},
+	traits::ExistenceRequirement::KeepAlive,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Move launch proceeds from buyer into the pallet account until released by milestones.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to transfer funds to pallet account `Balances`
+	/// - One storage read-write to update escrowed proceeds `MilestoneEscrows<T>`
+	pub fn unchecked_escrow_proceeds(
+		buyer: &T::AccountId,
+		launch_token_id: &TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		MilestoneEscrows::<T>::try_mutate(launch_token_id, |escrow| {
+			let escrow = escrow.as_mut().ok_or(Error::<T>::MilestonesNotFound)?;
+
+			T::Payment::pay(buyer, &Self::pallet_account(), amount, Self::payment_existence())
+				.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			escrow.escrowed = escrow.escrowed.saturating_add(amount);
+
+			Ok(())
+		})
+	}
+
+	/// Release proceeds unlocked by approved milestones to the launch token beneficiary or owner.
+	///
+	/// Returns the beneficiary and released amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update released proceeds `MilestoneEscrows<T>`
+	/// - One storage read to get launch token beneficiary `LaunchBeneficiaries<T>`
+	/// - Storage ops of `get_launch_token_owner`
+	/// - One storage read-write to transfer funds from pallet account `Balances`
+	pub fn unchecked_release_proceeds(
+		launch_token_id: &TokenId,
+	) -> Result<(T::AccountId, BalanceOf<T>), Error<T>> {
+		MilestoneEscrows::<T>::try_mutate(launch_token_id, |escrow| {
+			let escrow = escrow.as_mut().ok_or(Error::<T>::MilestonesNotFound)?;
+
+			let beneficiary = match Self::launch_beneficiary(launch_token_id) {
+				Some(beneficiary) => beneficiary,
+				None =>
+					Self::get_launch_token_owner(launch_token_id)
+						.ok_or(Error::<T>::TokenUnavailable)?
+						.0,
+			};
+
+			let amount = escrow.releasable();
+			if !amount.is_zero() {
+				T::Payment::pay(&Self::pallet_account(), &beneficiary, amount, KeepAlive)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+				escrow.released = escrow.released.saturating_add(amount);
+			}
+
+			Ok((beneficiary, amount))
+		})
+	}
+}
@@ -1,2 +1,57 @@
+pub mod accessors;
+pub mod activity;
+pub mod airdrop;
+pub mod auction;
+pub mod bridge;
+pub mod calendar;
+pub mod clone;
+pub mod collection_offer;
+pub mod content;
 pub mod creator;
+pub mod curation;
+pub mod delegation;
+pub mod drip;
+pub mod escrow;
+pub mod featured;
+pub mod force;
+pub mod history;
+pub mod holders;
+pub mod infringement;
+pub mod inline;
+pub mod intent;
+pub mod interface;
+pub mod launch_state;
+pub mod listing;
+pub mod lockup;
+pub mod lookup;
+pub mod matching;
+pub mod media;
+pub mod milestone;
+pub mod moderation;
+pub mod name;
+pub mod offer;
+pub mod operator;
+pub mod parameters;
+pub mod payment;
+pub mod primary;
+pub mod proceeds;
+pub mod purchase_cap;
+pub mod quote;
+pub mod raffle;
+pub mod receipt;
+pub mod report;
+pub mod reputation;
+pub mod reserve;
+pub mod retire;
+pub mod royalty;
+pub mod social;
+pub mod stats;
+pub mod succession;
+pub mod tag;
 pub mod token;
+pub mod view;
+pub mod wash;
+pub mod work;
+
+/// Max number of entries returned by a single page
+pub const MAX_PAGE_SIZE: u32 = 100;
@@ -0,0 +1,55 @@
+use crate::{BannedCreators, BlockedAccounts, Call, Config, CreatorId, Error, Pallet, TokenId};
+use frame_support::{pallet_prelude::*, traits::Contains};
+
+impl<T: Config> Pallet<T> {
+	/// Ensure creator account is not banned.
+	///
+	/// **Storage ops**
+	/// - Two storage reads to check creator and parent creator ban `BannedCreators<T>`
+	/// - One storage read to get creator by id `Creators<T>`
+	pub fn ensure_creator_not_banned(creator_id: &CreatorId) -> Result<(), Error<T>> {
+		ensure!(!Self::is_creator_banned(creator_id), Error::<T>::CreatorIsBanned);
+
+		Ok(())
+	}
+
+	/// Returns `true` if creator account or the parent of a sub-creator is banned.
+	///
+	/// **Storage ops**
+	/// - Two storage reads to check creator and parent creator ban `BannedCreators<T>`
+	/// - One storage read to get creator by id `Creators<T>`
+	pub fn is_creator_banned(creator_id: &CreatorId) -> bool {
+		BannedCreators::<T>::contains_key(creator_id) ||
+			Self::creator(creator_id)
+				.and_then(|creator| creator.parent)
+				.map_or(false, |parent_id| BannedCreators::<T>::contains_key(parent_id))
+	}
+
+	/// Returns `true` if launch token was created by a banned creator account.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - Two storage reads to check creator and parent creator ban `BannedCreators<T>`
+	/// - One storage read to get creator by id `Creators<T>`
+	pub fn is_launch_token_flagged(launch_token_id: &TokenId) -> bool {
+		Self::launch_token(launch_token_id)
+			.map_or(false, |launch_token| Self::is_creator_banned(&launch_token.creator))
+	}
+
+	/// Ensure call is not disabled by the runtime call filter.
+	pub fn ensure_call_allowed(call: &Call<T>) -> Result<(), Error<T>> {
+		ensure!(T::CallFilter::contains(call), Error::<T>::CallDisabled);
+
+		Ok(())
+	}
+
+	/// Ensure account is not blocked from the marketplace.
+	///
+	/// **Storage ops**
+	/// - One storage read to check account block `BlockedAccounts<T>`
+	pub fn ensure_account_not_blocked(account: &T::AccountId) -> Result<(), Error<T>> {
+		ensure!(!BlockedAccounts::<T>::contains_key(account), Error::<T>::AccountIsBlocked);
+
+		Ok(())
+	}
+}
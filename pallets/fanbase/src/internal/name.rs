@@ -0,0 +1,46 @@
+use super::MAX_PAGE_SIZE;
+use crate::{Config, LaunchToken, LaunchTokenIdsByName, NameHash, Pallet, TokenId};
+use frame_support::{sp_io::hashing::blake2_256, sp_std::prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// Hash of launch token name used for case insensitive exact name lookup.
+	pub fn launch_name_hash(name: &[u8]) -> NameHash {
+		blake2_256(&name.to_ascii_lowercase())
+	}
+
+	/// Add launch token to the name index.
+	///
+	/// Returns `true` if other launch tokens have the same name.
+	///
+	/// **Storage ops**
+	/// - One storage read to check launch tokens with the same name `LaunchTokenIdsByName<T>`
+	/// - One storage write to index launch token by name `LaunchTokenIdsByName<T>`
+	pub fn index_launch_name(launch_token: &LaunchToken<T>) -> bool {
+		let name_hash = Self::launch_name_hash(&launch_token.name);
+		let duplicate = LaunchTokenIdsByName::<T>::iter_key_prefix(name_hash)
+			.any(|launch_token_id| launch_token_id != launch_token.id);
+		LaunchTokenIdsByName::<T>::insert(name_hash, launch_token.id, ());
+		duplicate
+	}
+
+	/// Remove launch token from the name index.
+	///
+	/// **Storage ops**
+	/// - One storage write to remove launch token from name index `LaunchTokenIdsByName<T>`
+	pub fn unindex_launch_name(launch_token: &LaunchToken<T>) {
+		LaunchTokenIdsByName::<T>::remove(
+			Self::launch_name_hash(&launch_token.name),
+			launch_token.id,
+		);
+	}
+
+	/// Get page of launch tokens with name, ignoring case.
+	///
+	/// Pages are limited to `MAX_PAGE_SIZE` entries.
+	pub fn launch_tokens_by_name(name: &[u8], offset: u32, limit: u32) -> Vec<TokenId> {
+		LaunchTokenIdsByName::<T>::iter_key_prefix(Self::launch_name_hash(name))
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+}
@@ -0,0 +1,129 @@
+use crate::{BalanceOf, Config, Error, OfferDeposits, Offers, Pallet, Token, TokenId};
+use frame_support::{ensure, traits::ReservableCurrency};
+
+impl<T: Config> Pallet<T> {
+	/// Place offer for token, replacing any standing offer of buyer for the token.
+	///
+	/// Offer amount is reserved from buyer until the offer is accepted or cancelled. The offer
+	/// deposit is reserved for new offers and kept when replacing an offer.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update offer `Offers<T>`
+	/// - One storage read-write to unreserve previous offer amount `Balances`
+	/// - One storage read-write to reserve offer deposit `Balances`
+	/// - One storage write to save offer deposit `OfferDeposits<T>`
+	/// - One storage read-write to reserve offer amount `Balances`
+	pub fn unchecked_make_offer(
+		buyer: &T::AccountId,
+		token_id: &TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		Offers::<T>::try_mutate(token_id, buyer, |offer| {
+			match offer.take() {
+				// return previous offer amount to buyer
+				Some(previous) => {
+					T::Currency::unreserve(buyer, previous);
+				},
+				// reserve deposit for new offer
+				None => {
+					let deposit = T::OfferDeposit::get();
+					T::Currency::reserve(buyer, deposit)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+					OfferDeposits::<T>::insert(token_id, buyer, deposit);
+				},
+			}
+
+			// reserve offer amount from buyer
+			T::Currency::reserve(buyer, amount).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			*offer = Some(amount);
+
+			Ok(())
+		})
+	}
+
+	/// Ensure offer amount is not below the offer floor of token.
+	///
+	/// **Storage ops**
+	/// - One storage read to get offer floor of token `OfferFloors<T>`
+	pub fn ensure_offer_above_floor(
+		token_id: &TokenId,
+		amount: &BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if let Some(floor) = Self::offer_floor(token_id) {
+			ensure!(*amount >= floor, Error::<T>::OfferBelowFloor);
+		}
+
+		Ok(())
+	}
+
+	/// Cancel offer for token and return reserved amount to buyer.
+	///
+	/// Returns cancelled offer amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove offer `Offers<T>`
+	/// - One storage read-write to unreserve offer amount `Balances`
+	/// - Storage ops of `unchecked_release_offer_deposit`
+	pub fn unchecked_cancel_offer(
+		buyer: &T::AccountId,
+		token_id: &TokenId,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amount = Offers::<T>::take(token_id, buyer).ok_or(Error::<T>::OfferNotFound)?;
+
+		// return reserved amount to buyer
+		T::Currency::unreserve(buyer, amount);
+		Self::unchecked_release_offer_deposit(buyer, token_id);
+
+		Ok(amount)
+	}
+
+	/// Accept offer for token by transferring token to buyer and reserved amount to token owner.
+	///
+	/// Returns accepted offer amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove offer `Offers<T>`
+	/// - Storage ops of `unchecked_release_offer_deposit`
+	/// - Storage ops of `unchecked_transfer`
+	/// - Storage ops of `unchecked_pay_reserved_sale`
+	/// - Storage ops of `record_sale`
+	pub fn unchecked_accept_offer(
+		token: &Token<T>,
+		buyer: &T::AccountId,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amount = Offers::<T>::take(&token.id, buyer).ok_or(Error::<T>::OfferNotFound)?;
+		Self::unchecked_release_offer_deposit(buyer, &token.id);
+
+		// transfer token from owner to buyer
+		Self::unchecked_transfer(&token.owner, buyer, &token.id)?;
+
+		// transfer reserved amount to owner, less royalty
+		Self::unchecked_pay_reserved_sale(buyer, &token.owner, token, amount)?;
+
+		Self::record_sale(&token.launch_id, token.id, token.owner.clone(), buyer.clone(), amount)?;
+
+		Ok(amount)
+	}
+
+	/// Return offer deposit of buyer for token.
+	///
+	/// Offers made before deposits were required have no deposit.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove offer deposit `OfferDeposits<T>`
+	/// - One storage read-write to unreserve offer deposit `Balances`
+	pub fn unchecked_release_offer_deposit(buyer: &T::AccountId, token_id: &TokenId) {
+		if let Some(deposit) = OfferDeposits::<T>::take(token_id, buyer) {
+			T::Currency::unreserve(buyer, deposit);
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use crate::{Config, CreatorId, CreatorOperators, Error, Pallet};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Returns `true` if account is an operator of creator account that has not expired.
+	///
+	/// **Storage ops**
+	/// - One storage read to get operators of creator `CreatorOperators<T>`
+	pub fn is_creator_operator(creator_id: &CreatorId, account: &T::AccountId) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		Self::creator_operators(creator_id)
+			.iter()
+			.any(|(operator, expires_at)| operator == account && now < *expires_at)
+	}
+
+	/// Add operator key to creator account or update expiry of existing operator.
+	///
+	/// Expired operators are dropped.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update operators of creator `CreatorOperators<T>`
+	pub fn unchecked_add_operator(
+		creator_id: &CreatorId,
+		operator: &T::AccountId,
+		expires_at: T::BlockNumber,
+	) -> Result<(), Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		CreatorOperators::<T>::try_mutate(creator_id, |operators| {
+			operators.retain(|(account, expires_at)| account != operator && now < *expires_at);
+
+			operators
+				.try_push((operator.clone(), expires_at))
+				.map_err(|_| Error::<T>::MaxOperatorsReached)
+		})
+	}
+
+	/// Remove operator key from creator account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update operators of creator `CreatorOperators<T>`
+	pub fn unchecked_remove_operator(
+		creator_id: &CreatorId,
+		operator: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		CreatorOperators::<T>::try_mutate(creator_id, |operators| {
+			let index = operators
+				.iter()
+				.position(|(account, _)| account == operator)
+				.ok_or(Error::<T>::NotOperator)?;
+
+			// `swap_remove` because we do not care about ordering and it is faster than `remove`
+			operators.swap_remove(index);
+
+			Ok(())
+		})
+	}
+}
@@ -0,0 +1,35 @@
+use crate::{BalanceOf, Config, Pallet, PalletParameter, PalletParameterOf, Parameters};
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+impl<T: Config> Pallet<T> {
+	/// Get fee burned when renewing a creator registration.
+	///
+	/// **Storage ops**
+	/// - One storage read to get parameters `Parameters<T>`
+	pub fn creator_renewal_fee() -> BalanceOf<T> {
+		Parameters::<T>::get()
+			.creator_renewal_fee
+			.unwrap_or_else(T::CreatorRenewalFee::get)
+	}
+
+	/// Get max royalty of launch tokens.
+	///
+	/// **Storage ops**
+	/// - One storage read to get parameters `Parameters<T>`
+	pub fn max_royalty() -> Permill {
+		Parameters::<T>::get().max_royalty.unwrap_or_else(T::MaxRoyalty::get)
+	}
+
+	/// Set pallet parameter.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update parameters `Parameters<T>`
+	pub fn unchecked_set_parameter(parameter: &PalletParameterOf<T>) {
+		Parameters::<T>::mutate(|parameters| match parameter {
+			PalletParameter::CreatorRenewalFee(fee) => parameters.creator_renewal_fee = *fee,
+			PalletParameter::MaxRoyalty(royalty) => parameters.max_royalty = *royalty,
+		});
+	}
+}
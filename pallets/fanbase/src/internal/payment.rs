@@ -0,0 +1,40 @@
+use crate::{BalanceOf, Config, Error, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Saturating,
+	traits::{Currency, ExistenceRequirement},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Existence requirement of buyer payments.
+	///
+	/// Buyers keep their account alive unless the runtime allows purchases to spend the full
+	/// balance.
+	pub fn payment_existence() -> ExistenceRequirement {
+		if T::AllowDeathPayments::get() {
+			ExistenceRequirement::AllowDeath
+		} else {
+			ExistenceRequirement::KeepAlive
+		}
+	}
+
+	/// Ensure account can pay amount under the payment existence requirement.
+	///
+	/// **Storage ops**
+	/// - One storage read to get free balance of account `Balances`
+	pub fn ensure_can_pay(account: &T::AccountId, amount: BalanceOf<T>) -> Result<(), Error<T>> {
+		let free_balance = T::Currency::free_balance(account);
+
+		// ensure sufficient balance
+		ensure!(free_balance >= amount, Error::<T>::InsufficientFunds);
+
+		// ensure payment does not reap account unless allowed
+		ensure!(
+			T::AllowDeathPayments::get() ||
+				free_balance.saturating_sub(amount) >= T::Currency::minimum_balance(),
+			Error::<T>::PaymentWouldReap
+		);
+
+		Ok(())
+	}
+}
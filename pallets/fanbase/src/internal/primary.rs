@@ -0,0 +1,15 @@
+use crate::{Config, CreatorId, Pallet, PrimaryCreatorForAccount};
+
+impl<T: Config> Pallet<T> {
+	/// Clear primary creator id of account if it is the given creator id.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to clear primary creator id of account `PrimaryCreatorForAccount<T>`
+	pub fn clear_primary_creator(account: &T::AccountId, creator_id: &CreatorId) {
+		PrimaryCreatorForAccount::<T>::mutate_exists(account, |primary| {
+			if primary.as_ref() == Some(creator_id) {
+				*primary = None;
+			}
+		});
+	}
+}
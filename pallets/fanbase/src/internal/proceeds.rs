@@ -0,0 +1,101 @@
+use crate::{traits::Payment, BalanceOf, Config, Error, Pallet, PendingProceeds};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{BalanceStatus, Currency, ExistenceRequirement::KeepAlive, ReservableCurrency},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Get account credited with secondary sale proceeds of seller.
+	///
+	/// **Storage ops**
+	/// - One storage read to get payout account of seller `PayoutAccounts<T>`
+	pub fn get_payout_account(seller: &T::AccountId) -> T::AccountId {
+		Self::payout_account(seller).unwrap_or_else(|| seller.clone())
+	}
+
+	/// Move amount from buyer into the pallet account and credit it to the proceeds of recipient.
+	///
+	/// Recipients withdraw their proceeds with `withdraw_proceeds`, purchases never fail because
+	/// of the recipient account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to transfer funds to pallet account `Balances`
+	/// - One storage read-write to credit proceeds of recipient `PendingProceeds<T>`
+	pub fn unchecked_credit_proceeds(
+		buyer: &T::AccountId,
+		recipient: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		T::Payment::pay(buyer, &Self::pallet_account(), amount, Self::payment_existence())
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		PendingProceeds::<T>::mutate(recipient, |proceeds| proceeds.saturating_accrue(amount));
+
+		Ok(())
+	}
+
+	/// Move amount reserved from buyer into the pallet account and credit it to the proceeds of
+	/// recipient.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to transfer reserved funds to pallet account `Balances`
+	/// - One storage read-write to credit proceeds of recipient `PendingProceeds<T>`
+	pub fn unchecked_credit_reserved_proceeds(
+		buyer: &T::AccountId,
+		recipient: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		T::Currency::repatriate_reserved(
+			buyer,
+			&Self::pallet_account(),
+			amount,
+			BalanceStatus::Free,
+		)
+		.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		PendingProceeds::<T>::mutate(recipient, |proceeds| proceeds.saturating_accrue(amount));
+
+		Ok(())
+	}
+
+	/// Pay out all proceeds of account from the pallet account.
+	///
+	/// Returns withdrawn amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove proceeds of account `PendingProceeds<T>`
+	/// - One storage read-write to transfer funds from pallet account `Balances`
+	pub fn unchecked_withdraw_proceeds(account: &T::AccountId) -> Result<BalanceOf<T>, Error<T>> {
+		let amount = PendingProceeds::<T>::take(account);
+
+		// ensure account has proceeds
+		ensure!(!amount.is_zero(), Error::<T>::NoProceeds);
+
+		// ensure proceeds fund the account, dust keeps accumulating otherwise
+		ensure!(
+			amount >= T::Currency::minimum_balance() ||
+				!T::Currency::total_balance(account).is_zero(),
+			Error::<T>::ProceedsBelowMinimum
+		);
+
+		T::Payment::pay(&Self::pallet_account(), account, amount, KeepAlive)
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		Ok(amount)
+	}
+}
@@ -0,0 +1,37 @@
+use crate::{BlockPurchases, Config, Error, Pallet, TokenId};
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
+
+impl<T: Config> Pallet<T> {
+	/// Count a purchase of launch token against the per block purchase cap of buyer.
+	///
+	/// Purchase counts of previous blocks are replaced on the first purchase of a new block.
+	/// Does nothing if launch token has no purchase cap.
+	///
+	/// **Storage ops**
+	/// - One storage read to get purchase cap of launch token `BlockPurchaseCaps<T>`
+	/// - One storage read-write to update purchases of buyer `BlockPurchases<T>`
+	pub fn consume_block_purchase(
+		launch_token_id: &TokenId,
+		buyer: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		let cap = match Self::block_purchase_cap(launch_token_id) {
+			Some(cap) => cap,
+			None => return Ok(()),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+
+		BlockPurchases::<T>::try_mutate(launch_token_id, buyer, |purchases| {
+			let purchased = match purchases {
+				Some((block, purchased)) if *block == now => *purchased,
+				// purchases of previous blocks no longer count
+				_ => 0,
+			};
+
+			ensure!(purchased < cap, Error::<T>::BlockPurchaseCapReached);
+
+			*purchases = Some((now, purchased.saturating_add(1)));
+
+			Ok(())
+		})
+	}
+}
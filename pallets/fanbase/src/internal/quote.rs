@@ -0,0 +1,46 @@
+use crate::{Config, Error, Pallet, PriceQuoteOf, QuoteNonces};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Verify, Zero},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Verify price quote is signed by its seller for the current quote nonce and has not expired.
+	///
+	/// **Storage ops**
+	/// - One storage read to get quote nonce of seller `QuoteNonces<T>`
+	/// - One storage read to get genesis hash `BlockHash`
+	pub fn ensure_quote_valid(
+		quote: &PriceQuoteOf<T>,
+		signature: &T::OffchainSignature,
+	) -> Result<(), Error<T>> {
+		// ensure quote has not expired
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < quote.expires_at,
+			Error::<T>::QuoteExpired
+		);
+
+		// ensure quote has not been executed or revoked
+		ensure!(quote.nonce == Self::quote_nonce(&quote.seller), Error::<T>::QuoteRevoked);
+
+		// ensure quote is signed by seller for this chain
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		let payload = (b"fanbase/quote", genesis_hash, quote).encode();
+		ensure!(signature.verify(&payload[..], &quote.seller), Error::<T>::InvalidQuoteSignature);
+
+		Ok(())
+	}
+
+	/// Bump quote nonce of seller, invalidating all outstanding quotes of seller.
+	///
+	/// Returns new quote nonce.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update quote nonce of seller `QuoteNonces<T>`
+	pub fn bump_quote_nonce(seller: &T::AccountId) -> u32 {
+		QuoteNonces::<T>::mutate(seller, |nonce| {
+			*nonce = nonce.saturating_add(1);
+			*nonce
+		})
+	}
+}
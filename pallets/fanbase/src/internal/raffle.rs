@@ -0,0 +1,184 @@
+use crate::{
+	BalanceOf, Config, Error, LaunchToken, Pallet, RaffleEntries, Raffles, RafflesClosingAt,
+	TokenId,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Hash, Saturating, TrailingZeroInput, Zero},
+	sp_std::prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Randomness, ReservableCurrency},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Add tickets of account to raffle and reserve their ticket fees.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to reserve ticket fees `Balances`
+	/// - One storage read-write to update raffle entries `RaffleEntries<T>`
+	pub fn unchecked_enter_raffle(
+		account: &T::AccountId,
+		launch_token_id: &TokenId,
+		tickets: u32,
+		ticket_fee: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		T::Currency::reserve(account, ticket_fee.saturating_mul(tickets.into()))
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		RaffleEntries::<T>::try_mutate(launch_token_id, |entries| {
+			match entries.iter_mut().find(|(entrant, _)| entrant == account) {
+				// add tickets to existing entry
+				Some((_, existing_tickets)) => {
+					*existing_tickets = existing_tickets.saturating_add(tickets);
+					Ok(())
+				},
+				None => entries
+					.try_push((account.clone(), tickets))
+					.map_err(|_| Error::<T>::MaxRaffleEntriesReached),
+			}
+		})
+	}
+
+	/// Seed raffles whose entries close at block.
+	///
+	/// The seed is taken once at the start of the closing block so the draw cannot be influenced
+	/// by choosing when to draw.
+	///
+	/// Returns consumed weight.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to take raffles closing at block `RafflesClosingAt<T>`
+	/// - One storage read-write per raffle to set seed `Raffles<T>`
+	/// - One storage read per raffle to get random seed `Randomness`
+	pub fn seed_closing_raffles(block_number: T::BlockNumber) -> Weight {
+		let launch_token_ids = RafflesClosingAt::<T>::take(block_number);
+		let count = launch_token_ids.len() as u64;
+
+		for launch_token_id in launch_token_ids {
+			Raffles::<T>::mutate(&launch_token_id, |raffle| {
+				if let Some(raffle) = raffle {
+					let (seed, _) =
+						T::Randomness::random(&(b"fanbase/raffle", launch_token_id).encode());
+					raffle.seed = Some(seed);
+				}
+			});
+		}
+
+		T::DbWeight::get().reads_writes(1 + count * 2, 1 + count)
+	}
+
+	/// Draw up to `count` winners from raffle entries weighted by their tickets and seed.
+	///
+	/// Each entrant wins at most once. Returns winners with their tickets and the remaining
+	/// entries.
+	pub fn draw_raffle_winners(
+		seed: &T::Hash,
+		mut entries: Vec<(T::AccountId, u32)>,
+		count: u32,
+	) -> (Vec<(T::AccountId, u32)>, Vec<(T::AccountId, u32)>) {
+		let mut winners = Vec::new();
+
+		for round in 0..count {
+			let total_tickets =
+				entries.iter().fold(0u32, |total, (_, tickets)| total.saturating_add(*tickets));
+			if total_tickets.is_zero() {
+				break
+			}
+
+			// pick ticket from seed and round
+			let hash = T::Hashing::hash_of(&(seed, round));
+			let mut ticket = u32::decode(&mut TrailingZeroInput::new(hash.as_ref()))
+				.unwrap_or_default() %
+				total_tickets;
+
+			// find entry holding ticket
+			let index = entries
+				.iter()
+				.position(|(_, tickets)| {
+					if ticket < *tickets {
+						return true
+					}
+					ticket -= tickets;
+					false
+				})
+				.unwrap_or_default();
+
+			winners.push(entries.swap_remove(index));
+		}
+
+		(winners, entries)
+	}
+
+	/// Issue token of launch token to raffle winner and pay their ticket fees as launch sale.
+	///
+	/// Settled atomically so failed winners leave no partial changes.
+	///
+	/// **Storage ops**
+	/// - Storage ops of `unchecked_launch_transfer`
+	/// - Storage ops of `unchecked_settle_raffle_winner`
+	pub fn settle_raffle_winner(
+		winner: &T::AccountId,
+		seller: &T::AccountId,
+		launch_token: &LaunchToken<T>,
+		tickets: u32,
+		ticket_fee: BalanceOf<T>,
+	) -> Result<TokenId, DispatchError> {
+		with_transaction(|| {
+			let result =
+				Self::unchecked_launch_transfer(winner, &launch_token.id).and_then(|token_id| {
+					Self::unchecked_settle_raffle_winner(
+						winner,
+						seller,
+						launch_token,
+						&token_id,
+						tickets,
+						ticket_fee,
+					)
+					.map(|_| token_id)
+				});
+			match result {
+				Ok(token_id) => TransactionOutcome::Commit(Ok(token_id)),
+				Err(err) => TransactionOutcome::Rollback(Err(err.into())),
+			}
+		})
+	}
+
+	/// Release reserved ticket fees of raffle winner and pay them for the issued token like a
+	/// launch purchase.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to unreserve ticket fees `Balances`
+	/// - Storage ops of `unchecked_pay_launch_sale`
+	pub fn unchecked_settle_raffle_winner(
+		winner: &T::AccountId,
+		seller: &T::AccountId,
+		launch_token: &LaunchToken<T>,
+		token_id: &TokenId,
+		tickets: u32,
+		ticket_fee: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		let amount = ticket_fee.saturating_mul(tickets.into());
+		T::Currency::unreserve(winner, amount);
+
+		Self::unchecked_pay_launch_sale(winner, seller, launch_token, token_id, amount)
+	}
+
+	/// Refund ticket fees of raffle entrants.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write per entrant to unreserve funds `Balances`
+	pub fn unchecked_refund_raffle_entries(
+		entries: &[(T::AccountId, u32)],
+		ticket_fee: BalanceOf<T>,
+	) {
+		for (entrant, tickets) in entries {
+			T::Currency::unreserve(entrant, ticket_fee.saturating_mul((*tickets).into()));
+		}
+	}
+}
@@ -0,0 +1,33 @@
+use crate::{BalanceOf, Config, Event, Pallet, Receipt, ReceiptOf, Receipts, TokenId};
+use frame_support::sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Issue purchase receipt of token bought from launch token to buyer.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage write to save receipt `Receipts<T>`
+	pub fn unchecked_issue_receipt(
+		buyer: &T::AccountId,
+		launch_id: &TokenId,
+		token_id: &TokenId,
+		price: BalanceOf<T>,
+	) {
+		let block = frame_system::Pallet::<T>::block_number();
+		Receipts::<T>::insert((launch_id, buyer, token_id), Receipt { price, block });
+
+		Self::deposit_event(Event::<T>::ReceiptIssued(buyer.clone(), *launch_id, *token_id));
+	}
+
+	/// Get purchase receipts of launch token held by account with the purchased token ids.
+	///
+	/// **Storage ops**
+	/// - One storage read per receipt `Receipts<T>`
+	pub fn receipts_of(
+		launch_id: &TokenId,
+		account: &T::AccountId,
+	) -> Vec<(TokenId, ReceiptOf<T>)> {
+		Receipts::<T>::iter_prefix((launch_id, account)).collect()
+	}
+}
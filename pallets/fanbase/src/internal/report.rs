@@ -0,0 +1,83 @@
+use crate::{
+	BalanceOf, Config, Error, Pallet, Report, ReportCount, ReportId, ReportReason, Reports,
+	Strikes, TokenId,
+};
+use frame_support::traits::ReservableCurrency;
+
+impl<T: Config> Pallet<T> {
+	/// File report against launch token and reserve deposit from reporter.
+	///
+	/// Returns the report id.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to reserve deposit `Balances`
+	/// - One storage read-write to increment report count `ReportCount<T>`
+	/// - One storage write to save report `Reports<T>`
+	pub fn unchecked_report_launch(
+		reporter: &T::AccountId,
+		launch_token_id: &TokenId,
+		reason: ReportReason,
+		deposit: BalanceOf<T>,
+	) -> Result<ReportId, Error<T>> {
+		T::Currency::reserve(reporter, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		let report_id = ReportCount::<T>::try_mutate(launch_token_id, |count| {
+			let report_id = *count;
+			*count = count.checked_add(1).ok_or(Error::<T>::ReportsOverflow)?;
+			Ok::<_, Error<T>>(report_id)
+		})?;
+
+		Reports::<T>::insert(
+			launch_token_id,
+			report_id,
+			Report { reporter: reporter.clone(), reason, deposit },
+		);
+
+		Ok(report_id)
+	}
+
+	/// Resolve report against launch token.
+	///
+	/// Upheld reports add a strike to the launch token and return the deposit, dismissed reports
+	/// slash the deposit.
+	///
+	/// Returns the resolved report and strikes of launch token.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove report `Reports<T>`
+	/// - One storage read-write to unreserve or slash deposit `Balances`
+	/// - One storage read-write to increment strikes if upheld `Strikes<T>`
+	/// - One storage read to get launch token if upheld `LaunchTokens<T>`
+	/// - Storage ops of `record_upheld_report` if upheld
+	pub fn unchecked_resolve_report(
+		launch_token_id: &TokenId,
+		report_id: ReportId,
+		upheld: bool,
+	) -> Result<(Report<T>, u32), Error<T>> {
+		let report =
+			Reports::<T>::take(launch_token_id, report_id).ok_or(Error::<T>::ReportNotFound)?;
+
+		let strikes = if upheld {
+			T::Currency::unreserve(&report.reporter, report.deposit);
+
+			if let Some(launch_token) = Self::launch_token(launch_token_id) {
+				Self::record_upheld_report(&launch_token.creator);
+			}
+
+			Strikes::<T>::mutate(launch_token_id, |strikes| {
+				*strikes = strikes.saturating_add(1);
+				*strikes
+			})
+		} else {
+			let _ = T::Currency::slash_reserved(&report.reporter, report.deposit);
+
+			Self::strikes(launch_token_id)
+		};
+
+		Ok((report, strikes))
+	}
+}
@@ -0,0 +1,53 @@
+use crate::{BalanceOf, Config, CreatorId, Pallet, Reputations};
+use frame_support::sp_runtime::traits::Saturating;
+
+impl<T: Config> Pallet<T> {
+	/// Record sold out launch token of creator.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator reputation `Reputations<T>`
+	pub fn record_successful_launch(creator_id: &CreatorId) {
+		Reputations::<T>::mutate(creator_id, |reputation| {
+			reputation.successful_launches = reputation.successful_launches.saturating_add(1);
+		});
+	}
+
+	/// Record primary sale of creator's launch token.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator reputation `Reputations<T>`
+	pub fn record_creator_sale(creator_id: &CreatorId, price: BalanceOf<T>) {
+		Reputations::<T>::mutate(creator_id, |reputation| {
+			reputation.sales = reputation.sales.saturating_add(1);
+			reputation.sales_volume = reputation.sales_volume.saturating_add(price);
+		});
+	}
+
+	/// Record redeemable refund of creator's token.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator reputation `Reputations<T>`
+	pub fn record_refund(creator_id: &CreatorId) {
+		Reputations::<T>::mutate(creator_id, |reputation| {
+			reputation.refunds = reputation.refunds.saturating_add(1);
+		});
+	}
+
+	/// Record upheld report against creator's launch token.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator reputation `Reputations<T>`
+	pub fn record_upheld_report(creator_id: &CreatorId) {
+		Reputations::<T>::mutate(creator_id, |reputation| {
+			reputation.upheld_reports = reputation.upheld_reports.saturating_add(1);
+		});
+	}
+
+	/// Get reputation score of creator.
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator reputation `Reputations<T>`
+	pub fn creator_score(creator_id: &CreatorId) -> u32 {
+		Self::reputation(creator_id).score()
+	}
+}
@@ -0,0 +1,86 @@
+use crate::{traits::Payment, BalanceOf, Config, Error, Pallet, RedeemableReserves, TokenId};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{AccountIdConversion, Zero},
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Move redeemable share of a primary sale from buyer into the pallet account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to transfer funds to pallet account `Balances`
+	/// - One storage write to save token redeemable reserve `RedeemableReserves<T>`
+	pub fn unchecked_lock_redeemable(
+		buyer: &T::AccountId,
+		token_id: &TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		T::Payment::pay(buyer, &Self::pallet_account(), amount, Self::payment_existence())
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		RedeemableReserves::<T>::insert(token_id, amount);
+
+		Ok(())
+	}
+
+	/// Refund redeemable reserve of token to holder.
+	///
+	/// Returns refunded amount.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove token redeemable reserve `RedeemableReserves<T>`
+	/// - One storage read-write to transfer funds from pallet account `Balances`
+	/// - One storage read to get token `Tokens<T>`
+	/// - Storage ops of `record_refund`
+	pub fn unchecked_redeem(
+		holder: &T::AccountId,
+		token_id: &TokenId,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amount = match RedeemableReserves::<T>::take(token_id) {
+			Some(amount) => amount,
+			None => return Ok(Zero::zero()),
+		};
+
+		T::Payment::pay(&Self::pallet_account(), holder, amount, KeepAlive)
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+		if let Some(token) = Self::token(token_id) {
+			Self::record_refund(&token.creator);
+		}
+
+		Ok(amount)
+	}
+
+	/// Get pallet account holding redeemable reserves.
+	pub fn pallet_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Endow pallet account with the minimum balance so it is never reaped while holding
+	/// reserves, and transfers below the minimum balance into it succeed.
+	///
+	/// Returns `true` if the pallet account was endowed.
+	///
+	/// **Storage ops**
+	/// - One storage read to get free balance of pallet account `Balances`
+	/// - One storage write to endow pallet account if below the minimum balance `Balances`
+	pub fn ensure_pallet_account_funded() -> bool {
+		let account = Self::pallet_account();
+		let min = T::Currency::minimum_balance();
+		if T::Currency::free_balance(&account) >= min {
+			return false
+		}
+
+		let _ = T::Currency::make_free_balance_be(&account, min);
+		true
+	}
+}
@@ -0,0 +1,49 @@
+use crate::{
+	Config, DutchListings, Error, LaunchTokenStates, Pallet, RetiredTokens, TokenId, Tokens,
+};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Retire token, keeping its record while removing it from circulation.
+	///
+	/// Retired tokens are delisted, no longer counted as held by their owner and count as
+	/// destroyed in supply counters. They cannot be transferred, listed or burned.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to delist token `Tokens<T>`
+	/// - One storage write to clear dutch listing `DutchListings<T>`
+	/// - Storage ops of `unindex_listing`
+	/// - Storage ops of `track_released`
+	/// - One storage read-write to update launch token issuance counters `LaunchTokenStates<T>`
+	/// - Storage ops of `update_creator_stats`
+	/// - One storage write to mark token as retired `RetiredTokens<T>`
+	pub fn unchecked_retire(token_id: &TokenId) -> Result<(), Error<T>> {
+		let token = Tokens::<T>::try_mutate(token_id, |token| {
+			let token = token.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+			// delist token
+			token.price = None;
+
+			Ok::<_, Error<T>>(token.clone())
+		})?;
+		DutchListings::<T>::remove(token_id);
+		Self::unindex_listing(&token);
+		Self::track_released(&token.launch_id, &token.owner, &token.id);
+
+		// update launch token state
+		LaunchTokenStates::<T>::mutate(&token.launch_id, |launch_token_state| {
+			if let Some(launch_token_state) = launch_token_state {
+				launch_token_state.bump_destroyed_and_decrease_supply();
+			}
+		});
+		Self::update_creator_stats(&token.creator, |stats| {
+			stats.destroyed = stats.destroyed.saturating_add(1);
+		});
+
+		RetiredTokens::<T>::insert(token_id, frame_system::Pallet::<T>::block_number());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,225 @@
+use crate::{
+	weights, BalanceOf, Config, CreatorId, Error, Event, LaunchDefaultsForCreator, LaunchTokens,
+	Marketplaces, Pallet, Token, TokenId,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		Permill,
+	},
+	traits::Currency,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Get recipient and amount of royalty due on secondary sale of token at price.
+	///
+	/// Royalties are paid to the royalty beneficiary, launch token beneficiary or owner, none is
+	/// due when the seller is the recipient. Royalties above the current max royalty are capped.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token `LaunchTokens<T>`
+	/// - One storage read to get max royalty `Parameters<T>`
+	/// - One storage read to get royalty beneficiary `RoyaltyBeneficiaries<T>`
+	/// - One storage read to get launch token beneficiary `LaunchBeneficiaries<T>`
+	/// - Storage ops of `get_launch_token_owner`
+	pub fn get_royalty(
+		seller: &T::AccountId,
+		token: &Token<T>,
+		price: BalanceOf<T>,
+	) -> Option<(T::AccountId, BalanceOf<T>)> {
+		let launch_token = Self::launch_token(&token.launch_id)?;
+
+		let amount = launch_token.royalty.min(Self::max_royalty()) * price;
+		if amount.is_zero() {
+			return None
+		}
+
+		let recipient = Self::royalty_beneficiary(&token.launch_id)
+			.or_else(|| Self::launch_beneficiary(&token.launch_id))
+			.or_else(|| Self::get_launch_token_owner(&token.launch_id).map(|(owner, _)| owner))?;
+
+		(&recipient != seller).then(|| (recipient, amount))
+	}
+
+	/// Credit secondary sale price from buyer to seller or its payout account, less royalty
+	/// credited to the royalty recipient.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `get_royalty`
+	/// - Storage ops of `unchecked_credit_royalty`
+	/// - Storage ops of `get_payout_account`
+	/// - Storage ops of `unchecked_credit_proceeds` for seller
+	pub fn unchecked_pay_sale(
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		token: &Token<T>,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		let mut proceeds = price;
+
+		if let Some((recipient, royalty)) = Self::get_royalty(seller, token, price) {
+			Self::unchecked_credit_royalty(
+				buyer,
+				recipient,
+				token,
+				royalty,
+				Self::unchecked_credit_proceeds,
+			)?;
+			proceeds = proceeds.saturating_sub(royalty);
+		}
+
+		Self::unchecked_credit_proceeds(buyer, &Self::get_payout_account(seller), proceeds)?;
+
+		Ok(())
+	}
+
+	/// Credit secondary sale price reserved from buyer to seller or its payout account, less
+	/// royalty credited to the royalty recipient.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `get_royalty`
+	/// - Storage ops of `unchecked_credit_royalty`
+	/// - Storage ops of `get_payout_account`
+	/// - Storage ops of `unchecked_credit_reserved_proceeds` for seller
+	pub fn unchecked_pay_reserved_sale(
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		token: &Token<T>,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		let mut proceeds = price;
+
+		if let Some((recipient, royalty)) = Self::get_royalty(seller, token, price) {
+			Self::unchecked_credit_royalty(
+				buyer,
+				recipient,
+				token,
+				royalty,
+				Self::unchecked_credit_reserved_proceeds,
+			)?;
+			proceeds = proceeds.saturating_sub(royalty);
+		}
+
+		Self::unchecked_credit_reserved_proceeds(
+			buyer,
+			&Self::get_payout_account(seller),
+			proceeds,
+		)?;
+
+		Ok(())
+	}
+
+	/// Credit royalty from buyer with `credit` to the royalty recipient.
+	///
+	/// Royalties that together with pending proceeds stay below the existential deposit of a
+	/// recipient account that does not exist are credited to the dust treasury if one is
+	/// configured, so sales never leave unwithdrawable dust.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get balance of recipient `Balances`
+	/// - One storage read to get proceeds of recipient `PendingProceeds<T>`
+	/// - Storage ops of `credit`
+	pub fn unchecked_credit_royalty(
+		buyer: &T::AccountId,
+		recipient: T::AccountId,
+		token: &Token<T>,
+		royalty: BalanceOf<T>,
+		credit: fn(&T::AccountId, &T::AccountId, BalanceOf<T>) -> Result<(), Error<T>>,
+	) -> Result<(), Error<T>> {
+		if let Some(treasury) = T::RoyaltyDustTreasury::get() {
+			let pending = Self::pending_proceeds(&recipient).saturating_add(royalty);
+			if T::Currency::total_balance(&recipient).is_zero() &&
+				pending < T::Currency::minimum_balance()
+			{
+				credit(buyer, &treasury, royalty)?;
+				Self::deposit_event(Event::<T>::RoyaltyDustRouted(recipient, token.id, royalty));
+
+				return Ok(())
+			}
+		}
+
+		credit(buyer, &recipient, royalty)?;
+		Self::deposit_event(Event::<T>::RoyaltyPaid(recipient, token.id, royalty));
+
+		Ok(())
+	}
+
+	/// Weight of clamping royalty of a single launch token.
+	pub fn clamp_royalty_step_weight() -> Weight {
+		weights::LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	/// Lower royalty of launch token at `index` to `max_royalty` if it is higher.
+	///
+	/// Launch token ids start at 1, so the launch token at `index` has id `index + 1`.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update launch token `LaunchTokens<T>`
+	pub fn clamp_royalty_step(max_royalty: &Permill, index: u32) {
+		let launch_token_id = TokenId::from(index).saturating_add(1);
+
+		let clamped =
+			LaunchTokens::<T>::mutate(&launch_token_id, |launch_token| match launch_token {
+				Some(launch_token) if launch_token.royalty > *max_royalty => {
+					launch_token.royalty = *max_royalty;
+					true
+				},
+				_ => false,
+			});
+
+		if clamped {
+			Self::deposit_event(Event::<T>::LaunchRoyaltyClamped(launch_token_id, *max_royalty));
+		}
+	}
+
+	/// Ensure royalty does not exceed the max royalty.
+	pub fn ensure_valid_royalty(royalty: &Permill) -> Result<(), Error<T>> {
+		ensure!(*royalty <= Self::max_royalty(), Error::<T>::RoyaltyTooHigh);
+
+		Ok(())
+	}
+
+	/// Ensure token can be transferred to receiver without a sale.
+	///
+	/// Tokens of royalty enforced launch tokens can only be transferred to registered marketplaces,
+	/// other transfers have to go through sales paying royalties.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token by id `Tokens<T>`
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read to check receiver is a marketplace `Marketplaces<T>`
+	pub fn ensure_transfer_respects_royalty(
+		token_id: &TokenId,
+		receiver: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		let enforced = Self::token(token_id)
+			.and_then(|token| Self::launch_token(token.launch_id))
+			.map_or(false, |launch_token| launch_token.royalty_enforced);
+
+		ensure!(
+			!enforced || Marketplaces::<T>::contains_key(receiver),
+			Error::<T>::RoyaltyEnforced
+		);
+
+		Ok(())
+	}
+
+	/// Clear default beneficiary of creator.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update launch defaults of creator `LaunchDefaultsForCreator<T>`
+	pub fn clear_default_beneficiary(creator_id: &CreatorId) {
+		LaunchDefaultsForCreator::<T>::mutate(creator_id, |defaults| {
+			if let Some(defaults) = defaults {
+				defaults.beneficiary = None;
+			}
+		});
+	}
+}
@@ -0,0 +1,185 @@
+use crate::{
+	weights, AttestationCounts, Attesters, Config, CreatorId, Error, Pallet, SocialAttestation,
+	SocialAttestationOf, SocialAttestations, SocialAttestationsByAttester, SocialHandle,
+	SocialPlatform, SocialProofOf,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Verify},
+	sp_std::iter,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Verify social proof is signed by an attester for the current owner of creator account and
+	/// has not expired.
+	///
+	/// **Storage ops**
+	/// - One storage read to check attester `Attesters<T>`
+	pub fn ensure_social_proof_valid(
+		creator_id: &CreatorId,
+		owner: &T::AccountId,
+		platform: &SocialPlatform,
+		handle: &SocialHandle,
+		proof: &SocialProofOf<T>,
+	) -> Result<(), Error<T>> {
+		// ensure proof is signed by an attester
+		ensure!(Attesters::<T>::contains_key(&proof.attester), Error::<T>::AttesterNotFound);
+
+		// ensure proof has not expired
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < proof.expires_at,
+			Error::<T>::SocialProofExpired
+		);
+
+		let payload = (creator_id, owner, platform, handle, proof.expires_at).encode();
+		ensure!(
+			proof.signature.verify(&payload[..], &proof.attester),
+			Error::<T>::InvalidSocialProof
+		);
+
+		Ok(())
+	}
+
+	/// Save attested social handle of creator account, replacing any previous handle on platform.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to get previous attestation on platform `SocialAttestations<T>`
+	/// - Storage ops of `unindex_social_attestation` if platform was attested
+	/// - One storage read per attested platform if platform was not attested
+	///   `SocialAttestations<T>`
+	/// - One storage write to save attestation `SocialAttestations<T>`
+	/// - Storage ops of `index_social_attestation`
+	pub fn unchecked_attest_social(
+		creator_id: &CreatorId,
+		platform: &SocialPlatform,
+		handle: SocialHandle,
+		attester: T::AccountId,
+	) -> Result<(), Error<T>> {
+		match SocialAttestations::<T>::get(creator_id, platform) {
+			Some(previous) =>
+				Self::unindex_social_attestation(&previous.attester, creator_id, platform),
+			None => {
+				// ensure creator account has not reached the max attested platforms
+				let max_platforms = T::MaxSocialPlatforms::get() as usize;
+				ensure!(
+					SocialAttestations::<T>::iter_key_prefix(creator_id)
+						.take(max_platforms)
+						.count() < max_platforms,
+					Error::<T>::MaxSocialPlatformsReached
+				);
+			},
+		}
+
+		Self::index_social_attestation(&attester, creator_id, platform);
+		SocialAttestations::<T>::insert(
+			creator_id,
+			platform,
+			SocialAttestation {
+				handle,
+				attester,
+				block: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		Ok(())
+	}
+
+	/// Remove attested social handle of creator account on platform.
+	///
+	/// Returns the removed attestation.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to remove attestation `SocialAttestations<T>`
+	/// - Storage ops of `unindex_social_attestation` if platform was attested
+	pub fn revoke_social_attestation(
+		creator_id: &CreatorId,
+		platform: &SocialPlatform,
+	) -> Option<SocialAttestationOf<T>> {
+		let attestation = SocialAttestations::<T>::take(creator_id, platform)?;
+		Self::unindex_social_attestation(&attestation.attester, creator_id, platform);
+
+		Some(attestation)
+	}
+
+	/// Remove all attested social handles of creator account and its sub-creators.
+	///
+	/// Attestations vouch for the owner at the time of attestation, so they are cleared whenever
+	/// the creator account changes owner.
+	///
+	/// **Storage ops**
+	/// - One storage read to get sub-creator ids of creator `SubCreatorIdsForCreator<T>`
+	/// - One storage read-write per attested platform of creator and each sub-creator, up to
+	///   `MaxSocialPlatforms` each, to remove attestation `SocialAttestations<T>`
+	/// - Storage ops of `unindex_social_attestation` per attested platform
+	pub fn clear_social_attestations(creator_id: &CreatorId) {
+		let sub_creator_ids = Self::sub_creator_ids_for_creator(creator_id);
+
+		for creator_id in sub_creator_ids.iter().chain(iter::once(creator_id)) {
+			for (platform, attestation) in SocialAttestations::<T>::drain_prefix(creator_id) {
+				Self::unindex_social_attestation(&attestation.attester, creator_id, &platform);
+			}
+		}
+	}
+
+	/// Weight of removing a single attestation of a removed attester.
+	pub fn clear_attestation_step_weight() -> Weight {
+		weights::LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+
+	/// Remove the next remaining attestation of removed attester.
+	///
+	/// **Storage ops**
+	/// - One storage read to get next attestation of attester `SocialAttestationsByAttester<T>`
+	/// - One storage read-write to remove attestation if still held by attester
+	///   `SocialAttestations<T>`
+	/// - Storage ops of `unindex_social_attestation`
+	pub fn clear_attestation_step(attester: &T::AccountId) {
+		let (creator_id, platform) =
+			match SocialAttestationsByAttester::<T>::iter_key_prefix(attester).next() {
+				Some(key) => key,
+				None => return,
+			};
+
+		SocialAttestations::<T>::mutate_exists(&creator_id, &platform, |attestation| {
+			if attestation
+				.as_ref()
+				.map_or(false, |attestation| attestation.attester == *attester)
+			{
+				*attestation = None;
+			}
+		});
+		Self::unindex_social_attestation(attester, &creator_id, &platform);
+	}
+
+	/// Add attestation to the index of its attester.
+	///
+	/// **Storage ops**
+	/// - One storage write to index attestation `SocialAttestationsByAttester<T>`
+	/// - One storage read-write to increase attestation count of attester `AttestationCounts<T>`
+	fn index_social_attestation(
+		attester: &T::AccountId,
+		creator_id: &CreatorId,
+		platform: &SocialPlatform,
+	) {
+		SocialAttestationsByAttester::<T>::insert(attester, (creator_id, platform), ());
+		AttestationCounts::<T>::mutate(attester, |count| *count = count.saturating_add(1));
+	}
+
+	/// Remove attestation from the index of its attester.
+	///
+	/// **Storage ops**
+	/// - One storage write to unindex attestation `SocialAttestationsByAttester<T>`
+	/// - One storage read-write to decrease attestation count of attester `AttestationCounts<T>`
+	fn unindex_social_attestation(
+		attester: &T::AccountId,
+		creator_id: &CreatorId,
+		platform: &SocialPlatform,
+	) {
+		SocialAttestationsByAttester::<T>::remove(attester, (creator_id, platform));
+		AttestationCounts::<T>::mutate_exists(attester, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+		});
+	}
+}
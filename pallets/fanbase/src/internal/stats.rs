@@ -0,0 +1,11 @@
+use crate::{Config, CreatorId, CreatorStats, CreatorStatsOf, Pallet};
+
+impl<T: Config> Pallet<T> {
+	/// Update aggregate token counters of creator.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator stats `CreatorStatsOf<T>`
+	pub fn update_creator_stats(creator_id: &CreatorId, f: impl FnOnce(&mut CreatorStats)) {
+		CreatorStatsOf::<T>::mutate(creator_id, f);
+	}
+}
@@ -0,0 +1,78 @@
+use crate::{Config, CreatorId, Error, LastActive, Pallet, Succession, Successions};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{BadOrigin, Saturating},
+};
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Ensure origin is signed and record activity of the signing account if it is tracked by a
+	/// creator succession.
+	///
+	/// Returns the signing account.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update last activity of account if tracked `LastActive<T>`
+	pub fn ensure_signed_tracked(origin: OriginFor<T>) -> Result<T::AccountId, BadOrigin> {
+		let account = ensure_signed(origin)?;
+
+		LastActive::<T>::mutate_exists(&account, |last_active| {
+			if let Some(last_active) = last_active {
+				*last_active = frame_system::Pallet::<T>::block_number();
+			}
+		});
+
+		Ok(account)
+	}
+
+	/// Start tracking activity of account from the current block.
+	///
+	/// **Storage ops**
+	/// - One storage write to set last activity of account `LastActive<T>`
+	pub fn track_activity(account: &T::AccountId) {
+		LastActive::<T>::insert(account, frame_system::Pallet::<T>::block_number());
+	}
+
+	/// Ensure owner of creator account has been inactive for the threshold of its succession.
+	///
+	/// Owners whose activity is not tracked are never considered inactive.
+	///
+	/// **Storage ops**
+	/// - One storage read to get last activity of owner `LastActive<T>`
+	pub fn ensure_owner_inactive(
+		owner: &T::AccountId,
+		threshold: T::BlockNumber,
+	) -> Result<(), Error<T>> {
+		let last_active = Self::last_active(owner).ok_or(Error::<T>::OwnerStillActive)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= last_active.saturating_add(threshold),
+			Error::<T>::OwnerStillActive
+		);
+
+		Ok(())
+	}
+
+	/// Set succession of creator account and track activity of its owner from now on.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `track_activity`
+	/// - One storage write to save succession `Successions<T>`
+	pub fn unchecked_set_succession(
+		creator_id: &CreatorId,
+		owner: &T::AccountId,
+		succession: Succession<T>,
+	) {
+		Self::track_activity(owner);
+		Successions::<T>::insert(creator_id, succession);
+	}
+
+	/// Remove succession of creator account.
+	///
+	/// **Storage ops**
+	/// - One storage write to remove succession `Successions<T>`
+	pub fn clear_succession(creator_id: &CreatorId) {
+		Successions::<T>::remove(creator_id);
+	}
+}
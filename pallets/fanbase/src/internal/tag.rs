@@ -0,0 +1,17 @@
+use crate::{Config, Error, Pallet, Tags, TokenTags};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Ensure tags exist in the taxonomy and are not repeated.
+	///
+	/// **Storage ops**
+	/// - One storage read per tag to check tag exists `Tags<T>`
+	pub fn ensure_valid_tags(tags: &TokenTags) -> Result<(), Error<T>> {
+		for (index, tag_id) in tags.iter().enumerate() {
+			ensure!(Tags::<T>::contains_key(tag_id), Error::<T>::TagNotFound);
+			ensure!(!tags[..index].contains(tag_id), Error::<T>::DuplicateTag);
+		}
+
+		Ok(())
+	}
+}
@@ -1,31 +1,55 @@
 use crate::{
-	BalanceOf, Config, CreatorId, Error, IssuanceNonce, LaunchIssuanceNonce, LaunchToken,
-	LaunchTokenIdsForCreator, LaunchTokenMetadata, LaunchTokens, Pallet, Token, TokenId,
-	TokenIdsForAccount, Tokens,
+	traits::{EnsureTransferAllowed, PriceOracle},
+	types::{LaunchState, PriceMode},
+	BalanceOf, Config, CreatorId, DutchListing, DutchListings, Error, Event, IssuanceNonce,
+	LastPriceChange, LaunchBeneficiaries, LaunchIssuanceNonce, LaunchToken,
+	LaunchTokenIdsForCreator, LaunchTokenMetadata, LaunchTokenState, LaunchTokenStates,
+	LaunchTokens, MilestoneEscrows, MintQuota, OfferFloors, Pallet, Token, TokenId,
+	TokenIdsForAccount, TokenMetadata, Tokens,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, Perbill},
 };
-use frame_support::pallet_prelude::*;
 
 impl<T: Config> Pallet<T> {
 	/// Mint new launch token with provided price and metadata for creator.
 	///
+	/// Launch defaults of creator are inherited unless overridden in metadata.
+	///
 	/// Returns created launch token id.
 	///
 	/// *Unchecked!*
 	///
 	/// **Storage ops**
+	/// - One storage read-write to consume creator mint quota `MintQuota<T>`
 	/// - One storage read to get launch token issuance `LaunchIssuanceNonce<T>`
+	/// - One storage read to ensure launch token id is unused `LaunchTokens<T>`
 	/// - One storage read-write to add launch token id to creator `LaunchTokenIdsForCreator<T>`
+	/// - One storage read to get launch defaults of creator `LaunchDefaultsForCreator<T>`
+	/// - One storage read to get max royalty capping the default royalty `Parameters<T>`
+	/// - One storage write to save default beneficiary `LaunchBeneficiaries<T>`
+	/// - Storage ops of `index_launch_state`
+	/// - Storage ops of `index_launch_name`
+	/// - Storage ops of `check_duplicate_content`
 	/// - One storage write to save launch token `LaunchTokens<T>`
+	/// - One storage write to save launch token state `LaunchTokenStates<T>`
 	/// - One storage write to update launch token issuance `LaunchIssuanceNonce<T>`
+	/// - Storage ops of `update_creator_stats`
 	pub fn unchecked_mint(
 		creator_id: CreatorId,
 		price: BalanceOf<T>,
-		metadata: LaunchTokenMetadata,
+		mut metadata: LaunchTokenMetadata,
 	) -> Result<TokenId, Error<T>> {
-		// generate next launch token id
-		let next_token_id = Self::launch_issuance_nonce()
+		// consume mint quota for current period
+		Self::consume_mint_quota(&creator_id)?;
+
+		// get next launch token id
+		let nonce = Self::launch_issuance_nonce()
 			.checked_add(1)
 			.ok_or(Error::<T>::LaunchTokensOverflow)?;
+		let next_token_id = nonce;
+		ensure!(!LaunchTokens::<T>::contains_key(&next_token_id), Error::<T>::TokenIdTaken);
 
 		// add launch token id to creator
 		LaunchTokenIdsForCreator::<T>::try_mutate(&creator_id, |launch_token_ids| {
@@ -34,82 +58,297 @@ impl<T: Config> Pallet<T> {
 				.map_err(|_| Error::<T>::MaxLaunchTokensReached)
 		})?;
 
+		// inherit creator launch defaults
+		if let Some(defaults) = Self::launch_defaults_for_creator(&creator_id) {
+			metadata.royalty = metadata.royalty.or(Some(defaults.royalty.min(Self::max_royalty())));
+			if let Some(beneficiary) = defaults.beneficiary {
+				LaunchBeneficiaries::<T>::insert(&next_token_id, beneficiary);
+			}
+		}
+
+		Self::update_creator_stats(&creator_id, |stats| {
+			stats.launches = stats.launches.saturating_add(1);
+		});
+
 		// save launch token
-		LaunchTokens::<T>::insert(
-			&next_token_id,
-			LaunchToken::new(next_token_id, creator_id, price, metadata),
-		);
+		let launch_token_state = LaunchTokenState::new(&metadata);
+		let launch_token = LaunchToken::new(next_token_id, creator_id, price, metadata);
+		Self::index_launch_state(&next_token_id, &launch_token_state);
+		if Self::index_launch_name(&launch_token) {
+			Self::deposit_event(Event::<T>::DuplicateLaunchName(
+				launch_token.creator.clone(),
+				next_token_id,
+			));
+		}
+		Self::check_duplicate_content(&launch_token)?;
+		LaunchTokens::<T>::insert(&next_token_id, launch_token);
+		LaunchTokenStates::<T>::insert(&next_token_id, launch_token_state);
 
 		// update nonce
-		LaunchIssuanceNonce::<T>::set(next_token_id);
+		LaunchIssuanceNonce::<T>::set(nonce);
 
 		Ok(next_token_id)
 	}
 
+	/// Count a mint against the creator's quota for the current mint period.
+	///
+	/// Starts a new period if the previous one has elapsed.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update creator mint quota `MintQuota<T>`
+	pub fn consume_mint_quota(creator_id: &CreatorId) -> Result<(), Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		MintQuota::<T>::try_mutate(creator_id, |quota| {
+			let (period_start, minted) = match quota {
+				Some((period_start, minted))
+					if now < period_start.saturating_add(T::MintPeriod::get()) =>
+					(*period_start, *minted),
+				// start a new period
+				_ => (now, 0),
+			};
+
+			ensure!(minted < T::MaxMintsPerPeriod::get(), Error::<T>::MintQuotaExceeded);
+
+			*quota = Some((period_start, minted.saturating_add(1)));
+
+			Ok(())
+		})
+	}
+
+	/// Get token from the public supply of launch token and transfer to account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `unchecked_issue`
+	pub fn unchecked_launch_transfer(
+		receiver: &T::AccountId,
+		launch_token_id: &TokenId,
+	) -> Result<TokenId, Error<T>> {
+		Self::unchecked_issue(receiver, launch_token_id, false)
+	}
+
+	/// Get token from the gift supply of launch token and transfer to account.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `unchecked_issue`
+	pub fn unchecked_launch_gift(
+		receiver: &T::AccountId,
+		launch_token_id: &TokenId,
+	) -> Result<TokenId, Error<T>> {
+		Self::unchecked_issue(receiver, launch_token_id, true)
+	}
+
 	/// Get token from launch token and transfer to account.
 	///
+	/// Gifts are issued from the gift supply, other issuance from the public supply.
+	///
 	/// *Unchecked!*
 	///
 	/// **Storage ops**
-	/// - One storage read to get token issuance `IssuanceNonce<T>`
 	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read to get launch token state `LaunchTokenStates<T>`
+	/// - One storage read to get token issuance `IssuanceNonce<T>`
+	/// - One storage read to ensure token id is unused `Tokens<T>`
 	/// - One storage read-write to add token id to receiver account `TokenIdsForAccount<T>`
 	/// - One storage write to save token `Tokens<T>`
-	/// - One storage write to update launch token internal issuance `LaunchTokens<T>`
+	/// - One storage write to update launch token issuance counters `LaunchTokenStates<T>`
 	/// - One storage write to update token issuance `IssuanceNonce<T>`
-	pub fn unchecked_launch_transfer(
+	/// - Storage ops of `track_acquired`
+	/// - Storage ops of `unchecked_set_launch_state` if public supply is exhausted
+	/// - Storage ops of `record_successful_launch` if launch token sold out
+	/// - Storage ops of `update_creator_stats`
+	fn unchecked_issue(
 		receiver: &T::AccountId,
 		launch_token_id: &TokenId,
+		gift: bool,
 	) -> Result<TokenId, Error<T>> {
+		// get launch token
+		let launch_token = Self::launch_token(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+		let mut launch_token_state =
+			Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
 		// generate next token id
+		let nonce = Self::issuance_nonce().checked_add(1).ok_or(Error::<T>::TokensOverflow)?;
 		let next_token_id =
-			Self::issuance_nonce().checked_add(1).ok_or(Error::<T>::TokensOverflow)?;
+			T::TokenIdGenerator::next_token_id(nonce, launch_token_id, launch_token_state.issued)
+				.ok_or(Error::<T>::TokensOverflow)?;
+		ensure!(!Tokens::<T>::contains_key(&next_token_id), Error::<T>::TokenIdTaken);
+
+		// ensure launch token state allows issuance, gifts continue once sold out
+		match launch_token_state.state {
+			LaunchState::Live => (),
+			LaunchState::SoldOut if gift => (),
+			LaunchState::SoldOut => return Err(Error::<T>::TokenSoldOut),
+			LaunchState::Upcoming => return Err(Error::<T>::LaunchIsDraft),
+			LaunchState::Paused => return Err(Error::<T>::LaunchPaused),
+			LaunchState::Cancelled => return Err(Error::<T>::LaunchCancelled),
+			LaunchState::Frozen => return Err(Error::<T>::LaunchFrozen),
+		}
 
-		// get launch token
-		let launch_token = Self::launch_tokens(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+		// ensure issuance does not exceed gift or public supply
+		if gift {
+			ensure!(launch_token_state.available_for_gift() > 0, Error::<T>::GiftSupplyExhausted);
+		} else {
+			ensure!(launch_token_state.available_for_sale() > 0, Error::<T>::TokenSoldOut);
+		}
 
-		// ensure issuance does not exceed total supply
-		if launch_token.issued < launch_token.total_supply() {
-			// add token id to account
-			TokenIdsForAccount::<T>::try_mutate(receiver, |token_ids| {
-				token_ids.try_push(next_token_id).map_err(|_| Error::<T>::MaxTokensReached)
-			})?;
+		// add token id to account
+		TokenIdsForAccount::<T>::try_mutate(receiver, |token_ids| {
+			token_ids.try_push(next_token_id).map_err(|_| Error::<T>::MaxTokensReached)
+		})?;
 
-			// save token
-			Tokens::<T>::insert(
-				&next_token_id,
-				Token::new(receiver.clone(), next_token_id, launch_token),
-			);
+		let creator_id = launch_token.creator.clone();
 
-			// update launch token
-			LaunchTokens::<T>::mutate(launch_token_id, |launch_token| {
-				// unwrap because we are sure launch_token exists
-				launch_token.as_mut().unwrap().bump_issued();
-			});
+		// save token
+		Tokens::<T>::insert(
+			&next_token_id,
+			Token::new(
+				receiver.clone(),
+				next_token_id,
+				launch_token,
+				frame_system::Pallet::<T>::block_number(),
+			),
+		);
+
+		// update launch token state
+		launch_token_state.bump_issued();
+		if gift {
+			launch_token_state.bump_gifted();
+		}
+		if launch_token_state.state == LaunchState::Live {
+			let state = launch_token_state.live_state();
+			Self::unchecked_set_launch_state(launch_token_id, &mut launch_token_state, state);
+		}
+		let sold_out = launch_token_state.issued >= launch_token_state.total_supply();
+		LaunchTokenStates::<T>::insert(launch_token_id, launch_token_state);
+		if sold_out {
+			Self::record_successful_launch(&creator_id);
+		}
+		Self::update_creator_stats(&creator_id, |stats| {
+			stats.issued = stats.issued.saturating_add(1);
+		});
+
+		// update nonce
+		IssuanceNonce::<T>::set(nonce);
+
+		Self::track_acquired(launch_token_id, receiver, &next_token_id);
+
+		Ok(next_token_id)
+	}
 
-			// update nonce
-			IssuanceNonce::<T>::set(next_token_id);
+	/// Issue token from launch token to buyer at launch price.
+	///
+	/// Returns the issued token id.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `consume_block_purchase`
+	/// - Storage ops of `unchecked_launch_transfer`
+	/// - Storage ops of `unchecked_pay_launch_sale`
+	pub fn unchecked_launch_buy(
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		launch_token: &LaunchToken<T>,
+		price: BalanceOf<T>,
+	) -> Result<TokenId, Error<T>> {
+		// count purchase against per block purchase cap
+		Self::consume_block_purchase(&launch_token.id, buyer)?;
+
+		// transfer token to buyer from launch token
+		let token_id = Self::unchecked_launch_transfer(buyer, &launch_token.id)?;
+
+		Self::unchecked_pay_launch_sale(buyer, seller, launch_token, &token_id, price)?;
 
-			Ok(next_token_id)
+		Ok(token_id)
+	}
+
+	/// Pay for token issued from launch token to buyer.
+	///
+	/// The redeemable share of the price is held in reserve, the rest is credited to the launch
+	/// token beneficiary or seller if none is set, or held in escrow if the launch token has
+	/// milestones. Purchases are matched from the creator's active matching pool.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read to check milestones of launch token `MilestoneEscrows<T>`
+	/// - One storage read to get launch token beneficiary `LaunchBeneficiaries<T>`
+	/// - Storage ops of `unchecked_credit_proceeds` if launch token has no milestones
+	/// - Storage ops of `unchecked_escrow_proceeds` if launch token has milestones
+	/// - Storage ops of `unchecked_match_purchase`
+	/// - Storage ops of `unchecked_lock_redeemable`
+	/// - Storage ops of `record_sale` and `record_creator_sale`
+	/// - Storage ops of `T::OnPurchase`
+	/// - Storage ops of `unchecked_issue_receipt` if launch token issues receipts
+	pub fn unchecked_pay_launch_sale(
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		launch_token: &LaunchToken<T>,
+		token_id: &TokenId,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		// split redeemable share from creator proceeds
+		let redeemable = launch_token.redeemable * price;
+
+		let proceeds = price.saturating_sub(redeemable);
+		let beneficiary =
+			Self::launch_beneficiary(&launch_token.id).unwrap_or_else(|| seller.clone());
+		if MilestoneEscrows::<T>::contains_key(&launch_token.id) {
+			// hold proceeds in escrow until released by milestones
+			Self::unchecked_escrow_proceeds(buyer, &launch_token.id, proceeds)?;
 		} else {
-			Err(Error::<T>::TokenSoldOut)
+			// credit funds to beneficiary
+			Self::unchecked_credit_proceeds(buyer, &beneficiary, proceeds)?;
 		}
+
+		// match purchase from sponsor pool of creator
+		Self::unchecked_match_purchase(&launch_token.creator, &beneficiary, price);
+
+		// hold redeemable share in reserve
+		Self::unchecked_lock_redeemable(buyer, token_id, redeemable)?;
+
+		Self::record_sale(&launch_token.id, *token_id, seller.clone(), buyer.clone(), price)?;
+		Self::record_creator_sale(&launch_token.creator, price);
+
+		T::OnPurchase::on_purchase(buyer, &launch_token.creator, price);
+
+		if launch_token.receipts {
+			Self::unchecked_issue_receipt(buyer, &launch_token.id, token_id, price);
+		}
+
+		Ok(())
 	}
 
 	/// Remove token from owner and transfer to receiver.
 	///
-	/// *Unchecked!*
+	/// *Unchecked!* Only the configured transfer filter is consulted.
 	///
 	/// **Storage ops**
 	/// - One storage read to get token by id `Tokens<T>`
 	/// - One storage read-write to add token id to receiver account `TokenIdsForAccount<T>`
 	/// - One storage read-write to remove token id from owner account `TokenIdsForAccount<T>`
-	/// - One storage write to update token owner `Tokens<T>`
+	/// - One storage write to update token owner and clear token price `Tokens<T>`
+	/// - One storage write to clear last price change `LastPriceChange<T>`
+	/// - One storage write to clear dutch listing `DutchListings<T>`
+	/// - One storage write to clear offer floor `OfferFloors<T>`
+	/// - Storage ops of `unindex_listing`
+	/// - Storage ops of `track_released` and `track_acquired`
 	pub fn unchecked_transfer(
 		owner: &T::AccountId,
 		receiver: &T::AccountId,
 		token_id: &TokenId,
 	) -> Result<(), Error<T>> {
+		// ensure transfer is allowed
+		ensure!(
+			T::TransferFilter::is_transfer_allowed(owner, receiver, token_id),
+			Error::<T>::TransferNotAllowed
+		);
+
 		Tokens::<T>::try_mutate(token_id, |token| {
 			// check if token exists
 			let token = token.as_mut().ok_or(Error::<T>::TokenNotFound)?;
@@ -129,11 +368,47 @@ impl<T: Config> Pallet<T> {
 
 			// update token owner
 			token.owner = receiver.clone();
+			Self::track_released(&token.launch_id, owner, token_id);
+			Self::track_acquired(&token.launch_id, receiver, token_id);
+
+			// listing, price history and offer floor do not carry over to the new owner
+			token.price = None;
+			Self::unindex_listing(token);
+			DutchListings::<T>::remove(token_id);
+			LastPriceChange::<T>::remove(token_id);
+			OfferFloors::<T>::remove(token_id);
 
 			Ok(())
 		})
 	}
 
+	/// Buy token from its owner at price.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - Storage ops of `unchecked_transfer`
+	/// - Storage ops of `unchecked_pay_sale`
+	/// - Storage ops of `record_sale`
+	/// - Storage ops of `T::OnPurchase`
+	pub fn unchecked_buy(
+		buyer: &T::AccountId,
+		token: &Token<T>,
+		price: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		// transfer token from owner to buyer
+		Self::unchecked_transfer(&token.owner, buyer, &token.id)?;
+
+		// transfer funds, less royalty
+		Self::unchecked_pay_sale(buyer, &token.owner, token, price)?;
+
+		Self::record_sale(&token.launch_id, token.id, token.owner.clone(), buyer.clone(), price)?;
+
+		T::OnPurchase::on_purchase(buyer, &token.creator, price);
+
+		Ok(())
+	}
+
 	/// Set price for launch token.
 	///
 	/// *Unchecked!*
@@ -161,6 +436,9 @@ impl<T: Config> Pallet<T> {
 	///
 	/// **Storage ops**
 	/// - One storage read-write to update token price `Tokens<T>`
+	/// - One storage write to record last price change if price is set `LastPriceChange<T>`
+	/// - One storage write to clear dutch listing `DutchListings<T>`
+	/// - Storage ops of `index_listing`
 	pub fn unchecked_set_price(
 		token_id: &TokenId,
 		price: Option<BalanceOf<T>>,
@@ -169,8 +447,18 @@ impl<T: Config> Pallet<T> {
 			// check if token exists
 			let token = token.as_mut().ok_or(Error::<T>::TokenNotFound)?;
 
-			// update price
+			// update price, any price change ends a dutch listing
 			token.price = price;
+			DutchListings::<T>::remove(token_id);
+			Self::index_listing(token);
+
+			// record price change
+			if let Some(price) = price {
+				LastPriceChange::<T>::insert(
+					token_id,
+					(frame_system::Pallet::<T>::block_number(), price),
+				);
+			}
 
 			Ok(())
 		})
@@ -184,9 +472,15 @@ impl<T: Config> Pallet<T> {
 	/// - One storage read to get token by id `Tokens<T>`
 	/// - One storage read-write to remove token id from token owner account `TokenIdsForAccount<T>`
 	/// - One storage write to remove token `Tokens<T>`
-	/// - One storage read-write to update launch token internal issuance `LaunchTokens<T>`
+	/// - One storage write to clear last price change `LastPriceChange<T>`
+	/// - One storage write to clear dutch listing `DutchListings<T>`
+	/// - One storage write to clear offer floor `OfferFloors<T>`
+	/// - Storage ops of `unindex_listing`
+	/// - Storage ops of `track_released`
+	/// - One storage read-write to update launch token issuance counters `LaunchTokenStates<T>`
+	/// - Storage ops of `update_creator_stats`
 	pub fn unchecked_burn(token_id: &TokenId) -> Result<(), Error<T>> {
-		let token = Self::tokens(token_id).ok_or(Error::<T>::TokenNotFound)?;
+		let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
 
 		// remove token id from owner
 		TokenIdsForAccount::<T>::mutate(&token.owner, |token_ids| {
@@ -198,11 +492,19 @@ impl<T: Config> Pallet<T> {
 
 		// remove token
 		Tokens::<T>::remove(&token.id);
-
-		// update launch token
-		LaunchTokens::<T>::mutate(&token.launch_id, |launch_token| {
-			// unwrap because we are sure launch_token exists
-			launch_token.as_mut().unwrap().bump_destroyed_and_decrease_supply();
+		LastPriceChange::<T>::remove(&token.id);
+		DutchListings::<T>::remove(&token.id);
+		OfferFloors::<T>::remove(&token.id);
+		Self::unindex_listing(&token);
+		Self::track_released(&token.launch_id, &token.owner, &token.id);
+
+		// update launch token state
+		LaunchTokenStates::<T>::mutate(&token.launch_id, |launch_token_state| {
+			// unwrap because we are sure launch_token_state exists
+			launch_token_state.as_mut().unwrap().bump_destroyed_and_decrease_supply();
+		});
+		Self::update_creator_stats(&token.creator, |stats| {
+			stats.destroyed = stats.destroyed.saturating_add(1);
 		});
 
 		Ok(())
@@ -217,7 +519,7 @@ impl<T: Config> Pallet<T> {
 		launch_token_id: &TokenId,
 	) -> Result<(), Error<T>> {
 		ensure!(
-			Self::launch_tokens(launch_token_id)
+			Self::launch_token(launch_token_id)
 				.map_or(false, |launch_token| &launch_token.creator == creator_id),
 			Error::<T>::NotOwner
 		);
@@ -234,25 +536,173 @@ impl<T: Config> Pallet<T> {
 		token_id: &TokenId,
 	) -> Result<(), Error<T>> {
 		ensure!(
-			Self::tokens(token_id).map_or(false, |token| token.owner == *account),
+			Self::token(token_id).map_or(false, |token| token.owner == *account),
 			Error::<T>::NotOwner
 		);
 
 		Ok(())
 	}
 
-	/// Get launch token owner if launch token exists and it's creator's owner has not been disconnected.
+	/// Ensure account can transfer token to receiver without a sale.
+	///
+	/// **Storage ops**
+	/// - Storage ops of `ensure_account_owns_token`
+	/// - Storage ops of `ensure_token_unlocked`
+	/// - Storage ops of `ensure_lockup_over`
+	/// - Storage ops of `ensure_transfer_respects_royalty`
+	pub fn ensure_token_transferable(
+		account: &T::AccountId,
+		token_id: &TokenId,
+		receiver: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		// ensure account owns token
+		Self::ensure_account_owns_token(account, token_id)?;
+
+		// ensure token is not locked
+		Self::ensure_token_unlocked(token_id)?;
+
+		// ensure token transfer lockup is over
+		Self::ensure_lockup_over(token_id)?;
+
+		// ensure transfer does not bypass enforced royalty
+		Self::ensure_transfer_respects_royalty(token_id, receiver)
+	}
+
+	/// Ensure price is not below the configured minimum token price.
+	pub fn ensure_valid_price(price: &BalanceOf<T>) -> Result<(), Error<T>> {
+		ensure!(*price >= T::MinTokenPrice::get(), Error::<T>::PriceTooLow);
+
+		Ok(())
+	}
+
+	/// Ensure launch price is not below the configured minimum token price.
+	///
+	/// Reference prices are not checked since their native value is only known at purchase time.
+	pub fn ensure_valid_launch_price(
+		price_mode: PriceMode,
+		price: &BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		match price_mode {
+			PriceMode::Native => Self::ensure_valid_price(price),
+			PriceMode::Reference => Ok(()),
+		}
+	}
+
+	/// Ensure price and metadata of new launch token are valid.
+	///
+	/// **Storage ops**
+	/// - One storage read to get max royalty `Parameters<T>`
+	/// - One storage read per tag to check tag exists `Tags<T>`
+	pub fn ensure_valid_launch_metadata(
+		price: &BalanceOf<T>,
+		metadata: &LaunchTokenMetadata,
+	) -> Result<(), Error<T>> {
+		// ensure price is not below minimum
+		Self::ensure_valid_launch_price(metadata.price_mode, price)?;
+
+		// ensure gift supply is part of supply
+		ensure!(metadata.gift_supply <= metadata.supply, Error::<T>::InvalidGiftSupply);
+
+		// ensure royalty does not exceed max royalty
+		if let Some(royalty) = &metadata.royalty {
+			Self::ensure_valid_royalty(royalty)?;
+		}
+
+		// ensure tags exist in taxonomy
+		Self::ensure_valid_tags(&metadata.tags)
+	}
+
+	/// Ensure token price is not raised above its last price before the price change cooldown
+	/// has elapsed.
+	///
+	/// Lowering the price is always allowed.
+	///
+	/// **Storage ops**
+	/// - One storage read to get last price change `LastPriceChange<T>`
+	pub fn ensure_price_change_allowed(
+		token_id: &TokenId,
+		price: &BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if let Some((changed_at, last_price)) = Self::last_price_change(token_id) {
+			let cooldown_ends = changed_at.saturating_add(T::PriceChangeCooldown::get());
+
+			ensure!(
+				*price <= last_price || frame_system::Pallet::<T>::block_number() >= cooldown_ends,
+				Error::<T>::PriceChangeTooSoon
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Get launch token owner if launch token exists and it's creator's owner has not been disconnected
+	/// and its registration has not expired.
+	///
+	/// Launch tokens of sub-creators are owned by the owner of the parent creator.
 	///
 	/// **Storage ops**
 	/// - One storage read to get launch token by id `LaunchTokens<T>`
-	/// - One storage read to get creator of launch token `Creators<T>`
+	/// - Two storage reads to resolve creator of launch token `Creators<T>`
 	pub fn get_launch_token_owner(launch_token_id: &TokenId) -> Option<(T::AccountId, CreatorId)> {
-		let creator = Self::launch_tokens(launch_token_id)
-			.and_then(|launch_token| Self::creators(launch_token.creator))?;
+		let launch_token = Self::launch_token(launch_token_id)?;
+
+		let creator = Self::resolve_creator(&launch_token.creator)
+			.filter(|creator| !Self::is_creator_expired(creator))?;
 
 		let owner = creator.owner?;
 
-		Some((owner, creator.id))
+		Some((owner, launch_token.creator))
+	}
+
+	/// Get metadata of token from its launch token, with the token's own metadata uri if set.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	pub fn get_token_metadata(token: &Token<T>) -> Option<TokenMetadata> {
+		let launch_token = Self::launch_token(token.launch_id)?;
+
+		Some(TokenMetadata {
+			name: launch_token.name,
+			mime_type: launch_token.mime_type,
+			metadata_uri: token.metadata_uri.clone().unwrap_or(launch_token.metadata_uri),
+		})
+	}
+
+	/// Get launch token price in native currency.
+	///
+	/// Reference prices are converted using the configured price oracle.
+	pub fn get_launch_price(launch_token: &LaunchToken<T>) -> Result<BalanceOf<T>, Error<T>> {
+		match launch_token.price_mode {
+			PriceMode::Native => Ok(launch_token.price),
+			PriceMode::Reference =>
+				T::PriceOracle::to_native(launch_token.price).ok_or(Error::<T>::PriceUnavailable),
+		}
+	}
+
+	/// Get current price of listed token, dutch listings are priced by block.
+	///
+	/// **Storage ops**
+	/// - One storage read to get dutch listing `DutchListings<T>`
+	pub fn get_current_price(token: &Token<T>) -> Option<BalanceOf<T>> {
+		token.price.map(|price| Self::get_listing_price(&token.id, price))
+	}
+
+	/// Get price of dutch listing at the current block.
+	///
+	/// The price declines linearly from start price to end price between the start and end block.
+	pub fn get_dutch_price(listing: &DutchListing<T>) -> BalanceOf<T> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		if now >= listing.ends_at {
+			return listing.end_price
+		}
+
+		let elapsed = now.saturating_sub(listing.starts_at);
+		let duration = listing.ends_at.saturating_sub(listing.starts_at);
+		let decline = Perbill::from_rational(elapsed, duration) *
+			listing.start_price.saturating_sub(listing.end_price);
+
+		listing.start_price.saturating_sub(decline)
 	}
 
 	/// Get token price if token exists and has a price.
@@ -260,6 +710,6 @@ impl<T: Config> Pallet<T> {
 	/// **Storage ops**
 	/// - One storage read to get token by id `Tokens<T>`
 	pub fn get_token_price(token_id: &TokenId) -> Option<BalanceOf<T>> {
-		Self::tokens(token_id).and_then(|token| token.price)
+		Self::token(token_id).and_then(|token| token.price)
 	}
 }
@@ -0,0 +1,123 @@
+use crate::{
+	Config, CreatorId, CreatorView, CreatorViewOf, Erc721Attribute, Erc721Metadata,
+	LaunchTokenView, LaunchTokenViewOf, Pallet, TokenId, TokenView, TokenViewOf,
+};
+use frame_support::sp_std::{prelude::*, vec};
+
+impl<T: Config> Pallet<T> {
+	/// Get frontend view of token.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token `Tokens<T>`
+	/// - Storage ops of `get_token_metadata`
+	pub fn token_view(token_id: &TokenId) -> Option<TokenViewOf<T>> {
+		let token = Self::token(token_id)?;
+		let metadata = Self::get_token_metadata(&token)?;
+
+		Some(TokenView {
+			id: token.id,
+			launch_id: token.launch_id,
+			creator: token.creator.into_inner(),
+			owner: token.owner,
+			name: metadata.name.into_inner(),
+			price: token.price,
+			mime_type: metadata.mime_type.into_inner(),
+			metadata_uri: metadata.metadata_uri.into_inner(),
+			metadata_version: token.metadata_version,
+			locked_until: token.locked_until,
+		})
+	}
+
+	/// Get frontend view of launch token.
+	///
+	/// **Storage ops**
+	/// - One storage read to get launch token `LaunchTokens<T>`
+	/// - One storage read to get launch token state `LaunchTokenStates<T>`
+	pub fn launch_token_view(launch_token_id: &TokenId) -> Option<LaunchTokenViewOf<T>> {
+		let launch_token = Self::launch_token(launch_token_id)?;
+		let launch_token_state = Self::launch_token_state(launch_token_id)?;
+
+		Some(LaunchTokenView {
+			id: launch_token.id,
+			creator: launch_token.creator.into_inner(),
+			name: launch_token.name.into_inner(),
+			price: launch_token.price,
+			price_mode: launch_token.price_mode,
+			royalty: launch_token.royalty,
+			mime_type: launch_token.mime_type.into_inner(),
+			metadata_uri: launch_token.metadata_uri.into_inner(),
+			tags: launch_token.tags.into_inner(),
+			metadata_version: launch_token.metadata_version,
+			supply: launch_token_state.supply,
+			issued: launch_token_state.issued,
+			destroyed: launch_token_state.destroyed,
+			gift_supply: launch_token_state.gift_supply,
+			gifted: launch_token_state.gifted,
+			state: launch_token_state.state,
+		})
+	}
+
+	/// Get frontend view of creator account.
+	///
+	/// **Storage ops**
+	/// - One storage read to get creator `Creators<T>`
+	/// - Storage ops of `resolve_creator`
+	pub fn creator_view(creator_id: &CreatorId) -> Option<CreatorViewOf<T>> {
+		let creator = Self::creator(creator_id)?;
+		let owner = Self::resolve_creator(creator_id).and_then(|resolved| resolved.owner);
+
+		Some(CreatorView {
+			expired: Self::is_creator_expired(&creator),
+			id: creator.id.into_inner(),
+			owner,
+			parent: creator.parent.map(|parent| parent.into_inner()),
+			expires_at: creator.expires_at,
+		})
+	}
+
+	/// Get metadata uri of token, resolved through its launch token.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token `Tokens<T>`
+	/// - Storage ops of `get_token_metadata`
+	pub fn token_uri(token_id: &TokenId) -> Option<Vec<u8>> {
+		let token = Self::token(token_id)?;
+
+		Self::get_token_metadata(&token).map(|metadata| metadata.metadata_uri.into_inner())
+	}
+
+	/// Get metadata of token in the ERC-721 metadata JSON schema.
+	///
+	/// Creator, mime type and tag names of the launch token are exposed as attributes.
+	///
+	/// **Storage ops**
+	/// - One storage read to get token `Tokens<T>`
+	/// - One storage read to get launch token by id `LaunchTokens<T>`
+	/// - One storage read per tag of launch token `Tags<T>`
+	pub fn erc721_metadata(token_id: &TokenId) -> Option<Erc721Metadata> {
+		let token = Self::token(token_id)?;
+		let launch_token = Self::launch_token(token.launch_id)?;
+
+		let uri = token.metadata_uri.unwrap_or(launch_token.metadata_uri).into_inner();
+		let is_image = launch_token.mime_type.starts_with(b"image/");
+
+		let mut attributes = vec![
+			Erc721Attribute::new(b"creator", token.creator.into_inner()),
+			Erc721Attribute::new(b"mime_type", launch_token.mime_type.into_inner()),
+		];
+		attributes.extend(
+			launch_token
+				.tags
+				.iter()
+				.filter_map(|tag_id| Self::tag(tag_id))
+				.map(|name| Erc721Attribute::new(b"tag", name.into_inner())),
+		);
+
+		Some(Erc721Metadata {
+			name: launch_token.name.into_inner(),
+			animation_url: (!is_image).then(|| uri.clone()),
+			image: uri,
+			attributes,
+		})
+	}
+}
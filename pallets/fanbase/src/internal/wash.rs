@@ -0,0 +1,43 @@
+use crate::{Config, Error, Event, LastTrades, Pallet, TokenId};
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
+
+impl<T: Config> Pallet<T> {
+	/// Check sale of token for wash trading and remember it as the last trade of the token.
+	///
+	/// A sale is suspicious if it reverses the last trade of the token within the wash trade
+	/// window, or if seller and buyer are the same account. Suspicious sales are rejected if the
+	/// runtime rejects wash trades, otherwise they are flagged with an event.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update last trade of token `LastTrades<T>`
+	pub fn check_wash_trade(
+		token_id: TokenId,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		let window = T::WashTradeWindow::get();
+		if window.is_zero() {
+			return Ok(())
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let reversed = Self::last_trade(token_id).map_or(false, |(last_seller, last_buyer, at)| {
+			&last_seller == buyer && &last_buyer == seller && now < at.saturating_add(window)
+		});
+
+		if reversed || seller == buyer {
+			// ensure runtime does not reject wash trades
+			ensure!(!T::RejectWashTrades::get(), Error::<T>::WashTrade);
+
+			Self::deposit_event(Event::<T>::SuspiciousTrade(
+				token_id,
+				seller.clone(),
+				buyer.clone(),
+			));
+		}
+
+		LastTrades::<T>::insert(token_id, (seller.clone(), buyer.clone(), now));
+
+		Ok(())
+	}
+}
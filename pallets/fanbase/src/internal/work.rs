@@ -0,0 +1,133 @@
+use crate::{
+	Config, Error, Event, Pallet, Work, WorkId, WorkItem, WorkItemOf, WorkNonce, WorkQueue, Works,
+};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Queue work of `total` steps for processing in idle block time.
+	///
+	/// Returns the work id.
+	///
+	/// *Unchecked!*
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update work nonce `WorkNonce<T>`
+	/// - One storage read-write to queue work id `WorkQueue<T>`
+	/// - One storage write to save work `Works<T>`
+	pub fn unchecked_queue_work(item: WorkItemOf<T>, total: u32) -> Result<WorkId, Error<T>> {
+		let work_id = Self::work_nonce();
+		let next_work_id = work_id.checked_add(1).ok_or(Error::<T>::WorkOverflow)?;
+
+		WorkQueue::<T>::try_append(work_id).map_err(|_| Error::<T>::MaxQueuedWorkReached)?;
+		Works::<T>::insert(work_id, Work { item, progress: 0, total });
+		WorkNonce::<T>::set(next_work_id);
+
+		Ok(work_id)
+	}
+
+	/// Weight of processing a single step of work item.
+	pub fn work_step_weight(item: &WorkItemOf<T>) -> Weight {
+		match item {
+			WorkItem::Airdrop { .. } => Self::airdrop_step_weight(),
+			WorkItem::ClampRoyalties { .. } => Self::clamp_royalty_step_weight(),
+			WorkItem::ClearAttestations { .. } => Self::clear_attestation_step_weight(),
+		}
+	}
+
+	/// Process single step of work item.
+	fn process_work_step(item: &WorkItemOf<T>, step: u32) {
+		match item {
+			WorkItem::Airdrop { launch_token_id, snapshot_id } =>
+				Self::airdrop_step(launch_token_id, snapshot_id, step),
+			WorkItem::ClampRoyalties { max_royalty } => Self::clamp_royalty_step(max_royalty, step),
+			WorkItem::ClearAttestations { attester } => Self::clear_attestation_step(attester),
+		}
+	}
+
+	/// Process queued work in submission order within `max_weight`.
+	///
+	/// Work that does not fit in `max_weight` keeps its progress and continues in a following
+	/// block. Work whose single step exceeds the max block weight can never be processed and is
+	/// dropped from the queue.
+	///
+	/// Returns consumed weight.
+	///
+	/// **Storage ops**
+	/// - One storage read-write to update queued work ids `WorkQueue<T>`
+	/// - One storage read-write per processed work `Works<T>`
+	/// - Storage ops of each processed step
+	pub fn process_work_queue(max_weight: Weight) -> Weight {
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+		if max_weight < consumed {
+			return 0
+		}
+
+		let mut queue = WorkQueue::<T>::get().into_inner();
+		if queue.is_empty() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let lookup_weight = T::DbWeight::get().reads_writes(1, 1);
+		let max_block_weight = T::BlockWeights::get().max_block;
+		let mut completed = 0;
+		for work_id in queue.iter() {
+			// ensure work can be looked up within the remaining weight
+			if consumed.saturating_add(lookup_weight) > max_weight {
+				break
+			}
+			consumed = consumed.saturating_add(lookup_weight);
+
+			let mut work = match Self::work(work_id) {
+				Some(work) => work,
+				None => {
+					completed += 1;
+					continue
+				},
+			};
+			let step_weight = Self::work_step_weight(&work.item);
+			let started_at = work.progress;
+
+			// drop work that would block the queue forever
+			if lookup_weight.saturating_add(step_weight) > max_block_weight {
+				Works::<T>::remove(work_id);
+				completed += 1;
+
+				Self::deposit_event(Event::<T>::WorkSkipped(*work_id, work.progress, work.total));
+				continue
+			}
+
+			while !work.is_complete() && consumed.saturating_add(step_weight) <= max_weight {
+				consumed = consumed.saturating_add(step_weight);
+
+				Self::process_work_step(&work.item, work.progress);
+				work.progress = work.progress.saturating_add(1);
+			}
+
+			if work.is_complete() {
+				Works::<T>::remove(work_id);
+				completed += 1;
+
+				Self::deposit_event(Event::<T>::WorkCompleted(*work_id));
+				continue
+			}
+
+			// keep progress of unfinished work for a following block
+			if work.progress > started_at {
+				Self::deposit_event(Event::<T>::WorkProgressed(
+					*work_id,
+					work.progress,
+					work.total,
+				));
+			}
+			Works::<T>::insert(work_id, work);
+			break
+		}
+
+		queue.drain(..completed);
+		if let Ok(queue) = BoundedVec::try_from(queue) {
+			WorkQueue::<T>::put(queue);
+		}
+
+		consumed
+	}
+}
@@ -15,24 +15,57 @@ mod tests;
 mod benchmarking;
 
 mod internal;
+pub mod migrations;
+#[cfg(feature = "precompile")]
+pub mod precompile;
+pub mod traits;
 pub mod types;
-mod weights;
+pub mod weights;
 
+use traits::{
+	BridgeVerifier, EnsureTransferAllowed, OnPurchase, Payment, PriceOracle, TokenIdGenerator,
+	VerifyIdentity,
+};
 use types::{
-	aliases::BalanceOf, Creator, CreatorId, LaunchToken, LaunchTokenMetadata, Token, TokenId,
+	aliases::{BalanceOf, MemoOf},
+	Auction, BidIncrement, BidIncrementOf, BridgeAddress, BridgeCustodyOf, BridgeProofOf,
+	CalendarEntry, CalendarEntryOf, CalendarTitle, ChainId, CollaboratorRole, CollectionOffer,
+	ContentHash, Creator, CreatorAction, CreatorActivity, CreatorId, CreatorStats, CreatorTransfer,
+	CreatorView, CreatorViewOf, DripSchedule, DutchListing, Erc721Attribute, Erc721Metadata,
+	InfringementClaim, InlineContent, InlineData, LaunchCloneOverridesOf, LaunchDefaults,
+	LaunchState, LaunchToken, LaunchTokenMetadata, LaunchTokenState, LaunchTokenView,
+	LaunchTokenViewOf, ListingRequest, MatchingPool, MediaChunk, MediaUpload, MilestoneEscrow,
+	NameHash, PalletParameter, PalletParameterOf, PalletParametersOf, PriceQuoteOf, ProtectedSale,
+	PurchaseIntent, Raffle, Receipt, ReceiptOf, Report, ReportId, ReportReason, ReputationOf, Sale,
+	SaleOf, Snapshot, SnapshotId, SocialAttestation, SocialAttestationOf, SocialHandle,
+	SocialPlatform, SocialProofOf, Succession, TagId, TagName, Token, TokenId, TokenMetadata,
+	TokenSupply, TokenTags, TokenView, TokenViewOf, Work, WorkId, WorkItem, WorkItemOf, WorkOf,
+	SUB_CREATOR_SEPARATOR,
 };
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
+		dispatch::{Dispatchable, PostDispatchInfo},
 		pallet_prelude::*,
-		traits::{Currency, ExistenceRequirement::KeepAlive},
+		sp_runtime::{
+			traits::{IdentifyAccount, Saturating, Verify, Zero},
+			Perbill, Permill,
+		},
+		sp_std::prelude::*,
+		traits::{Contains, Randomness, ReservableCurrency},
+		weights::GetDispatchInfo,
+		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 
+	/// Current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
-	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// CONFIG
@@ -42,12 +75,227 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// Internal currency.
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Fund movement for purchases, proceeds, escrows and fees.
+		type Payment: Payment<Self::AccountId, BalanceOf<Self>>;
+
+		/// Calls that can be dispatched as keyless creator accounts.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<Call<Self>>;
+
+		/// Pallet id used to derive keyless creator accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Identity check for accounts creating creator accounts.
+		///
+		/// Use `()` to allow any account.
+		type IdentityVerifier: VerifyIdentity<Self::AccountId>;
+
+		/// Transfer restrictions applied to gifts, purchases and transfers.
+		///
+		/// Use `()` to allow all transfers.
+		type TransferFilter: EnsureTransferAllowed<Self::AccountId, TokenId>;
+
+		/// Hook called on every successful `buy` and `launch_buy`, e.g. to award loyalty points.
+		///
+		/// Use `()` to disable.
+		type OnPurchase: OnPurchase<Self::AccountId, BalanceOf<Self>>;
+
+		/// Calls disabled in this runtime, e.g. `burn` or secondary trading at chain launch.
+		///
+		/// Calls are checked with their arguments, use `Everything` to allow all calls.
+		type CallFilter: Contains<Call<Self>>;
+
+		/// Conversion of reference priced launch tokens into native currency.
+		///
+		/// Use `()` to disable reference priced purchases.
+		type PriceOracle: PriceOracle<BalanceOf<Self>>;
+
+		/// Id generation for new tokens, launch token ids are always sequential.
+		///
+		/// Use `()` for sequential ids.
+		type TokenIdGenerator: TokenIdGenerator;
+
+		/// Verification of proofs releasing tokens locked in bridge custody.
+		///
+		/// Use `()` to disable bridging.
+		type BridgeVerifier: BridgeVerifier<Self::AccountId, Self::BlockNumber>;
+
+		/// Max length of a proof releasing a bridged token
+		#[pallet::constant]
+		type MaxBridgeProofLength: Get<u32>;
 
 		/// Max creator accounts for account
 		#[pallet::constant]
 		type MaxCreatorAccounts: Get<u32>;
 
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Signatures and bridge proofs used by benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: traits::BenchmarkHelper<Self::AccountId, Self::OffchainSignature>;
+
+		/// Origin allowed to moderate creators.
+		type ModerationOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Allow purchases to spend the buyer's full balance and reap their account
+		#[pallet::constant]
+		type AllowDeathPayments: Get<bool>;
+
+		/// Account credited with royalties too small to fund a recipient account that does not
+		/// exist yet
+		///
+		/// Use `()` to accumulate them in the pending proceeds of the recipient instead.
+		type RoyaltyDustTreasury: Get<Option<Self::AccountId>>;
+
+		/// Deposit reserved from reporters until their report is resolved
+		#[pallet::constant]
+		type ReportDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved from buyers for every offer and collection offer on top of the offer
+		/// amount, returned when the offer is accepted, filled or cancelled
+		#[pallet::constant]
+		type OfferDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved from claimants until their infringement claim is resolved
+		#[pallet::constant]
+		type InfringementDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved per byte of content stored on-chain with a launch token, including
+		/// chunked media
+		#[pallet::constant]
+		type InlineDataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Max chunks of media uploaded for a launch token
+		#[pallet::constant]
+		type MaxMediaChunks: Get<u32>;
+
+		/// Origin allowed to reverse protected sales during their dispute period.
+		type DisputeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks protected sales can be disputed
+		#[pallet::constant]
+		type DisputePeriod: Get<Self::BlockNumber>;
+
+		/// Origin allowed to initiate recovery of creator accounts with lost keys.
+		///
+		/// Can be backed by governance or by an account recovered through `pallet_recovery`.
+		type RecoveryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to manage the launch token tag taxonomy
+		type TaxonomyOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to approve launch milestones
+		type MilestoneOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Max milestones for launch token
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
+		/// Number of blocks before an initiated creator recovery can be executed
+		#[pallet::constant]
+		type RecoveryDelay: Get<Self::BlockNumber>;
+
+		/// Number of blocks a proposed creator handover can be accepted
+		#[pallet::constant]
+		type CreatorTransferTimeout: Get<Self::BlockNumber>;
+
+		/// Minimum number of blocks of owner inactivity before a nominated successor can claim a
+		/// creator account
+		#[pallet::constant]
+		type MinSuccessionThreshold: Get<Self::BlockNumber>;
+
+		/// Number of blocks a creator registration lasts before it must be renewed
+		#[pallet::constant]
+		type CreatorRegistrationPeriod: Get<Self::BlockNumber>;
+
+		/// Number of blocks after expiry before a creator id can be claimed by another account
+		#[pallet::constant]
+		type CreatorGracePeriod: Get<Self::BlockNumber>;
+
+		/// Fee burned when renewing a creator registration
+		#[pallet::constant]
+		type CreatorRenewalFee: Get<BalanceOf<Self>>;
+
+		/// Max collaborators for creator
+		#[pallet::constant]
+		type MaxCollaborators: Get<u32>;
+
+		/// Max operator keys for creator
+		#[pallet::constant]
+		type MaxOperators: Get<u32>;
+
+		/// Max tokens bought from a launch token in a single purchase
+		#[pallet::constant]
+		type MaxLaunchBuyQuantity: Get<u32>;
+
+		/// Source of randomness for raffle drawings
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Max entrants of a raffle
+		#[pallet::constant]
+		type MaxRaffleEntries: Get<u32>;
+
+		/// Max raffles closing in the same block
+		#[pallet::constant]
+		type MaxRafflesClosingPerBlock: Get<u32>;
+
+		/// Max purchase intents queued for settlement in the next block
+		#[pallet::constant]
+		type MaxPurchaseIntents: Get<u32>;
+
+		/// Max weight consumed by hook processing per block, remaining work carries over
+		#[pallet::constant]
+		type MaxExpiryWeightPerBlock: Get<Weight>;
+
+		/// Max tokens bought by a single sweep
+		#[pallet::constant]
+		type MaxSweepCount: Get<u32>;
+
+		/// Max listings of a launch token scanned for the floor by a single sweep
+		#[pallet::constant]
+		type MaxSweepScan: Get<u32>;
+
+		/// Max entries kept in the activity log of a creator account
+		#[pallet::constant]
+		type MaxActivityLog: Get<u32>;
+
+		/// Number of blocks within which reversing the last trade of a token is suspicious, zero
+		/// disables wash trade checks
+		#[pallet::constant]
+		type WashTradeWindow: Get<Self::BlockNumber>;
+
+		/// Reject suspicious trades instead of flagging them with an event
+		#[pallet::constant]
+		type RejectWashTrades: Get<bool>;
+
+		/// Reject launch tokens with content minted before by the same creator instead of flagging
+		/// them with an event
+		#[pallet::constant]
+		type RejectDuplicateContent: Get<bool>;
+
+		/// Max tokens moved in a single batch call
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+
+		/// Platform-wide ceiling of royalties on secondary sales, governance can lower the max
+		/// royalty below it
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// Max length of memo attached to token movements
+		#[pallet::constant]
+		type MaxMemoLength: Get<u32>;
+
+		/// Max sub-creators for creator
+		#[pallet::constant]
+		type MaxSubCreators: Get<u32>;
+
 		/// Max launch tokens for creator
 		#[pallet::constant]
 		type MaxLaunchTokens: Get<u32>;
@@ -55,18 +303,92 @@ pub mod pallet {
 		/// Max tokens for account
 		#[pallet::constant]
 		type MaxTokens: Get<u32>;
+
+		/// Min price for launch tokens and listed tokens
+		#[pallet::constant]
+		type MinTokenPrice: Get<BalanceOf<Self>>;
+
+		/// Number of blocks after a price change before token price can be raised again
+		#[pallet::constant]
+		type PriceChangeCooldown: Get<Self::BlockNumber>;
+
+		/// Max launch tokens a creator can mint within a mint period
+		#[pallet::constant]
+		type MaxMintsPerPeriod: Get<u32>;
+
+		/// Length of a mint period in blocks
+		#[pallet::constant]
+		type MintPeriod: Get<Self::BlockNumber>;
+
+		/// Max holders recorded in a snapshot
+		#[pallet::constant]
+		type MaxSnapshotHolders: Get<u32>;
+
+		/// Length of a launch calendar period in blocks
+		#[pallet::constant]
+		type CalendarPeriod: Get<Self::BlockNumber>;
+
+		/// Max drops registered on the launch calendar per calendar period
+		#[pallet::constant]
+		type MaxCalendarEntries: Get<u32>;
+
+		/// Deposit reserved for every drop registered on the launch calendar until it is removed
+		#[pallet::constant]
+		type CalendarDeposit: Get<BalanceOf<Self>>;
+
+		/// Max multi-block work items queued at once
+		#[pallet::constant]
+		type MaxQueuedWork: Get<u32>;
+
+		/// Min raise of bids on auctions created without a min increment
+		#[pallet::constant]
+		type DefaultBidIncrement: Get<BidIncrementOf<Self>>;
+
+		/// Origin allowed to change governance mutable pallet parameters
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to manage the set of social attesters
+		type AttestationOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to manage featured launch tokens
+		type CurationOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Max featured launch tokens
+		#[pallet::constant]
+		type MaxFeaturedLaunches: Get<u32>;
+
+		/// Signature of off-chain proofs signed by attesters
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key of attesters, identifying their account
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Max platforms with an attested social handle per creator account
+		#[pallet::constant]
+		type MaxSocialPlatforms: Get<u32>;
+	}
+
+	// GENESIS
+	#[pallet::genesis_config]
+	#[derive(Default)]
+	pub struct GenesisConfig;
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			// endow pallet account so it is never reaped while holding reserves
+			Pallet::<T>::ensure_pallet_account_funded();
+		}
 	}
 
 	// STORAGE ITEMS
 	/// Creator accounts
 	#[pallet::storage]
-	#[pallet::getter(fn creators)]
 	pub type Creators<T> = StorageMap<_, Blake2_128Concat, CreatorId, Creator<T>>;
 
 	/// Creator ids for account.
 	/// Maps Accounts to their creator accounts.
 	#[pallet::storage]
-	#[pallet::getter(fn creator_ids_for_account)]
 	pub type CreatorIdsForAccount<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
@@ -75,442 +397,6151 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Launch tokens for creators.
+	/// Primary creator ids for accounts.
+	/// Maps accounts to the creator id displayed as their name.
 	#[pallet::storage]
-	#[pallet::getter(fn launch_tokens)]
-	pub type LaunchTokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, LaunchToken<T>>;
+	pub type PrimaryCreatorForAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CreatorId>;
 
-	/// Launch token ids for creator.
-	/// Maps creators to their launch tokens.
+	/// On-chain content of launch tokens.
 	#[pallet::storage]
-	#[pallet::getter(fn launch_token_ids_for_creator)]
-	pub type LaunchTokenIdsForCreator<T: Config> = StorageMap<
+	pub type InlineContents<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, InlineContent<T>>;
+
+	/// Media uploads of launch tokens.
+	#[pallet::storage]
+	pub type MediaUploads<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, MediaUpload<T>>;
+
+	/// Media chunks of launch tokens.
+	/// Maps launch tokens and chunk indexes to chunks.
+	#[pallet::storage]
+	pub type MediaChunks<T> =
+		StorageDoubleMap<_, Blake2_128Concat, TokenId, Twox64Concat, u32, MediaChunk>;
+
+	/// Collaborators for creators.
+	/// Maps creators to their collaborators and collaborator roles.
+	#[pallet::storage]
+	pub type Collaborators<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		CreatorId,
-		BoundedVec<TokenId, T::MaxLaunchTokens>,
+		BoundedVec<(T::AccountId, CollaboratorRole), T::MaxCollaborators>,
 		ValueQuery,
 	>;
 
-	/// Tokens for accounts.
+	/// Operator keys for creators.
+	/// Maps creators to short-lived keys managing them and the block each key expires at.
 	#[pallet::storage]
-	#[pallet::getter(fn tokens)]
-	pub type Tokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, Token<T>>;
+	pub type CreatorOperators<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CreatorId,
+		BoundedVec<(T::AccountId, T::BlockNumber), T::MaxOperators>,
+		ValueQuery,
+	>;
 
-	/// Token ids for accounts.
-	/// Maps accounts to their tokens.
+	/// Activity logs of creators.
+	/// Maps creators to the latest privileged actions performed on their behalf.
 	#[pallet::storage]
-	#[pallet::getter(fn token_ids_for_account)]
-	pub type TokenIdsForAccount<T: Config> = StorageMap<
+	pub type CreatorActivityLog<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		T::AccountId,
-		BoundedVec<TokenId, T::MaxTokens>,
+		CreatorId,
+		BoundedVec<CreatorActivity<T>, T::MaxActivityLog>,
 		ValueQuery,
 	>;
 
-	/// Track issued launch tokens count
+	/// Sub-creator ids for creator.
+	/// Maps creators to the sub-creators created under their namespace.
 	#[pallet::storage]
-	#[pallet::getter(fn launch_issuance_nonce)]
-	pub type LaunchIssuanceNonce<T> = StorageValue<_, TokenId, ValueQuery>;
+	pub type SubCreatorIdsForCreator<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CreatorId,
+		BoundedVec<CreatorId, T::MaxSubCreators>,
+		ValueQuery,
+	>;
 
-	/// Track issued tokens count
+	/// Pending creator recoveries.
+	/// Maps creators to the recovering account and the block the recovery can be executed at.
 	#[pallet::storage]
-	#[pallet::getter(fn issuance_nonce)]
-	pub type IssuanceNonce<T> = StorageValue<_, TokenId, ValueQuery>;
+	pub type PendingRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, (T::AccountId, T::BlockNumber)>;
 
-	// EVENTS
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// New creator account created [account, creator]
-		NewCreator(T::AccountId, CreatorId),
+	/// Creator successions.
+	/// Maps creators to the successor nominated by their owner and the inactivity threshold.
+	#[pallet::storage]
+	pub type Successions<T: Config> = StorageMap<_, Blake2_128Concat, CreatorId, Succession<T>>;
 
-		/// Creator account dropped [account, creator]
-		DroppedCreator(T::AccountId, CreatorId),
+	/// Last activity of accounts.
+	/// Maps owners of creators with a succession to the block of their last signed call.
+	#[pallet::storage]
+	pub type LastActive<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
 
-		/// New token minted [creator, launch token]
-		TokenCreated(CreatorId, TokenId),
+	/// Pending creator transfers.
+	/// Maps creators to their proposed handover awaiting acceptance.
+	#[pallet::storage]
+	pub type PendingCreatorTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, CreatorTransfer<T>>;
 
-		/// Token acquired for the first time [collector, creator, token]
-		TokenInitialCollection(T::AccountId, CreatorId, TokenId),
+	/// Banned creators.
+	/// Maps banned creators to the block they were banned at.
+	/// Launch tokens of banned creators are flagged through their creator's ban.
+	#[pallet::storage]
+	pub type BannedCreators<T: Config> = StorageMap<_, Blake2_128Concat, CreatorId, T::BlockNumber>;
 
-		/// Token transferred to new owner [previous owner, new owner, token]
-		TokenTransferred(T::AccountId, T::AccountId, TokenId),
+	/// Registered marketplaces.
+	/// Accounts allowed to receive plain transfers of royalty enforced tokens.
+	#[pallet::storage]
+	pub type Marketplaces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
 
-		/// Token listed on market [owner, token, price]
-		TokenListed(T::AccountId, TokenId, Option<BalanceOf<T>>),
+	/// Accounts allowed to attest social handles of creator accounts.
+	#[pallet::storage]
+	pub type Attesters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
 
-		/// Token unlisted from market [owner, token, price]
-		TokenUnlisted(T::AccountId, TokenId, Option<BalanceOf<T>>),
+	/// Featured launch tokens.
+	/// Launch tokens featured by curators in display order.
+	#[pallet::storage]
+	pub type FeaturedLaunches<T: Config> =
+		StorageValue<_, BoundedVec<TokenId, T::MaxFeaturedLaunches>, ValueQuery>;
 
-		/// Token launch price updated [creator, launch token, price]
-		TokenLaunchPriceUpdated(CreatorId, TokenId, Option<BalanceOf<T>>),
+	/// Launch calendar.
+	/// Maps calendar periods to upcoming drops registered by creator accounts.
+	#[pallet::storage]
+	pub type LaunchCalendar<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<CalendarEntryOf<T>, T::MaxCalendarEntries>,
+		ValueQuery,
+	>;
 
-		/// Token price updated [owner, token, price]
-		TokenPriceUpdated(T::AccountId, TokenId, Option<BalanceOf<T>>),
+	/// Social handles of creator accounts verified by attesters.
+	/// Maps creator accounts and platforms to their attested handle.
+	#[pallet::storage]
+	pub type SocialAttestations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CreatorId,
+		Blake2_128Concat,
+		SocialPlatform,
+		SocialAttestationOf<T>,
+	>;
 
-		/// Token permanently destroyed [owner, token]
-		TokenDestroyed(T::AccountId, TokenId),
-	}
+	/// Social attestations by attester.
+	/// Maps attesters to the creator accounts and platforms of the handles they attested.
+	#[pallet::storage]
+	pub type SocialAttestationsByAttester<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(CreatorId, SocialPlatform),
+		(),
+	>;
 
-	// ERRORS
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Insufficient funds to complete buy operation
-		InsufficientFunds,
+	/// Number of social handles attested by attesters.
+	#[pallet::storage]
+	pub type AttestationCounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-		/// Signing account is not the owner of this item
-		NotOwner,
+	/// Accounts blocked from the marketplace.
+	/// Maps blocked accounts to the block they were blocked at.
+	#[pallet::storage]
+	pub type BlockedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
 
-		/// Creator account already taken
-		CreatorAccountTaken,
+	/// Creator reputations.
+	/// Maps creator accounts to their on-chain activity.
+	#[pallet::storage]
+	pub type Reputations<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, ReputationOf<T>, ValueQuery>;
 
-		/// Token not found
-		TokenNotFound,
+	/// Creator stats.
+	/// Maps creator accounts to aggregate counters of their tokens.
+	#[pallet::storage]
+	pub type CreatorStatsOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, CreatorStats, ValueQuery>;
 
-		/// Token sold out of launch
-		TokenSoldOut,
+	/// Infringement claims.
+	/// Maps launch tokens to the open infringement claim against them.
+	#[pallet::storage]
+	pub type InfringementClaims<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, InfringementClaim<T>>;
 
-		/// Token not for sale
-		TokenNotForSale,
+	/// Reports against launch tokens awaiting resolution.
+	/// Maps launch tokens and report ids to their report.
+	#[pallet::storage]
+	pub type Reports<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TokenId, Blake2_128Concat, ReportId, Report<T>>;
 
-		/// Token creator is unavailable
-		TokenUnavailable,
+	/// Reports filed against launch tokens.
+	/// Maps launch tokens to their number of filed reports.
+	#[pallet::storage]
+	pub type ReportCount<T> = StorageMap<_, Blake2_128Concat, TokenId, ReportId, ValueQuery>;
 
-		/// Token not listed
-		TokenNotListed,
+	/// Strikes against launch tokens.
+	/// Maps launch tokens to their number of upheld reports.
+	#[pallet::storage]
+	pub type Strikes<T> = StorageMap<_, Blake2_128Concat, TokenId, u32, ValueQuery>;
 
-		/// Token already listed
-		TokenAlreadyListed,
+	/// Launch tokens for creators.
+	#[pallet::storage]
+	pub type LaunchTokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, LaunchToken<T>>;
 
-		/// Bid price too low to buy token
-		BidPriceTooLow,
+	/// Issuance counters and sale state of launch tokens.
+	#[pallet::storage]
+	pub type LaunchTokenStates<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, LaunchTokenState>;
 
-		/// Cannot set token supply to zero
-		ZeroSupply,
+	/// Launch tokens by sale state.
+	/// Indexes launch tokens under their current state.
+	#[pallet::storage]
+	pub type LaunchTokensByState<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, LaunchState, Blake2_128Concat, TokenId, (), OptionQuery>;
+
+	/// Launch tokens by name.
+	/// Indexes launch tokens under the hash of their lowercased name.
+	#[pallet::storage]
+	pub type LaunchTokenIdsByName<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, NameHash, Blake2_128Concat, TokenId, ()>;
+
+	/// Launch tokens by content.
+	/// Indexes launch tokens under their content hash.
+	#[pallet::storage]
+	pub type LaunchTokenIdsByContent<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ContentHash, Blake2_128Concat, TokenId, ()>;
+
+	/// Launch token ids for creator.
+	/// Maps creators to their launch tokens.
+	#[pallet::storage]
+	pub type LaunchTokenIdsForCreator<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CreatorId,
+		BoundedVec<TokenId, T::MaxLaunchTokens>,
+		ValueQuery,
+	>;
+
+	/// Tokens for accounts.
+	#[pallet::storage]
+	pub type Tokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, Token<T>>;
+
+	/// Retired tokens.
+	/// Maps tokens kept out of circulation to the block they were retired at.
+	#[pallet::storage]
+	pub type RetiredTokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, T::BlockNumber>;
+
+	/// Token ids for accounts.
+	/// Maps accounts to their tokens.
+	#[pallet::storage]
+	pub type TokenIdsForAccount<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<TokenId, T::MaxTokens>,
+		ValueQuery,
+	>;
+
+	/// Mint quota usage for creators.
+	/// Maps creators to the start block of their current mint period and launch tokens minted in it.
+	#[pallet::storage]
+	pub type MintQuota<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, (T::BlockNumber, u32)>;
+
+	/// Per block purchase caps for launch tokens.
+	/// Maps launch tokens to the purchases allowed per account in a single block.
+	#[pallet::storage]
+	pub type BlockPurchaseCaps<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, u32>;
+
+	/// Purchases of launch tokens by accounts.
+	/// Maps launch tokens and buyers to the block of their last purchase and purchases in it.
+	#[pallet::storage]
+	pub type BlockPurchases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::BlockNumber, u32),
+	>;
+
+	/// Last price change for tokens.
+	/// Maps tokens to the block and price of their last price change.
+	#[pallet::storage]
+	pub type LastPriceChange<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, (T::BlockNumber, BalanceOf<T>)>;
+
+	/// Protected sales held in escrow.
+	/// Maps tokens to their pending protected sale.
+	#[pallet::storage]
+	pub type ProtectedSales<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, ProtectedSale<T>>;
+
+	/// Redeemable reserves for tokens.
+	/// Maps tokens to the amount held in the pallet account and refunded on burn.
+	#[pallet::storage]
+	pub type RedeemableReserves<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, BalanceOf<T>>;
+
+	/// Beneficiaries of launch tokens.
+	/// Maps launch tokens to the account receiving their primary sale proceeds instead of the
+	/// creator owner.
+	#[pallet::storage]
+	pub type LaunchBeneficiaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, T::AccountId>;
+
+	/// Royalty beneficiaries of launch tokens.
+	/// Maps launch tokens to the account receiving their royalties instead of the launch token
+	/// beneficiary or creator owner.
+	#[pallet::storage]
+	pub type RoyaltyBeneficiaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, T::AccountId>;
+
+	/// Launch delegates.
+	/// Maps launch tokens to the creator account managing them on behalf of their creator.
+	#[pallet::storage]
+	pub type LaunchDelegates<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, CreatorId>;
+
+	/// Drip schedules.
+	/// Maps launch tokens to the schedule gradually unlocking their purchasable supply.
+	#[pallet::storage]
+	pub type DripSchedules<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, DripSchedule<T>>;
+
+	/// Raffles.
+	/// Maps launch tokens to their open raffle.
+	#[pallet::storage]
+	pub type Raffles<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, Raffle<T>>;
+
+	/// Raffle entries.
+	/// Maps launch tokens to the entrants of their raffle and their ticket count.
+	#[pallet::storage]
+	pub type RaffleEntries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		BoundedVec<(T::AccountId, u32), T::MaxRaffleEntries>,
+		ValueQuery,
+	>;
+
+	/// Raffles closing at block.
+	/// Maps blocks to the launch tokens whose raffle entries close and are seeded at that block.
+	#[pallet::storage]
+	pub type RafflesClosingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<TokenId, T::MaxRafflesClosingPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Purchase intents.
+	/// Launch purchases queued in submission order and settled at the start of the next block.
+	#[pallet::storage]
+	pub type PurchaseIntents<T: Config> =
+		StorageValue<_, BoundedVec<PurchaseIntent<T>, T::MaxPurchaseIntents>, ValueQuery>;
+
+	/// Snapshots of launch token holders.
+	#[pallet::storage]
+	pub type Snapshots<T: Config> = StorageMap<_, Blake2_128Concat, SnapshotId, Snapshot<T>>;
+
+	/// Holders recorded in snapshots.
+	/// Maps snapshots and holder indexes to holder accounts.
+	#[pallet::storage]
+	pub type SnapshotHolders<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SnapshotId, Blake2_128Concat, u32, T::AccountId>;
+
+	/// Track taken snapshots count
+	#[pallet::storage]
+	pub type SnapshotNonce<T> = StorageValue<_, SnapshotId, ValueQuery>;
+
+	/// Multi-block work and its progress.
+	#[pallet::storage]
+	pub type Works<T: Config> = StorageMap<_, Blake2_128Concat, WorkId, WorkOf<T>>;
+
+	/// Work queue.
+	/// Work ids queued in submission order and processed in idle block time.
+	#[pallet::storage]
+	pub type WorkQueue<T: Config> =
+		StorageValue<_, BoundedVec<WorkId, T::MaxQueuedWork>, ValueQuery>;
+
+	/// Track queued work count
+	#[pallet::storage]
+	pub type WorkNonce<T> = StorageValue<_, WorkId, ValueQuery>;
+
+	/// Held tokens for accounts.
+	/// Maps launch tokens and accounts to the number of tokens of the launch token they hold.
+	#[pallet::storage]
+	pub type HeldTokens<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Tokens owned by accounts.
+	/// Ownership index keyed by account and token id, iterated in stable order for paging.
+	#[pallet::storage]
+	pub type TokensOwned<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, TokenId, ()>;
+
+	/// Token ids for accounts by launch token.
+	/// Indexes tokens owned by accounts under the launch token they were issued from.
+	#[pallet::storage]
+	pub type TokenIdsForAccountByLaunch<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, TokenId>,
+			NMapKey<Blake2_128Concat, TokenId>,
+		),
+		(),
+	>;
+
+	/// Purchase receipts.
+	/// Maps launch tokens, buyers and tokens bought on primary purchase to their receipt.
+	#[pallet::storage]
+	pub type Receipts<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, TokenId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, TokenId>,
+		),
+		ReceiptOf<T>,
+	>;
+
+	/// Unique holders for launch tokens.
+	/// Maps launch tokens to the number of accounts holding at least one of their tokens.
+	#[pallet::storage]
+	pub type UniqueHolders<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, u32, ValueQuery>;
+
+	/// Launch defaults of creators.
+	/// Maps creator accounts to settings inherited by their new launch tokens.
+	#[pallet::storage]
+	pub type LaunchDefaultsForCreator<T: Config> =
+		StorageMap<_, Blake2_128Concat, CreatorId, LaunchDefaults<T>>;
+
+	/// Milestone escrows.
+	/// Maps launch tokens to their proceeds held in escrow and released by milestones.
+	#[pallet::storage]
+	pub type MilestoneEscrows<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, MilestoneEscrow<T>>;
+
+	/// Matching pools.
+	/// Maps creator accounts to the sponsor pool matching purchases of their launch tokens.
+	#[pallet::storage]
+	pub type MatchingPools<T: Config> = StorageMap<_, Blake2_128Concat, CreatorId, MatchingPool<T>>;
+
+	/// Auctions.
+	/// Maps tokens to their running auction.
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, Auction<T>>;
+
+	/// Offers for tokens.
+	/// Maps tokens and buyers to their offer amount reserved from the buyer.
+	#[pallet::storage]
+	pub type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// Deposits of offers for tokens.
+	/// Maps tokens and buyers to the deposit reserved for their offer.
+	#[pallet::storage]
+	pub type OfferDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// Collection offers for launch tokens.
+	/// Maps launch tokens and buyers to their offer for any token of the launch token.
+	#[pallet::storage]
+	pub type CollectionOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		CollectionOffer<T>,
+	>;
+
+	/// Offer floors of tokens.
+	/// Maps tokens to the min offer amount accepted by their owner.
+	#[pallet::storage]
+	pub type OfferFloors<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, BalanceOf<T>>;
+
+	/// Listed tokens.
+	/// Maps tokens for sale to their price.
+	#[pallet::storage]
+	pub type ListedTokens<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, BalanceOf<T>>;
+
+	/// Dutch listings.
+	/// Maps listed tokens to their declining price schedule, the token price holds the start price.
+	#[pallet::storage]
+	pub type DutchListings<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, DutchListing<T>>;
+
+	/// Listing requests.
+	/// Maps tokens of curated launch tokens to the request of their owner to list them.
+	#[pallet::storage]
+	pub type ListingRequests<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, ListingRequest<T>>;
+
+	/// Listed tokens for creators.
+	/// Maps creators and tokens minted from their launch tokens to the token price.
+	#[pallet::storage]
+	pub type ListedTokensForCreator<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CreatorId, Blake2_128Concat, TokenId, BalanceOf<T>>;
+
+	/// Listed tokens for launch tokens.
+	/// Maps launch tokens and tokens minted from them to the floor of the token price, the end
+	/// price for dutch listings.
+	#[pallet::storage]
+	pub type ListedTokensForLaunch<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TokenId, Blake2_128Concat, TokenId, BalanceOf<T>>;
+
+	/// Price ordered listings.
+	/// Set of launch tokens and the big-endian price floor and id of tokens listed from them,
+	/// iterating a launch token visits its listings cheapest first.
+	#[pallet::storage]
+	pub type ListingsByPrice<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TokenId, Identity, ([u8; 16], TokenId), ()>;
+
+	/// Sales of tokens.
+	/// Maps launch tokens and sale index to sales of tokens minted from the launch token.
+	#[pallet::storage]
+	pub type Sales<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TokenId, Twox64Concat, u32, SaleOf<T>>;
+
+	/// Last trades of tokens.
+	/// Maps tokens to the seller, buyer and block of their last sale.
+	#[pallet::storage]
+	pub type LastTrades<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, (T::AccountId, T::AccountId, T::BlockNumber)>;
+
+	/// Sale count for launch tokens.
+	#[pallet::storage]
+	pub type SaleCount<T: Config> = StorageMap<_, Blake2_128Concat, TokenId, u32, ValueQuery>;
+
+	/// Tags.
+	/// Maps tag ids of the launch token taxonomy to their names.
+	#[pallet::storage]
+	pub type Tags<T> = StorageMap<_, Blake2_128Concat, TagId, TagName>;
+
+	/// Payout accounts.
+	/// Maps accounts to the account credited with their secondary sale proceeds, e.g. a cold
+	/// wallet of an owner trading through a hot key.
+	#[pallet::storage]
+	pub type PayoutAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// Quote nonces.
+	/// Maps sellers to the nonce their off-chain price quotes must be signed for.
+	#[pallet::storage]
+	pub type QuoteNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Bridge custodies.
+	/// Maps tokens locked for another chain to their bridge custody.
+	#[pallet::storage]
+	pub type BridgeCustodies<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenId, BridgeCustodyOf<T>>;
+
+	/// Proceeds of sales and royalties awaiting withdrawal.
+	/// Maps accounts to their claimable proceeds held by the pallet account.
+	#[pallet::storage]
+	pub type PendingProceeds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Governance set values of pallet parameters.
+	#[pallet::storage]
+	pub type Parameters<T: Config> = StorageValue<_, PalletParametersOf<T>, ValueQuery>;
+
+	/// Track created tags count
+	#[pallet::storage]
+	pub type TagNonce<T> = StorageValue<_, TagId, ValueQuery>;
+
+	/// Track issued launch tokens count
+	#[pallet::storage]
+	pub type LaunchIssuanceNonce<T> = StorageValue<_, TokenId, ValueQuery>;
+
+	/// Track issued tokens count
+	#[pallet::storage]
+	pub type IssuanceNonce<T> = StorageValue<_, TokenId, ValueQuery>;
+
+	// EVENTS
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New creator account created [account, creator]
+		NewCreator(T::AccountId, CreatorId),
+
+		/// Creator account dropped [account, creator]
+		DroppedCreator(T::AccountId, CreatorId),
+
+		/// New token minted [creator, launch token]
+		TokenCreated(CreatorId, TokenId),
+
+		/// Token acquired for the first time [collector, creator, token]
+		TokenInitialCollection(T::AccountId, CreatorId, TokenId),
+
+		/// Token transferred to new owner [previous owner, new owner, token]
+		TokenTransferred(T::AccountId, T::AccountId, TokenId),
+
+		/// Token listed on market [owner, token, price]
+		TokenListed(T::AccountId, TokenId, Option<BalanceOf<T>>),
+
+		/// Token unlisted from market [owner, token, price]
+		TokenUnlisted(T::AccountId, TokenId, Option<BalanceOf<T>>),
+
+		/// Token launch price updated [creator, launch token, price]
+		TokenLaunchPriceUpdated(CreatorId, TokenId, Option<BalanceOf<T>>),
+
+		/// Token price updated [owner, token, price]
+		TokenPriceUpdated(T::AccountId, TokenId, Option<BalanceOf<T>>),
+
+		/// Token permanently destroyed [owner, token]
+		TokenDestroyed(T::AccountId, TokenId),
+
+		/// Sub-creator created [parent creator, sub-creator]
+		SubCreatorCreated(CreatorId, CreatorId),
+
+		/// Creator registration renewed [creator, expires at]
+		CreatorRenewed(CreatorId, T::BlockNumber),
+
+		/// Creator account owner changed [creator, previous owner, new owner]
+		CreatorOwnerChanged(CreatorId, T::AccountId, T::AccountId),
+
+		/// Creator successor updated [creator, successor, inactivity threshold]
+		SuccessorSet(CreatorId, Option<T::AccountId>, Option<T::BlockNumber>),
+
+		/// Creator handover proposed [creator, owner, new owner]
+		CreatorTransferProposed(CreatorId, T::AccountId, T::AccountId),
+
+		/// Creator handover cancelled [creator]
+		CreatorTransferCancelled(CreatorId),
+
+		/// Creator recovery initiated [creator, new owner, executable at]
+		CreatorRecoveryInitiated(CreatorId, T::AccountId, T::BlockNumber),
+
+		/// Creator recovery cancelled [creator]
+		CreatorRecoveryCancelled(CreatorId),
+
+		/// Creator account owner recovered [creator, previous owner, new owner]
+		CreatorOwnerRecovered(CreatorId, T::AccountId, T::AccountId),
+
+		/// Collaborator added to creator account or role updated [creator, collaborator, role]
+		CollaboratorAdded(CreatorId, T::AccountId, CollaboratorRole),
+
+		/// Collaborator removed from creator account [creator, collaborator]
+		CollaboratorRemoved(CreatorId, T::AccountId),
+
+		/// Operator key added to creator account or expiry updated [creator, operator, expires at]
+		OperatorAdded(CreatorId, T::AccountId, T::BlockNumber),
+
+		/// Operator key removed from creator account [creator, operator]
+		OperatorRemoved(CreatorId, T::AccountId),
+
+		/// Call dispatched as keyless creator account [creator, collaborator, result]
+		CreatorCallDispatched(CreatorId, T::AccountId, DispatchResult),
+
+		/// Creator account banned [creator]
+		CreatorBanned(CreatorId),
+
+		/// Creator account unbanned [creator]
+		CreatorUnbanned(CreatorId),
+
+		/// Launch token reported [reporter, launch token, report]
+		LaunchReported(T::AccountId, TokenId, ReportId),
+
+		/// Report upheld and deposit returned [reporter, launch token, report, strikes]
+		ReportUpheld(T::AccountId, TokenId, ReportId, u32),
+
+		/// Report dismissed and deposit slashed [reporter, launch token, report]
+		ReportDismissed(T::AccountId, TokenId, ReportId),
+
+		/// Infringement claimed against launch token [claimant, launch token, evidence hash]
+		InfringementClaimed(T::AccountId, TokenId, T::Hash),
+
+		/// Infringement claim upheld and launch token frozen [claimant, launch token]
+		InfringementUpheld(T::AccountId, TokenId),
+
+		/// Infringement claim dismissed and deposit slashed [claimant, launch token]
+		InfringementDismissed(T::AccountId, TokenId),
+
+		/// Proceeds withdrawn [account, amount]
+		ProceedsWithdrawn(T::AccountId, BalanceOf<T>),
+
+		/// Token sold through signed price quote [seller, buyer, token, price]
+		QuoteExecuted(T::AccountId, T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Outstanding price quotes of seller revoked [seller, new quote nonce]
+		QuotesRevoked(T::AccountId, u32),
+
+		/// Token locked in bridge custody [owner, token, destination chain, destination address]
+		TokenBridgeLocked(T::AccountId, TokenId, ChainId, BridgeAddress),
+
+		/// Token released from bridge custody [receiver, token]
+		TokenBridgeUnlocked(T::AccountId, TokenId),
+
+		/// Payout account of account updated [account, payout account]
+		PayoutAccountUpdated(T::AccountId, Option<T::AccountId>),
+
+		/// Pallet parameter changed [parameter]
+		ParameterChanged(PalletParameterOf<T>),
+
+		/// Attester added [attester]
+		AttesterAdded(T::AccountId),
+
+		/// Attester removed [attester]
+		AttesterRemoved(T::AccountId),
+
+		/// Launch token featured [launch token]
+		LaunchFeatured(TokenId),
+
+		/// Launch token no longer featured [launch token]
+		LaunchUnfeatured(TokenId),
+
+		/// Featured launch tokens reordered
+		FeaturedLaunchesReordered,
+
+		/// Drop registered on the launch calendar [creator, starts at]
+		DropScheduled(CreatorId, T::BlockNumber),
+
+		/// Launch token of calendar drop set [creator, starts at, launch token]
+		DropLaunchLinked(CreatorId, T::BlockNumber, TokenId),
+
+		/// Drop removed from the launch calendar [creator, starts at]
+		DropCancelled(CreatorId, T::BlockNumber),
+
+		/// Social handle of creator account attested [creator, platform, handle, attester]
+		SocialAttested(CreatorId, SocialPlatform, SocialHandle, T::AccountId),
+
+		/// Social handle of creator account revoked [creator, platform, handle]
+		SocialRevoked(CreatorId, SocialPlatform, SocialHandle),
+
+		/// Removal of attestations of removed attester queued [attester, work]
+		AttestationClearQueued(T::AccountId, WorkId),
+
+		/// Account blocked from the marketplace [account]
+		AccountBlocked(T::AccountId),
+
+		/// Account unblocked from the marketplace [account]
+		AccountUnblocked(T::AccountId),
+
+		/// Launch token cloned from another launch token [creator, source launch token, launch token]
+		LaunchCloned(CreatorId, TokenId, TokenId),
+
+		/// Launch token minted with the name of another launch token [creator, launch token]
+		DuplicateLaunchName(CreatorId, TokenId),
+
+		/// Launch token content minted before by creator [creator, launch token, original]
+		DuplicateContentDetected(CreatorId, TokenId, TokenId),
+
+		/// Draft launch token updated [creator, launch token, metadata version, old hash, new hash]
+		LaunchDraftUpdated(CreatorId, TokenId, u32, T::Hash, T::Hash),
+
+		/// Sale flagged as possible wash trade [token, seller, buyer]
+		SuspiciousTrade(TokenId, T::AccountId, T::AccountId),
+
+		/// Marketplace registered [account]
+		MarketplaceAdded(T::AccountId),
+
+		/// Marketplace unregistered [account]
+		MarketplaceRemoved(T::AccountId),
+
+		/// Creator set by root [creator]
+		CreatorForceSet(CreatorId),
+
+		/// Launch token set by root [launch token]
+		LaunchTokenForceSet(TokenId),
+
+		/// Token set by root [token]
+		TokenForceSet(TokenId),
+
+		/// Media chunk uploaded [launch token, index]
+		MediaChunkUploaded(TokenId, u32),
+
+		/// Media finalized [launch token, content hash]
+		MediaFinalized(TokenId, T::Hash),
+
+		/// Media removed [launch token]
+		MediaCleared(TokenId),
+
+		/// Privileged action performed on behalf of creator, role is `None` for the owner
+		/// [creator, actor, role, action, launch token]
+		CreatorActionPerformed(
+			CreatorId,
+			T::AccountId,
+			Option<CollaboratorRole>,
+			CreatorAction,
+			TokenId,
+		),
+
+		/// Primary creator set [account, creator]
+		PrimaryCreatorSet(T::AccountId, CreatorId),
+
+		/// Draft launch token published [creator, launch token]
+		LaunchPublished(CreatorId, TokenId),
+
+		/// Launch token state changed [creator, launch token, state]
+		LaunchStateChanged(CreatorId, TokenId, LaunchState),
+
+		/// Launch token management delegated [creator, launch token, delegate]
+		LaunchDelegated(CreatorId, TokenId, CreatorId),
+
+		/// Launch token delegation revoked [creator, launch token, delegate]
+		LaunchDelegationRevoked(CreatorId, TokenId, CreatorId),
+
+		/// Purchase receipt issued to buyer [buyer, launch token, token]
+		ReceiptIssued(T::AccountId, TokenId, TokenId),
+
+		/// Launch token milestones set [creator, launch token, milestones]
+		MilestonesSet(CreatorId, TokenId, u32),
+
+		/// Launch token milestone approved [launch token, milestone]
+		MilestoneApproved(TokenId, u32),
+
+		/// Launch proceeds released from escrow [launch token, beneficiary, amount]
+		MilestoneProceedsReleased(TokenId, T::AccountId, BalanceOf<T>),
+
+		/// Matching pool funded [sponsor, creator, amount, ratio, ends at]
+		MatchingPoolFunded(T::AccountId, CreatorId, BalanceOf<T>, Permill, T::BlockNumber),
+
+		/// Purchase matched from matching pool [creator, beneficiary, amount]
+		PurchaseMatched(CreatorId, T::AccountId, BalanceOf<T>),
+
+		/// Matching pool closed and remainder returned [sponsor, creator, amount]
+		MatchingPoolClosed(T::AccountId, CreatorId, BalanceOf<T>),
+
+		/// Tag added to taxonomy [tag, name]
+		TagAdded(TagId, TagName),
+
+		/// Tag removed from taxonomy [tag]
+		TagRemoved(TagId),
+
+		/// Launch token tags updated [creator, launch token, tags]
+		TokenTagsUpdated(CreatorId, TokenId, TokenTags),
+
+		/// Auction opened for token [seller, token, min bid, ends at]
+		AuctionCreated(T::AccountId, TokenId, BalanceOf<T>, T::BlockNumber),
+
+		/// Bid placed on auction [bidder, token, amount]
+		BidPlaced(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Bid outbid and its reserve returned [bidder, token, amount]
+		BidOutbid(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Auction settled [seller, token, winner, amount]
+		AuctionSettled(T::AccountId, TokenId, Option<T::AccountId>, Option<BalanceOf<T>>),
+
+		/// Auction ended below reserve price and highest bid returned [bidder, token, amount]
+		AuctionReserveNotMet(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Auction cancelled [seller, token]
+		AuctionCancelled(T::AccountId, TokenId),
+
+		/// Auction sale to highest bidder failed and bid returned [bidder, token, amount, error]
+		AuctionSaleFailed(T::AccountId, TokenId, BalanceOf<T>, DispatchError),
+
+		/// Offer made for token [buyer, token, amount]
+		OfferMade(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Collection offer made for launch token [buyer, launch token, amount, quantity]
+		CollectionOfferMade(T::AccountId, TokenId, BalanceOf<T>, u32),
+
+		/// Collection offer for launch token cancelled [buyer, launch token]
+		CollectionOfferCancelled(T::AccountId, TokenId),
+
+		/// Collection offer accepted with token [owner, buyer, token, amount]
+		CollectionOfferAccepted(T::AccountId, T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Offer floor of token updated [owner, token, floor]
+		OfferFloorUpdated(T::AccountId, TokenId, Option<BalanceOf<T>>),
+
+		/// Offer for token cancelled [buyer, token]
+		OfferCancelled(T::AccountId, TokenId),
+
+		/// Offer for token accepted [owner, buyer, token, amount]
+		OfferAccepted(T::AccountId, T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Offers accepted in batch [owner, count, total]
+		OffersAcceptedBatch(T::AccountId, u32, BalanceOf<T>),
+
+		/// Tokens transferred to new owner in batch [previous owner, new owner, count]
+		TokensTransferredBatch(T::AccountId, T::AccountId, u32),
+
+		/// Memo attached to token movement [account, token, memo]
+		MemoAttached(T::AccountId, TokenId, MemoOf<T>),
+
+		/// Cheapest listings of launch token swept [buyer, launch token, count, total]
+		TokensSwept(T::AccountId, TokenId, u32, BalanceOf<T>),
+
+		/// Listing of token of curated launch token requested [owner, token]
+		ListingRequested(T::AccountId, TokenId),
+
+		/// Listing of token of curated launch token approved [creator, token]
+		ListingApproved(CreatorId, TokenId),
+
+		/// Creator launch defaults updated [creator, royalty, beneficiary]
+		LaunchDefaultsUpdated(CreatorId, Permill, Option<T::AccountId>),
+
+		/// Royalty credited on secondary sale [recipient, token, amount]
+		RoyaltyPaid(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Royalty too small to fund the recipient account credited to the dust treasury
+		/// [recipient, token, amount]
+		RoyaltyDustRouted(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Launch token beneficiary updated [creator, launch token, beneficiary]
+		LaunchBeneficiaryUpdated(CreatorId, TokenId, Option<T::AccountId>),
+
+		/// Launch token royalty beneficiary updated [creator, launch token, beneficiary]
+		RoyaltyBeneficiaryUpdated(CreatorId, TokenId, Option<T::AccountId>),
+
+		/// Per block purchase cap of launch token updated [creator, launch token, cap]
+		BlockPurchaseCapUpdated(CreatorId, TokenId, Option<u32>),
+
+		/// Launch token drip schedule updated [creator, launch token, schedule]
+		DripScheduleUpdated(CreatorId, TokenId, Option<DripSchedule<T>>),
+
+		/// Purchase intent queued [account, launch token, max price]
+		PurchaseIntentQueued(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Snapshot of launch token holders taken [creator, snapshot, launch token, holders]
+		SnapshotTaken(CreatorId, SnapshotId, TokenId, u32),
+
+		/// Airdrop to snapshot holders queued [creator, launch token, snapshot, work]
+		AirdropQueued(CreatorId, TokenId, SnapshotId, WorkId),
+
+		/// Token airdropped to snapshot holder [receiver, launch token, token]
+		TokenAirdropped(T::AccountId, TokenId, TokenId),
+
+		/// Token could not be airdropped to snapshot holder [receiver, launch token, error]
+		AirdropFailed(T::AccountId, TokenId, DispatchError),
+
+		/// Royalties above a lowered max royalty queued for clamping [max royalty, work]
+		RoyaltyClampQueued(Permill, WorkId),
+
+		/// Launch token royalty lowered to the max royalty [launch token, royalty]
+		LaunchRoyaltyClamped(TokenId, Permill),
+
+		/// Work partially processed [work, progress, total]
+		WorkProgressed(WorkId, u32, u32),
+
+		/// Work fully processed [work]
+		WorkCompleted(WorkId),
+
+		/// Purchase intent failed to settle [account, launch token, error]
+		PurchaseIntentFailed(T::AccountId, TokenId, DispatchError),
+
+		/// Raffle opened [creator, launch token, ticket fee, winners, ends at]
+		RaffleOpened(CreatorId, TokenId, BalanceOf<T>, u32, T::BlockNumber),
+
+		/// Raffle entered [account, launch token, tickets]
+		RaffleEntered(T::AccountId, TokenId, u32),
+
+		/// Raffle drawn [launch token, winners]
+		RaffleDrawn(TokenId, u32),
+
+		/// Raffle cancelled and entrants refunded [launch token]
+		RaffleCancelled(TokenId),
+
+		/// Raffle winner not issued a token and refunded [account, launch token, error]
+		RaffleWinnerSkipped(T::AccountId, TokenId, DispatchError),
+
+		/// Token listed with declining price [owner, token, start price, end price, ends at]
+		TokenListedDutch(T::AccountId, TokenId, BalanceOf<T>, BalanceOf<T>, T::BlockNumber),
+
+		/// Token retired and kept out of circulation [owner, token]
+		TokenRetired(T::AccountId, TokenId),
+
+		/// Tokens permanently destroyed in batch [owner, count]
+		TokensDestroyedBatch(T::AccountId, u32),
+
+		/// Redeemable reserve refunded on burn [owner, token, amount]
+		TokenRedeemed(T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Token sale held in escrow [seller, buyer, token, price]
+		ProtectedSaleOpened(T::AccountId, T::AccountId, TokenId, BalanceOf<T>),
+
+		/// Protected sale reversed and funds returned to buyer [seller, buyer, token]
+		ProtectedSaleReversed(T::AccountId, T::AccountId, TokenId),
+
+		/// Work dropped since a single step exceeds the max block weight [work, progress, total]
+		WorkSkipped(WorkId, u32, u32),
+	}
+
+	// ERRORS
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Insufficient funds to complete buy operation
+		InsufficientFunds,
+
+		/// Signing account is not the owner of this item
+		NotOwner,
+
+		/// Creator account already taken
+		CreatorAccountTaken,
+
+		/// Token not found
+		TokenNotFound,
+
+		/// Token sold out of launch
+		TokenSoldOut,
+
+		/// Token not for sale
+		TokenNotForSale,
+
+		/// Token creator is unavailable
+		TokenUnavailable,
+
+		/// Token not listed
+		TokenNotListed,
+
+		/// Token already listed
+		TokenAlreadyListed,
+
+		/// Purchase total exceeds bid price
+		BidPriceTooLow,
+
+		/// Cannot set token supply to zero
+		ZeroSupply,
+
+		/// Token price is below the minimum token price
+		PriceTooLow,
+
+		/// Cannot transfer token to self
+		TransferToSelf,
+
+		/// Max number of creator accounts reached
+		MaxCreatorAccountsReached,
+
+		/// Max number of launch tokens reached
+		MaxLaunchTokensReached,
+
+		/// Max number of tokens reached
+		MaxTokensReached,
+
+		/// Max launch tokens minted
+		LaunchTokensOverflow,
+
+		/// Max tokens minted
+		TokensOverflow,
+
+		/// Signing account is blocked from the marketplace
+		AccountIsBlocked,
+
+		/// Account is not blocked from the marketplace
+		AccountNotBlocked,
+
+		/// Account has no proceeds to withdraw
+		NoProceeds,
+
+		/// Payout account is the account itself
+		InvalidPayoutAccount,
+
+		/// Account is already an attester
+		AttesterExists,
+
+		/// Account is not an attester
+		AttesterNotFound,
+
+		/// Social proof signature is invalid
+		InvalidSocialProof,
+
+		/// Social proof has expired
+		SocialProofExpired,
+
+		/// Max number of attested platforms of creator account reached
+		MaxSocialPlatformsReached,
+
+		/// Social handle is not attested
+		SocialAttestationNotFound,
+
+		/// Attestations of the removed attester are still being cleared
+		AttestationsPendingClear,
+
+		/// Price quote signature is invalid
+		InvalidQuoteSignature,
+
+		/// Price quote has expired
+		QuoteExpired,
+
+		/// Price quote was already executed or revoked
+		QuoteRevoked,
+
+		/// Price quote is for a different buyer
+		QuoteNotForAccount,
+
+		/// Destination chain is not supported by the bridge
+		UnsupportedBridgeChain,
+
+		/// Token is not locked in bridge custody
+		TokenNotBridged,
+
+		/// Bridge proof is invalid for token
+		InvalidBridgeProof,
+
+		/// Launch token is already featured
+		LaunchAlreadyFeatured,
+
+		/// Launch token is not featured
+		LaunchNotFeatured,
+
+		/// Max featured launch tokens reached
+		MaxFeaturedLaunchesReached,
+
+		/// Featured order does not contain exactly the featured launch tokens
+		InvalidFeaturedOrder,
+
+		/// Drop start is not in the future
+		InvalidDropStart,
+
+		/// Creator account already has a drop starting at the same block
+		DropAlreadyScheduled,
+
+		/// Drop not found on the launch calendar
+		DropNotFound,
+
+		/// Max drops on the launch calendar for the calendar period reached
+		MaxCalendarEntriesReached,
+
+		/// Creator account not found
+		CreatorNotFound,
+
+		/// Creator id is empty or contains the sub-creator separator
+		InvalidCreatorId,
+
+		/// Sub-creator id exceeds the max creator id length
+		CreatorIdTooLong,
+
+		/// Operation is not allowed on sub-creators
+		IsSubCreator,
+
+		/// Max number of sub-creators reached
+		MaxSubCreatorsReached,
+
+		/// Creator handover not found
+		CreatorTransferNotFound,
+
+		/// Creator handover can no longer be accepted
+		CreatorTransferExpired,
+
+		/// Creator recovery already pending
+		RecoveryAlreadyPending,
+
+		/// Creator recovery not found
+		RecoveryNotFound,
+
+		/// Creator recovery delay has not ended
+		RecoveryDelayNotOver,
+
+		/// Creator registration has expired and must be renewed
+		CreatorExpired,
+
+		/// Creator account is banned
+		CreatorIsBanned,
+
+		/// Creator account is not banned
+		CreatorNotBanned,
+
+		/// Creator account is already owned by its keyless account
+		CreatorAlreadyKeyless,
+
+		/// Creator account is not owned by its keyless account
+		CreatorNotKeyless,
+
+		/// Account is not a collaborator with the required role
+		NotCollaborator,
+
+		/// Max number of collaborators reached
+		MaxCollaboratorsReached,
+
+		/// Account is not an operator of creator account
+		NotOperator,
+
+		/// Operator expiry is not in the future
+		InvalidOperatorExpiry,
+
+		/// Max number of operators reached
+		MaxOperatorsReached,
+
+		/// Signing account does not have a verified identity
+		IdentityRequired,
+
+		/// Token is locked in escrow
+		TokenLocked,
+
+		/// Token is retired
+		TokenRetired,
+
+		/// Protected sale not found
+		ProtectedSaleNotFound,
+
+		/// Protected sale can no longer be disputed
+		DisputePeriodOver,
+
+		/// Protected sale cannot be finalized by seller during its dispute period
+		DisputePeriodNotOver,
+
+		/// Dutch listing end price is above start price or duration is zero
+		InvalidDutchListing,
+
+		/// Purchase quantity is zero or exceeds the max launch buy quantity
+		InvalidQuantity,
+
+		/// Launch token supply is short of the requested quantity
+		InsufficientSupply,
+
+		/// Max number of queued purchase intents reached
+		MaxPurchaseIntentsReached,
+
+		/// Snapshot not found
+		SnapshotNotFound,
+
+		/// Launch token has more holders than a snapshot can record
+		SnapshotTooLarge,
+
+		/// Snapshot id overflow
+		SnapshotsOverflow,
+
+		/// Max queued work reached
+		MaxQueuedWorkReached,
+
+		/// Work id overflow
+		WorkOverflow,
+
+		/// Gift supply exceeds launch token supply
+		InvalidGiftSupply,
+
+		/// Gift supply of launch token is exhausted
+		GiftSupplyExhausted,
+
+		/// Launch token already has an open raffle
+		RaffleAlreadyOpen,
+
+		/// Raffle not found
+		RaffleNotFound,
+
+		/// Raffle entries are closed
+		RaffleClosed,
+
+		/// Raffle entries are still open
+		RaffleNotClosed,
+
+		/// Max number of raffle entrants reached
+		MaxRaffleEntriesReached,
+
+		/// Max number of raffles closing in the same block reached
+		MaxRafflesClosingReached,
+
+		/// No listing could be bought within the sweep budget
+		NothingToSweep,
+
+		/// Reference price cannot be converted to native currency
+		PriceUnavailable,
+
+		/// Royalty exceeds the max royalty
+		RoyaltyTooHigh,
+
+		/// Payment would bring buyer balance below the existential deposit
+		PaymentWouldReap,
+
+		/// Proceeds are too small to fund the account
+		ProceedsBelowMinimum,
+
+		/// Offer not found
+		OfferNotFound,
+
+		/// Offer amount is below the offer floor of token
+		OfferBelowFloor,
+
+		/// Collection offer not found
+		CollectionOfferNotFound,
+
+		/// Collection offer has expired or expires in the past
+		CollectionOfferExpired,
+
+		/// Launch token has no milestones
+		MilestonesNotFound,
+
+		/// Milestone shares do not add up to 100%
+		InvalidMilestones,
+
+		/// Milestones cannot be changed once tokens have been issued
+		MilestonesLocked,
+
+		/// All milestones already approved
+		MilestonesComplete,
+
+		/// Matching pool not found
+		MatchingPoolNotFound,
+
+		/// Creator already has an active matching pool
+		MatchingPoolActive,
+
+		/// Matching ratio or amount is zero or matching window has ended
+		InvalidMatchingPool,
+
+		/// Launch token is a draft and cannot be issued
+		LaunchIsDraft,
+
+		/// Launch token is not a draft
+		LaunchNotDraft,
+
+		/// Launch token is paused
+		LaunchPaused,
+
+		/// Launch token is not paused
+		LaunchNotPaused,
+
+		/// Launch token is cancelled
+		LaunchCancelled,
+
+		/// Launch token is frozen by moderators
+		LaunchFrozen,
+
+		/// Launch token already has an open infringement claim
+		InfringementClaimExists,
+
+		/// Infringement claim not found
+		InfringementClaimNotFound,
+
+		/// Force calls are not enabled in this runtime
+		ForceCallsDisabled,
+
+		/// Call is disabled in this runtime
+		CallDisabled,
+
+		/// Sale reverses a recent trade between the same accounts
+		WashTrade,
+
+		/// Creator minted launch token with the same content before
+		DuplicateContent,
+
+		/// Account holds no tokens of launch token
+		NoTokensOfLaunch,
+
+		/// Token cannot be transferred or listed until its lockup after issuance is over
+		TokenInLockup,
+
+		/// Drip schedule amount or period is zero
+		InvalidDripSchedule,
+
+		/// Per block purchase cap is zero
+		InvalidPurchaseCap,
+
+		/// Account reached the per block purchase cap of launch token
+		BlockPurchaseCapReached,
+
+		/// Launch token does not require listing approval
+		LaunchNotCurated,
+
+		/// Token of curated launch token can only be listed with approval of the creator
+		ListingApprovalRequired,
+
+		/// Listing request not found
+		ListingRequestNotFound,
+
+		/// Launch token cannot be delegated to its own creator
+		InvalidDelegate,
+
+		/// Launch token has no delegate
+		DelegationNotFound,
+
+		/// Succession threshold is below the minimum
+		InvalidSuccessionThreshold,
+
+		/// Creator account has no succession for account
+		SuccessionNotFound,
+
+		/// Creator owner has been active within the succession threshold
+		OwnerStillActive,
+
+		/// Unlocked supply of drip schedule is bought, more supply unlocks in a later period
+		DripSupplyLocked,
+
+		/// Royalty enforced tokens can only be transferred to registered marketplaces
+		RoyaltyEnforced,
+
+		/// Account is already a registered marketplace
+		MarketplaceExists,
+
+		/// Account is not a registered marketplace
+		MarketplaceNotFound,
+
+		/// Media chunk index exceeds max chunks
+		InvalidChunkIndex,
+
+		/// Launch token has no media
+		MediaNotFound,
+
+		/// Media is finalized and cannot change
+		MediaFinalized,
+
+		/// Media has missing chunks
+		MediaIncomplete,
+
+		/// Tag not found in taxonomy
+		TagNotFound,
+
+		/// Tag is repeated
+		DuplicateTag,
+
+		/// Tags cannot be changed once tokens have been issued
+		TagsLocked,
+
+		/// Max tags created
+		TagsOverflow,
+
+		/// Report not found
+		ReportNotFound,
+
+		/// Max reports filed against launch token
+		ReportsOverflow,
+
+		/// Auction not found
+		AuctionNotFound,
+
+		/// Auction duration is zero or reserve or buy-now price is below the min bid or reserve
+		InvalidAuction,
+
+		/// Auction highest bid meets the reserve price, it can only be settled
+		AuctionReserveMet,
+
+		/// Auction has no buy-now price
+		BuyNowUnavailable,
+
+		/// Auction bidding has closed
+		AuctionEnded,
+
+		/// Auction bidding is still open
+		AuctionNotEnded,
+
+		/// Auction has bids and cannot be cancelled
+		AuctionHasBids,
+
+		/// Bid is below the min bid or does not raise the highest bid by the min increment
+		BidTooLow,
+
+		/// Token transfer rejected by transfer filter
+		TransferNotAllowed,
+
+		/// Token price cannot be raised until the price change cooldown has elapsed
+		PriceChangeTooSoon,
+
+		/// Max launch tokens for current mint period reached
+		MintQuotaExceeded,
+
+		/// Generated token id is already in use
+		TokenIdTaken,
+	}
+
+	// HOOKS
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			// seed raffles closing at this block
+			let weight = Self::seed_closing_raffles(n);
+
+			// settle purchase intents queued in previous blocks within the block budget
+			weight.saturating_add(Self::settle_purchase_intents(T::MaxExpiryWeightPerBlock::get()))
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// process queued multi-block work within the idle block time
+			Self::process_work_queue(remaining_weight)
+		}
+	}
+
+	// CALLS
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new creator account.
+		///
+		/// Creator ids whose registration and grace period have ended can be claimed.
+		#[pallet::weight(T::WeightInfo::create_account(T::MaxCreatorAccounts::get()))]
+		pub fn create_account(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::create_account {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure creator id is valid
+			Self::ensure_valid_creator_id(&creator_id)?;
+
+			// ensure account has a verified identity
+			ensure!(T::IdentityVerifier::has_identity(&account), Error::<T>::IdentityRequired);
+
+			Self::add_new_creator_to_account(creator_id.clone(), account.clone())?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::NewCreator(account, creator_id));
+
+			Ok(())
+		}
+
+		/// Drop creator account.
+		///
+		/// Keeps creator account alive if tokens have been created by the creator account.
+		#[pallet::weight(T::WeightInfo::drop_account_remove(T::MaxCreatorAccounts::get())
+			.max(T::WeightInfo::drop_account_disconnect(T::MaxCreatorAccounts::get())))]
+		pub fn drop_account(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::drop_account { creator_id: creator_id.clone() })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			Self::remove_creator_from_account(creator_id.clone(), account.clone())?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::DroppedCreator(account, creator_id));
+
+			Ok(())
+		}
+
+		/// Create new token.
+		///
+		/// Launch tokens minted as draft cannot be issued until published with `publish_launch`.
+		/// Deposit for on-chain content is reserved from account.
+		#[pallet::weight(T::WeightInfo::mint(
+			metadata.metadata_uri.len() as u32,
+			metadata.inline_data.as_ref().map_or(0, |data| data.len() as u32),
+		))]
+		pub fn mint(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			price: BalanceOf<T>,
+			mut metadata: LaunchTokenMetadata,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::mint {
+				creator_id: creator_id.clone(),
+				price,
+				metadata: metadata.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			// ensure price and metadata are valid
+			Self::ensure_valid_launch_metadata(&price, &metadata)?;
+
+			// mint launch token
+			let inline_data = metadata.inline_data.take();
+			let token_id = Self::unchecked_mint(creator_id.clone(), price, metadata)?;
+
+			// store on-chain content against deposit from account
+			if inline_data.is_some() {
+				Self::unchecked_set_inline_data(&account, &token_id, inline_data)?;
+			}
+
+			Self::record_creator_action(&creator_id, &account, CreatorAction::Mint, token_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenCreated(creator_id, token_id));
+
+			Ok(())
+		}
+
+		/// Gift token to account first hand.
+		///
+		/// Gifts are issued from the launch token's gift supply.
+		/// An optional `memo` is emitted with the events and not stored.
+		#[pallet::weight(T::WeightInfo::launch_gift(T::MaxTokens::get()))]
+		pub fn launch_gift(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			receiver: T::AccountId,
+			memo: Option<MemoOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::launch_gift {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				receiver: receiver.clone(),
+				memo: memo.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			// ensure transfer is allowed
+			ensure!(
+				T::TransferFilter::is_transfer_allowed(&account, &receiver, &launch_token_id),
+				Error::<T>::TransferNotAllowed
+			);
+
+			// transfer token to receiver from gift supply
+			let token_id = Self::unchecked_launch_gift(&receiver, &launch_token_id)?;
+
+			Self::record_creator_action(
+				&creator_id,
+				&account,
+				CreatorAction::Gift,
+				launch_token_id,
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenInitialCollection(
+				account.clone(),
+				creator_id,
+				token_id,
+			));
+			if let Some(memo) = memo {
+				Self::deposit_event(Event::<T>::MemoAttached(account, token_id, memo));
+			}
+
+			Ok(())
+		}
+
+		/// Buy token from creator first hand.
+		///
+		/// Reference priced launch tokens are converted to native currency at purchase time.
+		/// The redeemable share of the launch price is held in reserve until the token is burned.
+		///
+		/// `bid_price` is the maximum total the buyer is willing to pay, only the total is charged.
+		#[pallet::weight(T::WeightInfo::launch_buy(T::MaxTokens::get()))]
+		pub fn launch_buy(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::launch_buy { launch_token_id, bid_price })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let launch_token =
+				Self::launch_token(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// get launch token owner
+			let (launch_token_owner, launch_token_creator) =
+				Self::get_launch_token_owner(&launch_token_id)
+					.ok_or(Error::<T>::TokenUnavailable)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&launch_token_creator)?;
+
+			// ensure drip schedule has unlocked supply
+			let launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			ensure!(
+				Self::get_drip_available(&launch_token_id, &launch_token_state) > 0,
+				Error::<T>::DripSupplyLocked
+			);
+
+			// get launch price in native currency
+			let launch_price = Self::get_launch_price(&launch_token)?;
+
+			// ensure total does not exceed bid price
+			ensure!(bid_price >= launch_price, Error::<T>::BidPriceTooLow);
+
+			// ensure sufficient balance
+			Self::ensure_can_pay(&account, launch_price)?;
+
+			// ensure transfer is allowed
+			ensure!(
+				T::TransferFilter::is_transfer_allowed(
+					&launch_token_owner,
+					&account,
+					&launch_token_id
+				),
+				Error::<T>::TransferNotAllowed
+			);
+
+			let token_id = Self::unchecked_launch_buy(
+				&account,
+				&launch_token_owner,
+				&launch_token,
+				launch_price,
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenInitialCollection(
+				account,
+				launch_token_creator.clone(),
+				token_id,
+			));
+
+			Ok(())
+		}
+
+		/// Buy token from market.
+		///
+		/// `bid_price` is the maximum total the buyer is willing to pay, only the total is charged.
+		/// An optional `memo` is emitted with the events and not stored.
+		#[pallet::weight(T::WeightInfo::buy(T::MaxTokens::get()))]
+		pub fn buy(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			bid_price: BalanceOf<T>,
+			memo: Option<MemoOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::buy { token_id, bid_price, memo: memo.clone() })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// get current token price, return error if not for sale
+			let token_price = Self::get_current_price(&token).ok_or(Error::<T>::TokenNotForSale)?;
+
+			// ensure total does not exceed bid price
+			ensure!(bid_price >= token_price, Error::<T>::BidPriceTooLow);
+
+			// ensure sufficient balance
+			Self::ensure_can_pay(&account, token_price)?;
+
+			Self::unchecked_buy(&account, &token, token_price)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(
+				token.owner,
+				account.clone(),
+				token_id,
+			));
+			if let Some(memo) = memo {
+				Self::deposit_event(Event::<T>::MemoAttached(account, token_id, memo));
+			}
+
+			Ok(())
+		}
+
+		/// Transfer token to account.
+		///
+		/// An optional `memo` is emitted with the events and not stored.
+		#[pallet::weight(T::WeightInfo::transfer(T::MaxTokens::get()))]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			receiver: T::AccountId,
+			memo: Option<MemoOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::transfer {
+				token_id,
+				receiver: receiver.clone(),
+				memo: memo.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// check if token exists and return `NotFound` error early
+			Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure account can transfer token to receiver
+			Self::ensure_token_transferable(&account, &token_id, &receiver)?;
+
+			// transfer token to receiver
+			Self::unchecked_transfer(&account, &receiver, &token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(account.clone(), receiver, token_id));
+			if let Some(memo) = memo {
+				Self::deposit_event(Event::<T>::MemoAttached(account, token_id, memo));
+			}
+
+			Ok(())
+		}
+
+		/// List token on market.
+		#[pallet::weight(T::WeightInfo::list())]
+		pub fn list(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::list { token_id, price })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure token does not have a price
+			ensure!(Self::get_token_price(&token_id).is_none(), Error::<T>::TokenAlreadyListed);
+
+			// ensure listing of curated launch token is approved
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::consume_listing_approval(&token)?;
+
+			// ensure price is not below minimum
+			Self::ensure_valid_price(&price)?;
+
+			// ensure price is not raised within cooldown
+			Self::ensure_price_change_allowed(&token_id, &price)?;
+
+			Self::unchecked_set_price(&token_id, Some(price))?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenListed(account, token_id, Some(price)));
+
+			Ok(())
+		}
+
+		/// Unlist token from market.
+		#[pallet::weight(T::WeightInfo::unlist())]
+		pub fn unlist(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::unlist { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token has price
+			ensure!(Self::get_token_price(&token_id).is_some(), Error::<T>::TokenNotListed);
+
+			// update token price
+			Self::unchecked_set_price(&token_id, None)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenUnlisted(account, token_id, None));
+
+			Ok(())
+		}
+
+		/// Update launch price of token.
+		///
+		/// Price is in the launch token's price mode.
+		#[pallet::weight(T::WeightInfo::set_launch_price())]
+		pub fn set_launch_price(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_launch_price {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				price,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure price is not below minimum
+			let launch_token =
+				Self::launch_token(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::ensure_valid_launch_price(launch_token.price_mode, &price)?;
+
+			// update launch token price
+			Self::unchecked_set_launch_price(&launch_token_id, price)?;
+
+			Self::record_creator_action(
+				&creator_id,
+				&account,
+				CreatorAction::PriceChange,
+				launch_token_id,
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenLaunchPriceUpdated(
+				creator_id,
+				launch_token_id,
+				Some(price),
+			));
+
+			Ok(())
+		}
+
+		/// Update price of token.
+		///
+		/// Price increases are only allowed once the price change cooldown has elapsed.
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_price { token_id, price })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token has price
+			ensure!(Self::get_token_price(&token_id).is_some(), Error::<T>::TokenNotListed);
+
+			// ensure price is not below minimum
+			Self::ensure_valid_price(&price)?;
+
+			// ensure price is not raised within cooldown
+			Self::ensure_price_change_allowed(&token_id, &price)?;
+
+			// update token price
+			Self::unchecked_set_price(&token_id, Some(price))?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenPriceUpdated(account, token_id, Some(price)));
+
+			Ok(())
+		}
+
+		/// Destroy token.
+		///
+		/// Refunds the token's redeemable reserve to its owner.
+		#[pallet::weight(T::WeightInfo::burn(T::MaxTokens::get()))]
+		pub fn burn(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::burn { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// refund redeemable reserve to owner
+			let redeemed = Self::unchecked_redeem(&account, &token_id)?;
+
+			Self::unchecked_burn(&token_id)?;
+
+			// emit events
+			if !redeemed.is_zero() {
+				Self::deposit_event(Event::<T>::TokenRedeemed(account.clone(), token_id, redeemed));
+			}
+			Self::deposit_event(Event::<T>::TokenDestroyed(account, token_id));
+
+			Ok(())
+		}
+
+		/// Create sub-creator `creator_id/name` under creator account.
+		///
+		/// Sub-creators are owned by the owner of their parent, follow its registration and have
+		/// their own launch tokens and collaborators.
+		#[pallet::weight(T::WeightInfo::create_sub_creator())]
+		pub fn create_sub_creator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			name: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::create_sub_creator {
+				creator_id: creator_id.clone(),
+				name: name.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure sub-creator name is valid
+			Self::ensure_valid_creator_id(&name)?;
+
+			// verify account owns creator account
+			let parent = Self::ensure_account_holds_creator(&account, &creator_id)?;
+
+			// ensure creator registration has not expired
+			ensure!(!Self::is_creator_expired(&parent), Error::<T>::CreatorExpired);
+
+			let sub_creator_id = Self::unchecked_add_sub_creator(&parent, &name)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::SubCreatorCreated(creator_id, sub_creator_id));
+
+			Ok(())
+		}
+
+		/// Renew creator registration for another registration period.
+		///
+		/// Expired registrations can be renewed until they are claimed by another account.
+		#[pallet::weight(T::WeightInfo::renew_creator())]
+		pub fn renew_creator(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::renew_creator {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_holds_creator(&account, &creator_id)?;
+
+			// burn renewal fee
+			let fee = Self::creator_renewal_fee();
+			if !fee.is_zero() {
+				T::Payment::charge_fee(&account, fee).map_err(|_| Error::<T>::InsufficientFunds)?;
+			}
+
+			let expires_at = Self::unchecked_renew_creator(&creator_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorRenewed(creator_id, expires_at));
+
+			Ok(())
+		}
+
+		/// Add collaborator to creator account or update role of existing collaborator.
+		#[pallet::weight(T::WeightInfo::add_collaborator())]
+		pub fn add_collaborator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			collaborator: T::AccountId,
+			role: CollaboratorRole,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::add_collaborator {
+				creator_id: creator_id.clone(),
+				collaborator: collaborator.clone(),
+				role,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			Self::unchecked_add_collaborator(&creator_id, &collaborator, role)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CollaboratorAdded(creator_id, collaborator, role));
+
+			Ok(())
+		}
+
+		/// Remove collaborator from creator account.
+		#[pallet::weight(T::WeightInfo::remove_collaborator())]
+		pub fn remove_collaborator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			collaborator: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::remove_collaborator {
+				creator_id: creator_id.clone(),
+				collaborator: collaborator.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			Self::unchecked_remove_collaborator(&creator_id, &collaborator)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CollaboratorRemoved(creator_id, collaborator));
+
+			Ok(())
+		}
+
+		/// Add short-lived operator key to creator account or update expiry of existing operator.
+		///
+		/// Operators manage the creator account like collaborators until `expires_at`, e.g. to
+		/// gift tokens or update prices, but cannot transfer the creator account or change payout
+		/// settings. Operators are removed when the creator account changes owner.
+		#[pallet::weight(T::WeightInfo::add_operator())]
+		pub fn add_operator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			operator: T::AccountId,
+			expires_at: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::add_operator {
+				creator_id: creator_id.clone(),
+				operator: operator.clone(),
+				expires_at,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			// ensure operator expires in the future
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidOperatorExpiry
+			);
+
+			Self::unchecked_add_operator(&creator_id, &operator, expires_at)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::OperatorAdded(creator_id, operator, expires_at));
+
+			Ok(())
+		}
+
+		/// Remove operator key from creator account.
+		#[pallet::weight(T::WeightInfo::remove_operator())]
+		pub fn remove_operator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			operator: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::remove_operator {
+				creator_id: creator_id.clone(),
+				operator: operator.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			Self::unchecked_remove_operator(&creator_id, &operator)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::OperatorRemoved(creator_id, operator));
+
+			Ok(())
+		}
+
+		/// Hand creator account over to its keyless pallet-derived account.
+		///
+		/// The keyless account can only be operated by admin collaborators through
+		/// `dispatch_as_creator`, so admins must be added before handing over.
+		#[pallet::weight(T::WeightInfo::make_creator_keyless())]
+		pub fn make_creator_keyless(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::make_creator_keyless {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			let keyless_account = Self::keyless_creator_account(&creator_id);
+
+			// ensure creator account is not already keyless
+			ensure!(account != keyless_account, Error::<T>::CreatorAlreadyKeyless);
+
+			Self::unchecked_set_creator_owner(&creator_id, keyless_account.clone())?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorOwnerChanged(
+				creator_id,
+				account,
+				keyless_account,
+			));
+
+			Ok(())
+		}
+
+		/// Dispatch call as keyless creator account.
+		///
+		/// Only admin collaborators of creator accounts owned by their keyless account can
+		/// dispatch calls.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				dispatch_info.weight.saturating_add(T::WeightInfo::dispatch_as_creator()),
+				dispatch_info.class,
+			)
+		})]
+		pub fn dispatch_as_creator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::dispatch_as_creator {
+				creator_id: creator_id.clone(),
+				call: call.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account is an admin of keyless creator account
+			let keyless_account = Self::ensure_keyless_creator_admin(&account, &creator_id)?;
+
+			let result = call
+				.dispatch(frame_system::RawOrigin::Signed(keyless_account).into())
+				.map(|_| ())
+				.map_err(|e| e.error);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorCallDispatched(creator_id, account, result));
+
+			Ok(())
+		}
+
+		/// Create new launch token copying metadata of a launch token owned by creator account.
+		///
+		/// Price, supply, gift supply and sale start can be overridden. The drip schedule of the
+		/// source launch token is copied to the clone.
+		#[pallet::weight(T::WeightInfo::clone_launch(2048))]
+		pub fn clone_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			source_launch_id: TokenId,
+			overrides: LaunchCloneOverridesOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::clone_launch {
+				creator_id: creator_id.clone(),
+				source_launch_id,
+				overrides: overrides.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &source_launch_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			let (price, metadata) = Self::get_clone_metadata(&source_launch_id, &overrides)?;
+
+			// ensure price is not below minimum
+			Self::ensure_valid_launch_price(metadata.price_mode, &price)?;
+
+			// ensure gift supply is part of supply
+			ensure!(metadata.gift_supply <= metadata.supply, Error::<T>::InvalidGiftSupply);
+
+			// ensure tags still exist in taxonomy
+			Self::ensure_valid_tags(&metadata.tags)?;
+
+			// mint launch token
+			let token_id = Self::unchecked_mint(creator_id.clone(), price, metadata.clone())?;
+			let schedule = Self::unchecked_clone_drip_schedule(
+				&source_launch_id,
+				&token_id,
+				&metadata,
+				overrides.sale_start,
+			);
+
+			Self::record_creator_action(&creator_id, &account, CreatorAction::Mint, token_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenCreated(creator_id.clone(), token_id));
+			Self::deposit_event(Event::<T>::LaunchCloned(
+				creator_id.clone(),
+				source_launch_id,
+				token_id,
+			));
+			if schedule.is_some() {
+				Self::deposit_event(Event::<T>::DripScheduleUpdated(
+					creator_id, token_id, schedule,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Buy token at the price of a quote signed off-chain by its owner, without the token being
+		/// listed.
+		///
+		/// Only the buyer of the quote can execute it. Executing a quote invalidates all other
+		/// outstanding quotes of the seller.
+		#[pallet::weight(T::WeightInfo::execute_quote(T::MaxTokens::get()))]
+		pub fn execute_quote(
+			origin: OriginFor<T>,
+			quote: PriceQuoteOf<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::execute_quote {
+				quote: quote.clone(),
+				signature: signature.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is the buyer of the quote
+			ensure!(quote.buyer == account, Error::<T>::QuoteNotForAccount);
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure quote is signed by seller and still valid
+			Self::ensure_quote_valid(&quote, &signature)?;
+
+			// ensure price is not below minimum
+			Self::ensure_valid_price(&quote.price)?;
+
+			let token = Self::token(quote.token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure seller owns token
+			Self::ensure_account_owns_token(&quote.seller, &quote.token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&quote.token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&quote.token_id)?;
+
+			// ensure sufficient balance
+			Self::ensure_can_pay(&account, quote.price)?;
+
+			// invalidate executed and other outstanding quotes of seller
+			Self::bump_quote_nonce(&quote.seller);
+
+			Self::unchecked_buy(&account, &token, quote.price)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(
+				quote.seller.clone(),
+				account.clone(),
+				quote.token_id,
+			));
+			Self::deposit_event(Event::<T>::QuoteExecuted(
+				quote.seller,
+				account,
+				quote.token_id,
+				quote.price,
+			));
+
+			Ok(())
+		}
+
+		/// Revoke all outstanding price quotes signed by the signing account.
+		#[pallet::weight(T::WeightInfo::revoke_quotes())]
+		pub fn revoke_quotes(origin: OriginFor<T>) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::revoke_quotes {})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let nonce = Self::bump_quote_nonce(&account);
+
+			// emit events
+			Self::deposit_event(Event::<T>::QuotesRevoked(account, nonce));
+
+			Ok(())
+		}
+
+		/// Lock token in bridge custody to move it to an address on another chain.
+		///
+		/// The token cannot be transferred, listed or sold until it is released with a proof that
+		/// it was returned, see `bridge_unlock`.
+		#[pallet::weight(T::WeightInfo::bridge_lock())]
+		pub fn bridge_lock(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			dest_chain: ChainId,
+			dest_address: BridgeAddress,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::bridge_lock {
+				token_id,
+				dest_chain,
+				dest_address: dest_address.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure destination chain is supported
+			ensure!(
+				T::BridgeVerifier::supports_chain(dest_chain),
+				Error::<T>::UnsupportedBridgeChain
+			);
+
+			Self::unchecked_bridge_lock(
+				account.clone(),
+				&token_id,
+				dest_chain,
+				dest_address.clone(),
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenBridgeLocked(
+				account,
+				token_id,
+				dest_chain,
+				dest_address,
+			));
+
+			Ok(())
+		}
+
+		/// Release token from bridge custody with a proof that it was returned from another chain.
+		///
+		/// Any account can submit the proof, e.g. a bridge relayer. The token is released to the
+		/// account named by the proof.
+		#[pallet::weight(T::WeightInfo::bridge_unlock(T::MaxTokens::get()))]
+		pub fn bridge_unlock(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			proof: BridgeProofOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::bridge_unlock {
+				token_id,
+				proof: proof.clone(),
+			})?;
+
+			// allow only signed origin
+			Self::ensure_signed_tracked(origin)?;
+
+			// ensure token is in bridge custody
+			let custody = Self::bridge_custody(token_id).ok_or(Error::<T>::TokenNotBridged)?;
+
+			// ensure proof releases token
+			let receiver = T::BridgeVerifier::verify_unlock(&token_id, &custody, &proof)
+				.ok_or(Error::<T>::InvalidBridgeProof)?;
+
+			let custody = Self::unchecked_bridge_unlock(&token_id, &receiver)?;
+
+			// emit events
+			if custody.owner != receiver {
+				Self::deposit_event(Event::<T>::TokenTransferred(
+					custody.owner,
+					receiver.clone(),
+					token_id,
+				));
+			}
+			Self::deposit_event(Event::<T>::TokenBridgeUnlocked(receiver, token_id));
+
+			Ok(())
+		}
+
+		/// Transfer multiple tokens to account.
+		///
+		/// All tokens are transferred or none.
+		#[pallet::weight(T::WeightInfo::transfer_batch(token_ids.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			token_ids: BoundedVec<TokenId, T::MaxBatch>,
+			receiver: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::transfer_batch {
+				token_ids: token_ids.clone(),
+				receiver: receiver.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			for token_id in token_ids.iter() {
+				// check if token exists and return `NotFound` error early
+				Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure account can transfer token to receiver
+				Self::ensure_token_transferable(&account, token_id, &receiver)?;
+
+				// transfer token to receiver
+				Self::unchecked_transfer(&account, &receiver, token_id)?;
+			}
+
+			// emit events
+			for token_id in token_ids.iter() {
+				Self::deposit_event(Event::<T>::TokenTransferred(
+					account.clone(),
+					receiver.clone(),
+					*token_id,
+				));
+			}
+			Self::deposit_event(Event::<T>::TokensTransferredBatch(
+				account,
+				receiver,
+				token_ids.len() as u32,
+			));
+
+			Ok(())
+		}
+
+		/// Make offer for token.
+		///
+		/// Offer amount is reserved until the offer is accepted or cancelled.
+		/// A standing offer of the signing account for the token is replaced.
+		/// Offers below the offer floor set by the token owner are rejected.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::make_offer { token_id, amount })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure account does not own token
+			ensure!(token.owner != account, Error::<T>::TransferToSelf);
+
+			// ensure amount is not below min token price
+			Self::ensure_valid_price(&amount)?;
+
+			// ensure amount is not below offer floor of token
+			Self::ensure_offer_above_floor(&token_id, &amount)?;
+
+			Self::unchecked_make_offer(&account, &token_id, amount)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::OfferMade(account, token_id, amount));
+
+			Ok(())
+		}
+
+		/// Set min offer amount accepted for token, removes the offer floor if `None`.
+		///
+		/// Standing offers are not affected. The offer floor is cleared when the token changes
+		/// owner.
+		#[pallet::weight(T::WeightInfo::set_offer_floor())]
+		pub fn set_offer_floor(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			floor: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_offer_floor { token_id, floor })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			OfferFloors::<T>::set(&token_id, floor);
+
+			// emit events
+			Self::deposit_event(Event::<T>::OfferFloorUpdated(account, token_id, floor));
+
+			Ok(())
+		}
+
+		/// Cancel offer for token.
+		///
+		/// Offers can be cancelled even if the token no longer exists.
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_offer { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			Self::unchecked_cancel_offer(&account, &token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::OfferCancelled(account, token_id));
+
+			Ok(())
+		}
+
+		/// Accept offer for token.
+		#[pallet::weight(T::WeightInfo::accept_offer(T::MaxTokens::get()))]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::accept_offer { token_id, buyer: buyer.clone() })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// check if token exists and return `NotFound` error early
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure buyer is not blocked
+			Self::ensure_account_not_blocked(&buyer)?;
+
+			let amount = Self::unchecked_accept_offer(&token, &buyer)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(
+				account.clone(),
+				buyer.clone(),
+				token_id,
+			));
+			Self::deposit_event(Event::<T>::OfferAccepted(account, buyer, token_id, amount));
+
+			Ok(())
+		}
+
+		/// Make offer for `quantity` tokens of launch token at `amount` each, valid until block
+		/// `expiry`.
+		///
+		/// The amount for the whole quantity is reserved until tokens are sold or the offer is
+		/// cancelled. A standing collection offer of the signing account for the launch token is
+		/// replaced.
+		#[pallet::weight(T::WeightInfo::make_collection_offer())]
+		pub fn make_collection_offer(
+			origin: OriginFor<T>,
+			launch_id: TokenId,
+			amount: BalanceOf<T>,
+			quantity: u32,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::make_collection_offer {
+				launch_id,
+				amount,
+				quantity,
+				expiry,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure launch token exists
+			ensure!(Self::launch_token(launch_id).is_some(), Error::<T>::TokenNotFound);
+
+			// ensure amount is not below min token price
+			Self::ensure_valid_price(&amount)?;
+
+			// ensure offer is for at least one token
+			ensure!(quantity > 0, Error::<T>::InvalidQuantity);
+
+			let offer = CollectionOffer {
+				amount,
+				quantity,
+				expires_at: expiry,
+				deposit: T::OfferDeposit::get(),
+			};
+
+			// ensure offer expires in the future
+			ensure!(!offer.is_expired(), Error::<T>::CollectionOfferExpired);
+
+			Self::unchecked_make_collection_offer(&account, &launch_id, offer)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CollectionOfferMade(
+				account, launch_id, amount, quantity,
+			));
+
+			Ok(())
+		}
+
+		/// Cancel collection offer for launch token.
+		///
+		/// Expired collection offers are cancelled to release their reserved amount.
+		#[pallet::weight(T::WeightInfo::cancel_collection_offer())]
+		pub fn cancel_collection_offer(origin: OriginFor<T>, launch_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_collection_offer { launch_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			Self::unchecked_cancel_collection_offer(&account, &launch_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CollectionOfferCancelled(account, launch_id));
+
+			Ok(())
+		}
+
+		/// Sell token to collection offer of buyer for its launch token.
+		#[pallet::weight(T::WeightInfo::accept_collection_offer(T::MaxTokens::get()))]
+		pub fn accept_collection_offer(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::accept_collection_offer {
+				token_id,
+				buyer: buyer.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// check if token exists and return `NotFound` error early
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure buyer is not blocked
+			Self::ensure_account_not_blocked(&buyer)?;
+
+			let amount = Self::unchecked_accept_collection_offer(&token, &buyer)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(
+				account.clone(),
+				buyer.clone(),
+				token_id,
+			));
+			Self::deposit_event(Event::<T>::CollectionOfferAccepted(
+				account, buyer, token_id, amount,
+			));
+
+			Ok(())
+		}
+
+		/// Accept multiple offers for tokens.
+		///
+		/// All offers are accepted or none.
+		#[pallet::weight(T::WeightInfo::accept_offers_batch(offers.len() as u32))]
+		pub fn accept_offers_batch(
+			origin: OriginFor<T>,
+			offers: BoundedVec<(TokenId, T::AccountId), T::MaxBatch>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::accept_offers_batch { offers: offers.clone() })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let mut total: BalanceOf<T> = Zero::zero();
+			let mut accepted = Vec::with_capacity(offers.len());
+			for (token_id, buyer) in offers.into_iter() {
+				// check if token exists and return `NotFound` error early
+				let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure account owns token
+				Self::ensure_account_owns_token(&account, &token_id)?;
+
+				// ensure token is not locked
+				Self::ensure_token_unlocked(&token_id)?;
+
+				// ensure token transfer lockup is over
+				Self::ensure_lockup_over(&token_id)?;
+
+				// ensure buyer is not blocked
+				Self::ensure_account_not_blocked(&buyer)?;
+
+				let amount = Self::unchecked_accept_offer(&token, &buyer)?;
+
+				total = total.saturating_add(amount);
+				accepted.push((token_id, buyer, amount));
+			}
+
+			// emit events
+			let count = accepted.len() as u32;
+			for (token_id, buyer, amount) in accepted {
+				Self::deposit_event(Event::<T>::TokenTransferred(
+					account.clone(),
+					buyer.clone(),
+					token_id,
+				));
+				Self::deposit_event(Event::<T>::OfferAccepted(
+					account.clone(),
+					buyer,
+					token_id,
+					amount,
+				));
+			}
+			Self::deposit_event(Event::<T>::OffersAcceptedBatch(account, count, total));
+
+			Ok(())
+		}
+
+		/// Request approval of the creator to list token of curated launch token.
+		#[pallet::weight(T::WeightInfo::request_listing())]
+		pub fn request_listing(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::request_listing { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// check if token exists and return `NotFound` error early
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure account owns token
+			ensure!(token.owner == account, Error::<T>::NotOwner);
+
+			// ensure launch token requires listing approval
+			let launch_token =
+				Self::launch_token(token.launch_id).ok_or(Error::<T>::TokenNotFound)?;
+			ensure!(launch_token.curated, Error::<T>::LaunchNotCurated);
+
+			ListingRequests::<T>::insert(
+				&token_id,
+				ListingRequest { owner: account.clone(), approved: false },
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::ListingRequested(account, token_id));
+
+			Ok(())
+		}
+
+		/// Approve request to list token of curated launch token.
+		///
+		/// The approval is consumed when the token is listed and lapses if the token changes owner.
+		#[pallet::weight(T::WeightInfo::approve_listing())]
+		pub fn approve_listing(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::approve_listing {
+				creator_id: creator_id.clone(),
+				token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &token.launch_id)?;
+
+			ListingRequests::<T>::try_mutate(&token_id, |request| {
+				let request = request.as_mut().ok_or(Error::<T>::ListingRequestNotFound)?;
+				request.approved = true;
+				Ok::<(), Error<T>>(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::ListingApproved(creator_id, token_id));
+
+			Ok(())
+		}
+
+		/// Retire token, keeping its record for provenance while removing it from circulation.
+		///
+		/// Retired tokens are delisted and can no longer be transferred, listed or burned. Refunds
+		/// the token's redeemable reserve to its owner.
+		#[pallet::weight(T::WeightInfo::retire(T::MaxTokens::get()))]
+		pub fn retire(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::retire { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked or already retired
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// refund redeemable reserve to owner
+			let redeemed = Self::unchecked_redeem(&account, &token_id)?;
+
+			Self::unchecked_retire(&token_id)?;
+
+			// emit events
+			if !redeemed.is_zero() {
+				Self::deposit_event(Event::<T>::TokenRedeemed(account.clone(), token_id, redeemed));
+			}
+			Self::deposit_event(Event::<T>::TokenRetired(account, token_id));
+
+			Ok(())
+		}
+
+		/// Destroy multiple tokens.
+		///
+		/// Refunds each token's redeemable reserve to its owner. All tokens are destroyed or none.
+		#[pallet::weight(T::WeightInfo::burn_batch(token_ids.len() as u32))]
+		pub fn burn_batch(
+			origin: OriginFor<T>,
+			token_ids: BoundedVec<TokenId, T::MaxBatch>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::burn_batch { token_ids: token_ids.clone() })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let mut redeemed = Vec::with_capacity(token_ids.len());
+			for token_id in token_ids.iter() {
+				// check if token exists and return `NotFound` error early
+				Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure account owns token
+				Self::ensure_account_owns_token(&account, token_id)?;
+
+				// ensure token is not locked
+				Self::ensure_token_unlocked(token_id)?;
+
+				// refund redeemable reserve to owner
+				redeemed.push(Self::unchecked_redeem(&account, token_id)?);
+
+				Self::unchecked_burn(token_id)?;
+			}
+
+			// emit events
+			for (token_id, redeemed) in token_ids.iter().zip(redeemed) {
+				if !redeemed.is_zero() {
+					Self::deposit_event(Event::<T>::TokenRedeemed(
+						account.clone(),
+						*token_id,
+						redeemed,
+					));
+				}
+				Self::deposit_event(Event::<T>::TokenDestroyed(account.clone(), *token_id));
+			}
+			Self::deposit_event(Event::<T>::TokensDestroyedBatch(account, token_ids.len() as u32));
+
+			Ok(())
+		}
+
+		/// Buy token from market with payment held in escrow.
+		///
+		/// Payment is reserved and the token locked until the sale is finalized. During the dispute
+		/// period the sale can be reversed by the dispute origin.
+		///
+		/// `bid_price` is the maximum total the buyer is willing to pay, only the total is reserved.
+		#[pallet::weight(T::WeightInfo::buy_protected())]
+		pub fn buy_protected(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::buy_protected { token_id, bid_price })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// get current token price, return error if not for sale
+			let token_price = Self::get_current_price(&token).ok_or(Error::<T>::TokenNotForSale)?;
+
+			// ensure total does not exceed bid price
+			ensure!(bid_price >= token_price, Error::<T>::BidPriceTooLow);
+
+			// ensure transfer is allowed
+			ensure!(
+				T::TransferFilter::is_transfer_allowed(&token.owner, &account, &token_id),
+				Error::<T>::TransferNotAllowed
+			);
+
+			// hold sale in escrow
+			Self::unchecked_open_protected_sale(
+				token.owner.clone(),
+				account.clone(),
+				&token_id,
+				token_price,
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::ProtectedSaleOpened(
+				token.owner,
+				account,
+				token_id,
+				token_price,
+			));
+
+			Ok(())
+		}
+
+		/// Finalize protected sale.
+		///
+		/// Buyer can finalize at any time, seller only after the dispute period.
+		#[pallet::weight(T::WeightInfo::finalize_sale(T::MaxTokens::get()))]
+		pub fn finalize_sale(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::finalize_sale { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let sale = Self::protected_sale(token_id).ok_or(Error::<T>::ProtectedSaleNotFound)?;
+
+			// ensure account is party to the sale
+			ensure!(account == sale.buyer || account == sale.seller, Error::<T>::NotOwner);
+
+			// ensure seller waits for dispute period to end
+			ensure!(
+				account == sale.buyer || Self::is_dispute_period_over(&sale),
+				Error::<T>::DisputePeriodNotOver
+			);
+
+			let sale = Self::unchecked_finalize_protected_sale(&token_id)?;
+
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::record_sale(
+				&token.launch_id,
+				token_id,
+				sale.seller.clone(),
+				sale.buyer.clone(),
+				sale.price,
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTransferred(sale.seller, sale.buyer, token_id));
+
+			Ok(())
+		}
+
+		/// Reverse protected sale during its dispute period.
+		///
+		/// Reserved funds are returned to buyer and the token remains with seller.
+		#[pallet::weight(T::WeightInfo::reverse_sale())]
+		pub fn reverse_sale(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::reverse_sale { token_id })?;
+
+			// allow only dispute origin
+			T::DisputeOrigin::ensure_origin(origin)?;
+
+			let sale = Self::protected_sale(token_id).ok_or(Error::<T>::ProtectedSaleNotFound)?;
+
+			// ensure sale can still be disputed
+			ensure!(!Self::is_dispute_period_over(&sale), Error::<T>::DisputePeriodOver);
+
+			let sale = Self::unchecked_reverse_protected_sale(&token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::ProtectedSaleReversed(
+				sale.seller,
+				sale.buyer,
+				token_id,
+			));
+
+			Ok(())
+		}
+
+		/// Ban creator account.
+		///
+		/// Banned creators cannot mint, gift or sell launch tokens.
+		#[pallet::weight(T::WeightInfo::ban_creator())]
+		pub fn ban_creator(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::ban_creator { creator_id: creator_id.clone() })?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure creator account exists
+			ensure!(Creators::<T>::contains_key(&creator_id), Error::<T>::CreatorNotFound);
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			BannedCreators::<T>::insert(&creator_id, frame_system::Pallet::<T>::block_number());
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorBanned(creator_id));
+
+			Ok(())
+		}
+
+		/// Lift ban on creator account.
+		#[pallet::weight(T::WeightInfo::unban_creator())]
+		pub fn unban_creator(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::unban_creator {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure creator account is banned
+			ensure!(BannedCreators::<T>::contains_key(&creator_id), Error::<T>::CreatorNotBanned);
+
+			BannedCreators::<T>::remove(&creator_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorUnbanned(creator_id));
+
+			Ok(())
+		}
+
+		/// Block account from buying and listing tokens.
+		///
+		/// Blocked accounts can still transfer their tokens out.
+		#[pallet::weight(T::WeightInfo::block_account())]
+		pub fn block_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::block_account { account: account.clone() })?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			BlockedAccounts::<T>::insert(&account, frame_system::Pallet::<T>::block_number());
+
+			// emit events
+			Self::deposit_event(Event::<T>::AccountBlocked(account));
+
+			Ok(())
+		}
+
+		/// Lift marketplace block on account.
+		#[pallet::weight(T::WeightInfo::unblock_account())]
+		pub fn unblock_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::unblock_account { account: account.clone() })?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure account is blocked
+			ensure!(BlockedAccounts::<T>::contains_key(&account), Error::<T>::AccountNotBlocked);
+
+			BlockedAccounts::<T>::remove(&account);
+
+			// emit events
+			Self::deposit_event(Event::<T>::AccountUnblocked(account));
+
+			Ok(())
+		}
+
+		/// Change governance mutable pallet parameter.
+		///
+		/// The max royalty cannot exceed the runtime royalty ceiling. Lowering it queues work
+		/// clamping royalties of existing launch tokens.
+		#[pallet::weight(T::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			parameter: PalletParameterOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_parameter { parameter: parameter.clone() })?;
+
+			// allow only admin origin
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			// ensure max royalty does not exceed the royalty ceiling
+			if let PalletParameter::MaxRoyalty(Some(royalty)) = &parameter {
+				ensure!(*royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+			}
+
+			let previous_max_royalty = Self::max_royalty();
+			Self::unchecked_set_parameter(&parameter);
+
+			// clamp royalties of existing launch tokens to a lowered max royalty
+			let max_royalty = Self::max_royalty();
+			let clamp_work_id = if max_royalty < previous_max_royalty {
+				let total = u32::try_from(Self::launch_issuance_nonce()).unwrap_or(u32::MAX);
+				Some(Self::unchecked_queue_work(WorkItem::ClampRoyalties { max_royalty }, total)?)
+			} else {
+				None
+			};
+
+			// emit events
+			Self::deposit_event(Event::<T>::ParameterChanged(parameter));
+			if let Some(work_id) = clamp_work_id {
+				Self::deposit_event(Event::<T>::RoyaltyClampQueued(max_royalty, work_id));
+			}
+
+			Ok(())
+		}
+
+		/// Allow account to attest social handles of creator accounts.
+		#[pallet::weight(T::WeightInfo::add_attester())]
+		pub fn add_attester(origin: OriginFor<T>, attester: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::add_attester { attester: attester.clone() })?;
+
+			// allow only attestation origin
+			T::AttestationOrigin::ensure_origin(origin)?;
+
+			// ensure account is not an attester
+			ensure!(!Attesters::<T>::contains_key(&attester), Error::<T>::AttesterExists);
+
+			// ensure attestations of the account as a previous attester are cleared
+			ensure!(Self::attestation_count(&attester) == 0, Error::<T>::AttestationsPendingClear);
+
+			Attesters::<T>::insert(&attester, ());
+
+			// emit events
+			Self::deposit_event(Event::<T>::AttesterAdded(attester));
+
+			Ok(())
+		}
+
+		/// Remove attester.
+		///
+		/// Handles attested by the attester are removed by queued work in idle block time.
+		#[pallet::weight(T::WeightInfo::remove_attester())]
+		pub fn remove_attester(origin: OriginFor<T>, attester: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::remove_attester { attester: attester.clone() })?;
+
+			// allow only attestation origin
+			T::AttestationOrigin::ensure_origin(origin)?;
+
+			// ensure account is an attester
+			ensure!(Attesters::<T>::contains_key(&attester), Error::<T>::AttesterNotFound);
+
+			Attesters::<T>::remove(&attester);
+
+			// clear attestations of the attester
+			let total = Self::attestation_count(&attester);
+			let clear_work_id = if total > 0 {
+				Some(Self::unchecked_queue_work(
+					WorkItem::ClearAttestations { attester: attester.clone() },
+					total,
+				)?)
+			} else {
+				None
+			};
+
+			// emit events
+			Self::deposit_event(Event::<T>::AttesterRemoved(attester.clone()));
+			if let Some(work_id) = clear_work_id {
+				Self::deposit_event(Event::<T>::AttestationClearQueued(attester, work_id));
+			}
+
+			Ok(())
+		}
+
+		/// Feature launch token, appended to the end of the featured launch tokens.
+		#[pallet::weight(T::WeightInfo::feature_launch(T::MaxFeaturedLaunches::get()))]
+		pub fn feature_launch(origin: OriginFor<T>, launch_token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::feature_launch { launch_token_id })?;
+
+			// allow only curation origin
+			T::CurationOrigin::ensure_origin(origin)?;
+
+			// ensure launch token exists
+			ensure!(Self::launch_token(launch_token_id).is_some(), Error::<T>::TokenNotFound);
+
+			FeaturedLaunches::<T>::try_mutate(|featured| {
+				// ensure launch token is not featured
+				ensure!(!featured.contains(&launch_token_id), Error::<T>::LaunchAlreadyFeatured);
+
+				featured
+					.try_push(launch_token_id)
+					.map_err(|_| Error::<T>::MaxFeaturedLaunchesReached)
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchFeatured(launch_token_id));
+
+			Ok(())
+		}
+
+		/// Remove launch token from featured launch tokens.
+		///
+		/// Launch tokens that no longer exist can be removed.
+		#[pallet::weight(T::WeightInfo::unfeature_launch(T::MaxFeaturedLaunches::get()))]
+		pub fn unfeature_launch(origin: OriginFor<T>, launch_token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::unfeature_launch { launch_token_id })?;
+
+			// allow only curation origin
+			T::CurationOrigin::ensure_origin(origin)?;
+
+			FeaturedLaunches::<T>::try_mutate(|featured| {
+				// ensure launch token is featured
+				let index = featured
+					.iter()
+					.position(|id| *id == launch_token_id)
+					.ok_or(Error::<T>::LaunchNotFeatured)?;
+
+				featured.remove(index);
+
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchUnfeatured(launch_token_id));
+
+			Ok(())
+		}
+
+		/// Replace display order of featured launch tokens.
+		///
+		/// `order` must contain exactly the currently featured launch tokens.
+		#[pallet::weight(T::WeightInfo::reorder_featured_launches(order.len() as u32))]
+		pub fn reorder_featured_launches(
+			origin: OriginFor<T>,
+			order: BoundedVec<TokenId, T::MaxFeaturedLaunches>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::reorder_featured_launches {
+				order: order.clone(),
+			})?;
+
+			// allow only curation origin
+			T::CurationOrigin::ensure_origin(origin)?;
+
+			// ensure order only rearranges featured launch tokens
+			Self::ensure_valid_featured_order(&order)?;
+
+			FeaturedLaunches::<T>::put(order);
+
+			// emit events
+			Self::deposit_event(Event::<T>::FeaturedLaunchesReordered);
+
+			Ok(())
+		}
+
+		/// Save social handle of creator account verified off-chain by an attester.
+		///
+		/// The proof must be signed by an attester over the SCALE encoded
+		/// `(creator_id, owner, platform, handle, expires_at)` before it expires. Attestations are
+		/// cleared when the creator account changes owner.
+		#[pallet::weight(T::WeightInfo::attest_social(T::MaxSocialPlatforms::get()))]
+		pub fn attest_social(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			platform: SocialPlatform,
+			handle: SocialHandle,
+			proof: SocialProofOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::attest_social {
+				creator_id: creator_id.clone(),
+				platform: platform.clone(),
+				handle: handle.clone(),
+				proof: proof.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+
+			// ensure proof is signed by an attester for the current owner
+			let owner = Self::resolve_creator(&creator_id)
+				.and_then(|creator| creator.owner)
+				.ok_or(Error::<T>::CreatorNotFound)?;
+			Self::ensure_social_proof_valid(&creator_id, &owner, &platform, &handle, &proof)?;
+
+			Self::unchecked_attest_social(
+				&creator_id,
+				&platform,
+				handle.clone(),
+				proof.attester.clone(),
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::SocialAttested(
+				creator_id,
+				platform,
+				handle,
+				proof.attester,
+			));
+
+			Ok(())
+		}
+
+		/// Revoke attested social handle of creator account on platform.
+		///
+		/// Can be called by an account managing the creator account or by the attester of the
+		/// handle.
+		#[pallet::weight(T::WeightInfo::revoke_social())]
+		pub fn revoke_social(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			platform: SocialPlatform,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::revoke_social {
+				creator_id: creator_id.clone(),
+				platform: platform.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let attestation = Self::social_attestation(&creator_id, &platform)
+				.ok_or(Error::<T>::SocialAttestationNotFound)?;
+
+			// verify account attested handle or manages creator account
+			if attestation.attester != account {
+				Self::ensure_account_manages_creator(&account, &creator_id)?;
+			}
+
+			Self::revoke_social_attestation(&creator_id, &platform);
+
+			// emit events
+			Self::deposit_event(Event::<T>::SocialRevoked(
+				creator_id,
+				platform,
+				attestation.handle,
+			));
+
+			Ok(())
+		}
+
+		/// Initiate recovery of creator account to a new owner.
+		///
+		/// The recovery can be executed after the recovery delay and cancelled by the current owner
+		/// until then.
+		#[pallet::weight(T::WeightInfo::initiate_creator_recovery())]
+		pub fn initiate_creator_recovery(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::initiate_creator_recovery {
+				creator_id: creator_id.clone(),
+				new_owner: new_owner.clone(),
+			})?;
+
+			// allow only recovery origin
+			T::RecoveryOrigin::ensure_origin(origin)?;
+
+			// ensure creator account exists and is connected
+			let creator = Self::creator(&creator_id).ok_or(Error::<T>::CreatorNotFound)?;
+			ensure!(creator.owner.is_some(), Error::<T>::CreatorNotFound);
+
+			// ensure creator account is not a sub-creator
+			ensure!(!creator.is_sub_creator(), Error::<T>::IsSubCreator);
+
+			// ensure no recovery is pending
+			ensure!(
+				!PendingRecoveries::<T>::contains_key(&creator_id),
+				Error::<T>::RecoveryAlreadyPending
+			);
+
+			let executable_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RecoveryDelay::get());
+			PendingRecoveries::<T>::insert(&creator_id, (new_owner.clone(), executable_at));
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorRecoveryInitiated(
+				creator_id,
+				new_owner,
+				executable_at,
+			));
+
+			Ok(())
+		}
+
+		/// Cancel pending recovery of creator account.
+		///
+		/// Can be called by the current owner or the recovery origin.
+		#[pallet::weight(T::WeightInfo::cancel_creator_recovery())]
+		pub fn cancel_creator_recovery(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_creator_recovery {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow recovery origin or signed origin of the current owner
+			if let Err(origin) = T::RecoveryOrigin::try_origin(origin) {
+				let account = Self::ensure_signed_tracked(origin)?;
+
+				// verify account owns creator account
+				Self::ensure_account_holds_creator(&account, &creator_id)?;
+			}
+
+			// ensure recovery is pending
+			ensure!(
+				PendingRecoveries::<T>::contains_key(&creator_id),
+				Error::<T>::RecoveryNotFound
+			);
+
+			PendingRecoveries::<T>::remove(&creator_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorRecoveryCancelled(creator_id));
+
+			Ok(())
+		}
+
+		/// Execute pending recovery of creator account after the recovery delay.
+		///
+		/// Can be called by any signed account. Collaborators and any pending handover of the
+		/// creator account are removed.
+		#[pallet::weight(T::WeightInfo::execute_creator_recovery())]
+		pub fn execute_creator_recovery(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::execute_creator_recovery {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			Self::ensure_signed_tracked(origin)?;
+
+			let (new_owner, executable_at) =
+				Self::pending_recovery(&creator_id).ok_or(Error::<T>::RecoveryNotFound)?;
+
+			// ensure recovery delay has ended
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= executable_at,
+				Error::<T>::RecoveryDelayNotOver
+			);
+
+			PendingRecoveries::<T>::remove(&creator_id);
+
+			let previous_owner = Self::unchecked_set_creator_owner(&creator_id, new_owner.clone())?
+				.ok_or(Error::<T>::CreatorNotFound)?;
+
+			// collaborators and pending handover set up with the lost key do not survive recovery
+			Collaborators::<T>::remove(&creator_id);
+			PendingCreatorTransfers::<T>::remove(&creator_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorOwnerRecovered(
+				creator_id,
+				previous_owner,
+				new_owner,
+			));
+
+			Ok(())
+		}
+
+		/// Propose handover of creator account to a new owner.
+		///
+		/// Ownership only changes once the new owner accepts with `accept_creator_transfer` before
+		/// the transfer timeout. Proposing again replaces the pending handover.
+		#[pallet::weight(T::WeightInfo::transfer_creator())]
+		pub fn transfer_creator(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::transfer_creator {
+				creator_id: creator_id.clone(),
+				new_owner: new_owner.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			let creator = Self::ensure_account_holds_creator(&account, &creator_id)?;
+
+			// ensure creator registration has not expired
+			ensure!(!Self::is_creator_expired(&creator), Error::<T>::CreatorExpired);
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::CreatorTransferTimeout::get());
+			PendingCreatorTransfers::<T>::insert(
+				&creator_id,
+				CreatorTransfer { from: account.clone(), to: new_owner.clone(), expires_at },
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorTransferProposed(
+				creator_id, account, new_owner,
+			));
+
+			Ok(())
+		}
+
+		/// Accept proposed handover of creator account.
+		#[pallet::weight(T::WeightInfo::accept_creator_transfer())]
+		pub fn accept_creator_transfer(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::accept_creator_transfer {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let transfer = Self::pending_creator_transfer(&creator_id)
+				.filter(|transfer| transfer.to == account)
+				.ok_or(Error::<T>::CreatorTransferNotFound)?;
+
+			// ensure handover has not timed out
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < transfer.expires_at,
+				Error::<T>::CreatorTransferExpired
+			);
+
+			// verify proposing account still owns creator account
+			Self::ensure_account_holds_creator(&transfer.from, &creator_id)?;
+
+			PendingCreatorTransfers::<T>::remove(&creator_id);
+
+			Self::unchecked_set_creator_owner(&creator_id, account.clone())?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorOwnerChanged(
+				creator_id,
+				transfer.from,
+				account,
+			));
+
+			Ok(())
+		}
+
+		/// Cancel proposed handover of creator account.
+		///
+		/// Can be called by the proposing owner or the proposed new owner.
+		#[pallet::weight(T::WeightInfo::cancel_creator_transfer())]
+		pub fn cancel_creator_transfer(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_creator_transfer {
+				creator_id: creator_id.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure handover is pending and involves account
+			ensure!(
+				Self::pending_creator_transfer(&creator_id)
+					.map_or(false, |transfer| transfer.from == account || transfer.to == account),
+				Error::<T>::CreatorTransferNotFound
+			);
+
+			PendingCreatorTransfers::<T>::remove(&creator_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::CreatorTransferCancelled(creator_id));
+
+			Ok(())
+		}
+
+		/// Buy up to `max_count` of the cheapest listed tokens minted from launch token.
+		///
+		/// Up to `max_scan` listings of the launch token are scanned from the price floor and bought
+		/// cheapest first until the next listing would exceed `max_total`. Listings owned by the
+		/// buyer are skipped.
+		#[pallet::weight(T::WeightInfo::sweep(
+			(*max_count).min(T::MaxSweepCount::get()),
+			(*max_scan).min(T::MaxSweepScan::get()),
+		))]
+		pub fn sweep(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			max_count: u32,
+			max_total: BalanceOf<T>,
+			max_scan: u32,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::sweep {
+				launch_token_id,
+				max_count,
+				max_total,
+				max_scan,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let max_count = max_count.min(T::MaxSweepCount::get());
+			let max_scan = max_scan.min(T::MaxSweepScan::get());
+			let mut count = 0u32;
+			let mut total: BalanceOf<T> = Zero::zero();
+
+			for (token_id, token_price) in Self::get_floor_listings(&launch_token_id, max_scan) {
+				// stop when count or budget is exhausted
+				if count >= max_count || total.saturating_add(token_price) > max_total {
+					break
+				}
+
+				let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+				// skip listings owned by buyer
+				if token.owner == account {
+					continue
+				}
+
+				Self::unchecked_buy(&account, &token, token_price)?;
+
+				count += 1;
+				total = total.saturating_add(token_price);
+
+				Self::deposit_event(Event::<T>::TokenTransferred(
+					token.owner,
+					account.clone(),
+					token_id,
+				));
+			}
+
+			// ensure at least one listing was bought
+			ensure!(count > 0, Error::<T>::NothingToSweep);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokensSwept(account, launch_token_id, count, total));
+
+			Ok(())
+		}
+
+		/// List token on market with a price declining from `start_price` to `end_price` over
+		/// `duration` blocks.
+		///
+		/// The price stays at `end_price` once the duration has passed. Changing the price or
+		/// unlisting ends the dutch listing.
+		#[pallet::weight(T::WeightInfo::list_dutch())]
+		pub fn list_dutch(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			start_price: BalanceOf<T>,
+			end_price: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::list_dutch {
+				token_id,
+				start_price,
+				end_price,
+				duration,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure token does not have a price
+			ensure!(Self::get_token_price(&token_id).is_none(), Error::<T>::TokenAlreadyListed);
+
+			// ensure listing of curated launch token is approved
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::consume_listing_approval(&token)?;
+
+			// ensure price declines over a non-zero duration
+			ensure!(
+				start_price >= end_price && !duration.is_zero(),
+				Error::<T>::InvalidDutchListing
+			);
+
+			// ensure end price is not below minimum
+			Self::ensure_valid_price(&end_price)?;
+
+			// ensure price is not raised within cooldown
+			Self::ensure_price_change_allowed(&token_id, &start_price)?;
+
+			Self::unchecked_set_price(&token_id, Some(start_price))?;
+
+			let starts_at = frame_system::Pallet::<T>::block_number();
+			let ends_at = starts_at.saturating_add(duration);
+			DutchListings::<T>::insert(
+				&token_id,
+				DutchListing { start_price, end_price, starts_at, ends_at },
+			);
+
+			// index listing at its end price
+			Self::index_listing(&Token { price: Some(start_price), ..token });
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenListedDutch(
+				account,
+				token_id,
+				start_price,
+				end_price,
+				ends_at,
+			));
+
+			Ok(())
+		}
+
+		/// Buy up to `quantity` tokens from creator first hand.
+		///
+		/// Fails if the remaining supply is short of `quantity` unless `allow_partial` is set, in
+		/// which case the remaining supply is bought.
+		///
+		/// `max_total` is the maximum total the buyer is willing to pay, only the total is charged.
+		#[pallet::weight(T::WeightInfo::launch_buy_many(
+			(*quantity).min(T::MaxLaunchBuyQuantity::get()),
+		))]
+		pub fn launch_buy_many(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			quantity: u32,
+			max_total: BalanceOf<T>,
+			allow_partial: bool,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::launch_buy_many {
+				launch_token_id,
+				quantity,
+				max_total,
+				allow_partial,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure quantity is within bounds
+			ensure!(
+				quantity > 0 && quantity <= T::MaxLaunchBuyQuantity::get(),
+				Error::<T>::InvalidQuantity
+			);
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let launch_token =
+				Self::launch_token(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// get launch token owner
+			let (launch_token_owner, launch_token_creator) =
+				Self::get_launch_token_owner(&launch_token_id)
+					.ok_or(Error::<T>::TokenUnavailable)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&launch_token_creator)?;
+
+			// ensure remaining supply covers quantity unless partial purchases are allowed
+			let launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			let available = launch_token_state.available_for_sale();
+			ensure!(available > 0, Error::<T>::TokenSoldOut);
+			ensure!(allow_partial || available >= quantity, Error::<T>::InsufficientSupply);
+
+			// ensure drip schedule has unlocked supply covering quantity unless partial purchases
+			// are allowed
+			let available =
+				available.min(Self::get_drip_available(&launch_token_id, &launch_token_state));
+			ensure!(available > 0, Error::<T>::DripSupplyLocked);
+			ensure!(allow_partial || available >= quantity, Error::<T>::DripSupplyLocked);
+			let quantity = quantity.min(available);
+
+			// get launch price in native currency
+			let launch_price = Self::get_launch_price(&launch_token)?;
+			let total = launch_price.saturating_mul(quantity.into());
+
+			// ensure total does not exceed max total
+			ensure!(max_total >= total, Error::<T>::BidPriceTooLow);
+
+			// ensure sufficient balance
+			Self::ensure_can_pay(&account, total)?;
+
+			// ensure transfer is allowed
+			ensure!(
+				T::TransferFilter::is_transfer_allowed(
+					&launch_token_owner,
+					&account,
+					&launch_token_id
+				),
+				Error::<T>::TransferNotAllowed
+			);
+
+			for _ in 0..quantity {
+				let token_id = Self::unchecked_launch_buy(
+					&account,
+					&launch_token_owner,
+					&launch_token,
+					launch_price,
+				)?;
+
+				// emit events
+				Self::deposit_event(Event::<T>::TokenInitialCollection(
+					account.clone(),
+					launch_token_creator.clone(),
+					token_id,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Set account receiving primary sale proceeds of launch token.
+		///
+		/// Proceeds go to the creator owner when no beneficiary is set. Beneficiaries are cleared
+		/// when the creator account changes owner.
+		#[pallet::weight(T::WeightInfo::set_launch_beneficiary())]
+		pub fn set_launch_beneficiary(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_launch_beneficiary {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				beneficiary: beneficiary.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			LaunchBeneficiaries::<T>::set(&launch_token_id, beneficiary.clone());
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchBeneficiaryUpdated(
+				creator_id,
+				launch_token_id,
+				beneficiary,
+			));
+
+			Ok(())
+		}
+
+		/// Set account receiving secondary sale royalties of launch token, e.g. a split contract or
+		/// charity.
+		///
+		/// Royalties go to the launch token beneficiary or creator owner when no royalty
+		/// beneficiary is set. Royalty beneficiaries are cleared when the creator account changes
+		/// owner.
+		#[pallet::weight(T::WeightInfo::set_royalty_beneficiary())]
+		pub fn set_royalty_beneficiary(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_royalty_beneficiary {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				beneficiary: beneficiary.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			RoyaltyBeneficiaries::<T>::set(&launch_token_id, beneficiary.clone());
+
+			// emit events
+			Self::deposit_event(Event::<T>::RoyaltyBeneficiaryUpdated(
+				creator_id,
+				launch_token_id,
+				beneficiary,
+			));
+
+			Ok(())
+		}
+
+		/// Set schedule releasing purchasable supply of launch token by `amount` every `period`
+		/// blocks, starting with the first release at the current block.
+		///
+		/// Gifts are not limited by the schedule. Removes the schedule if `None`.
+		#[pallet::weight(T::WeightInfo::set_drip_schedule())]
+		pub fn set_drip_schedule(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			schedule: Option<(TokenSupply, T::BlockNumber)>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_drip_schedule {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				schedule,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			let schedule = match schedule {
+				Some((amount, period)) => {
+					// ensure schedule releases supply
+					ensure!(amount > 0 && !period.is_zero(), Error::<T>::InvalidDripSchedule);
+
+					let start = frame_system::Pallet::<T>::block_number();
+					Some(DripSchedule { amount, period, start })
+				},
+				None => None,
+			};
+			DripSchedules::<T>::set(&launch_token_id, schedule.clone());
+
+			// emit events
+			Self::deposit_event(Event::<T>::DripScheduleUpdated(
+				creator_id,
+				launch_token_id,
+				schedule,
+			));
+
+			Ok(())
+		}
+
+		/// Set max purchases of launch token per account in a single block, e.g. to slow down bots.
+		///
+		/// Removes the cap if `None`.
+		#[pallet::weight(T::WeightInfo::set_block_purchase_cap())]
+		pub fn set_block_purchase_cap(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			cap: Option<u32>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_block_purchase_cap {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				cap,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure cap allows purchases
+			ensure!(cap != Some(0), Error::<T>::InvalidPurchaseCap);
+
+			BlockPurchaseCaps::<T>::set(&launch_token_id, cap);
+
+			// emit events
+			Self::deposit_event(Event::<T>::BlockPurchaseCapUpdated(
+				creator_id,
+				launch_token_id,
+				cap,
+			));
+
+			Ok(())
+		}
+
+		/// Register upcoming drop of creator account on the launch calendar.
+		///
+		/// A deposit is reserved from the signing account until the drop is cancelled.
+		#[pallet::weight(T::WeightInfo::schedule_drop())]
+		pub fn schedule_drop(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			title: CalendarTitle,
+			starts_at: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::schedule_drop {
+				creator_id: creator_id.clone(),
+				title: title.clone(),
+				starts_at,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+
+			// ensure drop starts in the future
+			ensure!(
+				starts_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidDropStart
+			);
+
+			Self::unchecked_schedule_drop(&account, creator_id.clone(), title, starts_at)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::DropScheduled(creator_id, starts_at));
+
+			Ok(())
+		}
+
+		/// Set launch token of drop of creator account starting at `starts_at` once minted.
+		#[pallet::weight(T::WeightInfo::link_drop_launch())]
+		pub fn link_drop_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			starts_at: T::BlockNumber,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::link_drop_launch {
+				creator_id: creator_id.clone(),
+				starts_at,
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			Self::unchecked_link_drop(&creator_id, starts_at, launch_token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::DropLaunchLinked(
+				creator_id,
+				starts_at,
+				launch_token_id,
+			));
+
+			Ok(())
+		}
+
+		/// Remove drop of creator account starting at `starts_at` from the launch calendar and
+		/// return its deposit.
+		#[pallet::weight(T::WeightInfo::cancel_drop())]
+		pub fn cancel_drop(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			starts_at: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_drop {
+				creator_id: creator_id.clone(),
+				starts_at,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+
+			Self::unchecked_cancel_drop(&creator_id, starts_at)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::DropCancelled(creator_id, starts_at));
+
+			Ok(())
+		}
+
+		/// Open raffle issuing up to `winners` tokens of launch token to entrants drawn at random.
+		///
+		/// Entrants reserve `ticket_fee` per ticket, chances of winning are weighted by tickets.
+		/// Winners are limited by the remaining supply at draw time.
+		#[pallet::weight(T::WeightInfo::open_raffle())]
+		pub fn open_raffle(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			ticket_fee: BalanceOf<T>,
+			winners: u32,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::open_raffle {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				ticket_fee,
+				winners,
+				duration,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			// ensure at least one winner is drawn
+			ensure!(winners > 0, Error::<T>::InvalidQuantity);
+
+			// ensure raffle closes in a later block
+			ensure!(!duration.is_zero(), Error::<T>::InvalidQuantity);
+
+			// ensure launch token has no open raffle
+			ensure!(!Raffles::<T>::contains_key(&launch_token_id), Error::<T>::RaffleAlreadyOpen);
+
+			let ends_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			RafflesClosingAt::<T>::try_append(ends_at, launch_token_id)
+				.map_err(|_| Error::<T>::MaxRafflesClosingReached)?;
+			Raffles::<T>::insert(
+				&launch_token_id,
+				Raffle { creator: creator_id.clone(), ticket_fee, winners, ends_at, seed: None },
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::RaffleOpened(
+				creator_id,
+				launch_token_id,
+				ticket_fee,
+				winners,
+				ends_at,
+			));
+
+			Ok(())
+		}
+
+		/// Enter raffle of launch token with `tickets` tickets.
+		///
+		/// Ticket fees are reserved until the raffle is drawn.
+		#[pallet::weight(T::WeightInfo::enter_raffle())]
+		pub fn enter_raffle(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			tickets: u32,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::enter_raffle { launch_token_id, tickets })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure at least one ticket is bought
+			ensure!(tickets > 0, Error::<T>::InvalidQuantity);
+
+			let raffle = Self::raffle(launch_token_id).ok_or(Error::<T>::RaffleNotFound)?;
+
+			// ensure raffle entries are open
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < raffle.ends_at,
+				Error::<T>::RaffleClosed
+			);
+
+			Self::unchecked_enter_raffle(&account, &launch_token_id, tickets, raffle.ticket_fee)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::RaffleEntered(account, launch_token_id, tickets));
+
+			Ok(())
+		}
+
+		/// Draw closed raffle of launch token.
+		///
+		/// Winners are drawn from the seed set at the start of the closing block, so the outcome
+		/// does not depend on when or by whom the raffle is drawn. Winners are issued a token and
+		/// pay their ticket fees like a launch purchase, winners that cannot be issued a token and
+		/// remaining entrants are refunded. The raffle is cancelled and all entrants refunded if
+		/// the launch token is no longer available. Can be called by any signed account.
+		#[pallet::weight(T::WeightInfo::draw_raffle(T::MaxRaffleEntries::get()))]
+		pub fn draw_raffle(origin: OriginFor<T>, launch_token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::draw_raffle { launch_token_id })?;
+
+			// allow only signed origin
+			Self::ensure_signed_tracked(origin)?;
+
+			let raffle = Self::raffle(launch_token_id).ok_or(Error::<T>::RaffleNotFound)?;
+
+			// ensure raffle entries are closed and the draw is seeded
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= raffle.ends_at,
+				Error::<T>::RaffleNotClosed
+			);
+			let seed = raffle.seed.ok_or(Error::<T>::RaffleNotClosed)?;
+
+			Raffles::<T>::remove(&launch_token_id);
+			let entries = RaffleEntries::<T>::take(&launch_token_id).into_inner();
+
+			// cancel if launch token is unavailable or its creator is banned
+			let launch_token = Self::launch_token(launch_token_id);
+			let launch_token_state = Self::launch_token_state(launch_token_id);
+			let owner = Self::get_launch_token_owner(&launch_token_id)
+				.filter(|(_, creator_id)| !Self::is_creator_banned(creator_id));
+			let (launch_token, launch_token_state, (owner, creator_id)) =
+				match (launch_token, launch_token_state, owner) {
+					(Some(launch_token), Some(launch_token_state), Some(owner)) =>
+						(launch_token, launch_token_state, owner),
+					_ => {
+						Self::unchecked_refund_raffle_entries(&entries, raffle.ticket_fee);
+
+						// emit events
+						Self::deposit_event(Event::<T>::RaffleCancelled(launch_token_id));
+
+						return Ok(())
+					},
+				};
+
+			// limit winners to remaining supply
+			let available = launch_token_state.available_for_sale();
+			let (winners, losers) =
+				Self::draw_raffle_winners(&seed, entries, raffle.winners.min(available));
+
+			let mut drawn = 0u32;
+			for (winner, tickets) in winners {
+				let result = Self::settle_raffle_winner(
+					&winner,
+					&owner,
+					&launch_token,
+					tickets,
+					raffle.ticket_fee,
+				);
+
+				match result {
+					Ok(token_id) => {
+						drawn = drawn.saturating_add(1);
+						Self::deposit_event(Event::<T>::TokenInitialCollection(
+							winner,
+							creator_id.clone(),
+							token_id,
+						));
+					},
+					Err(err) => {
+						Self::unchecked_refund_raffle_entries(
+							&[(winner.clone(), tickets)],
+							raffle.ticket_fee,
+						);
+						Self::deposit_event(Event::<T>::RaffleWinnerSkipped(
+							winner,
+							launch_token_id,
+							err,
+						));
+					},
+				}
+			}
+
+			Self::unchecked_refund_raffle_entries(&losers, raffle.ticket_fee);
+
+			// emit events
+			Self::deposit_event(Event::<T>::RaffleDrawn(launch_token_id, drawn));
+
+			Ok(())
+		}
+
+		/// Queue purchase of token from creator first hand.
+		///
+		/// Intents are settled at the start of the next block in submission order, so purchases
+		/// within a block cannot be reordered. `max_price` is reserved until settlement and only the
+		/// launch price is charged.
+		#[pallet::weight(T::WeightInfo::submit_purchase_intent())]
+		pub fn submit_purchase_intent(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::submit_purchase_intent {
+				launch_token_id,
+				max_price,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure launch token exists
+			ensure!(LaunchTokens::<T>::contains_key(&launch_token_id), Error::<T>::TokenNotFound);
+
+			Self::unchecked_queue_purchase_intent(&account, &launch_token_id, max_price)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::PurchaseIntentQueued(
+				account,
+				launch_token_id,
+				max_price,
+			));
+
+			Ok(())
+		}
+
+		/// Auction token to the highest bidder.
+		///
+		/// Token is unlisted and locked until the auction is settled or cancelled.
+		///
+		/// Bids must raise the highest bid by `min_increment`, or by the default bid increment if
+		/// `None`. An optional `buy_now` price lets buyers settle the auction immediately. Tokens are
+		/// only sold if the highest bid meets the optional `reserve` price.
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			min_bid: BalanceOf<T>,
+			duration: T::BlockNumber,
+			min_increment: Option<BidIncrementOf<T>>,
+			reserve: Option<BalanceOf<T>>,
+			buy_now: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::create_auction {
+				token_id,
+				min_bid,
+				duration,
+				min_increment,
+				reserve,
+				buy_now,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account owns token
+			Self::ensure_account_owns_token(&account, &token_id)?;
+
+			// ensure token is not locked
+			Self::ensure_token_unlocked(&token_id)?;
+
+			// ensure token transfer lockup is over
+			Self::ensure_lockup_over(&token_id)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure auction runs over a non-zero duration
+			ensure!(!duration.is_zero(), Error::<T>::InvalidAuction);
+
+			// ensure min bid is not below minimum
+			Self::ensure_valid_price(&min_bid)?;
+
+			// ensure reserve and buy-now prices are not below min bid or reserve
+			if let Some(reserve) = &reserve {
+				ensure!(*reserve >= min_bid, Error::<T>::InvalidAuction);
+			}
+			if let Some(buy_now) = &buy_now {
+				ensure!(*buy_now >= reserve.unwrap_or(min_bid), Error::<T>::InvalidAuction);
+			}
+
+			// unlist token
+			Self::unchecked_set_price(&token_id, None)?;
+
+			let ends_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Auctions::<T>::insert(
+				&token_id,
+				Auction {
+					seller: account.clone(),
+					min_bid,
+					min_increment: min_increment.unwrap_or_else(T::DefaultBidIncrement::get),
+					reserve,
+					buy_now,
+					highest_bid: None,
+					ends_at,
+				},
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::AuctionCreated(account, token_id, min_bid, ends_at));
+
+			Ok(())
+		}
+
+		/// Bid on token auction.
+		///
+		/// Bid is reserved and returned automatically once outbid.
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::bid { token_id, amount })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let auction = Self::auction(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			// ensure account is not the seller
+			ensure!(auction.seller != account, Error::<T>::TransferToSelf);
+
+			// ensure bidding is open
+			ensure!(!Self::is_auction_over(&auction), Error::<T>::AuctionEnded);
+
+			// ensure bid meets min bid or raises highest bid by min increment
+			ensure!(amount >= Self::get_min_next_bid(&auction), Error::<T>::BidTooLow);
+
+			let outbid = Self::unchecked_place_bid(&account, &token_id, amount)?;
+
+			// emit events
+			if let Some((outbid_bidder, outbid_amount)) = outbid {
+				Self::deposit_event(Event::<T>::BidOutbid(outbid_bidder, token_id, outbid_amount));
+			}
+			Self::deposit_event(Event::<T>::BidPlaced(account, token_id, amount));
+
+			Ok(())
+		}
+
+		/// Buy auctioned token at its buy-now price, settling the auction immediately.
+		///
+		/// The reserve of the highest bidder is returned. `bid_price` is the maximum the buyer is
+		/// willing to pay.
+		#[pallet::weight(T::WeightInfo::buy_now(T::MaxTokens::get()))]
+		pub fn buy_now(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::buy_now { token_id, bid_price })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let auction = Self::auction(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			// ensure account is not the seller
+			ensure!(auction.seller != account, Error::<T>::TransferToSelf);
+
+			// ensure bidding is open
+			ensure!(!Self::is_auction_over(&auction), Error::<T>::AuctionEnded);
+
+			// get buy-now price, return error if none is set
+			let price = auction.buy_now.ok_or(Error::<T>::BuyNowUnavailable)?;
+
+			// ensure price does not exceed bid price
+			ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
+
+			// ensure sufficient balance
+			Self::ensure_can_pay(&account, price)?;
+
+			let auction = Self::unchecked_buy_now(&account, &token_id, price)?;
+
+			// emit events
+			if let Some((bidder, amount)) = auction.highest_bid {
+				Self::deposit_event(Event::<T>::BidOutbid(bidder, token_id, amount));
+			}
+			Self::deposit_event(Event::<T>::TokenTransferred(
+				auction.seller.clone(),
+				account.clone(),
+				token_id,
+			));
+			Self::deposit_event(Event::<T>::AuctionSettled(
+				auction.seller,
+				token_id,
+				Some(account),
+				Some(price),
+			));
+
+			Ok(())
+		}
+
+		/// Settle token auction once bidding has closed.
+		///
+		/// The highest bid is returned and the token remains with the seller if the token cannot be
+		/// transferred to the highest bidder. Can be called by any account.
+		#[pallet::weight(T::WeightInfo::settle_auction(T::MaxTokens::get()))]
+		pub fn settle_auction(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::settle_auction { token_id })?;
+
+			// allow only signed origin
+			Self::ensure_signed_tracked(origin)?;
+
+			let auction = Self::auction(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			// ensure bidding has closed
+			ensure!(Self::is_auction_over(&auction), Error::<T>::AuctionNotEnded);
+
+			let (auction, sale_error) = Self::unchecked_settle_auction(&token_id)?;
+
+			// emit events
+			let reserve_met = Self::is_reserve_met(&auction);
+			let (winner, amount) = match (auction.highest_bid, sale_error) {
+				(Some((bidder, amount)), Some(err)) => {
+					Self::deposit_event(Event::<T>::AuctionSaleFailed(
+						bidder, token_id, amount, err,
+					));
+					(None, None)
+				},
+				(Some((bidder, amount)), None) if reserve_met => {
+					Self::deposit_event(Event::<T>::TokenTransferred(
+						auction.seller.clone(),
+						bidder.clone(),
+						token_id,
+					));
+					(Some(bidder), Some(amount))
+				},
+				(Some((bidder, amount)), None) => {
+					Self::deposit_event(Event::<T>::AuctionReserveNotMet(bidder, token_id, amount));
+					(None, None)
+				},
+				(None, _) => (None, None),
+			};
+			Self::deposit_event(Event::<T>::AuctionSettled(
+				auction.seller,
+				token_id,
+				winner,
+				amount,
+			));
+
+			Ok(())
+		}
+
+		/// Close ended auction that did not sell and list token at a fixed price.
+		///
+		/// Any highest bid below the reserve price is returned. Token is listed at
+		/// `as_listing_price`, or at the reserve price or min bid of the auction if `None`.
+		#[pallet::weight(T::WeightInfo::relist_from_auction())]
+		pub fn relist_from_auction(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			as_listing_price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::relist_from_auction {
+				token_id,
+				as_listing_price,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let auction = Self::auction(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			// ensure account is the seller
+			ensure!(auction.seller == account, Error::<T>::NotOwner);
+
+			// ensure bidding has closed
+			ensure!(Self::is_auction_over(&auction), Error::<T>::AuctionNotEnded);
+
+			// ensure auction did not sell
+			ensure!(!Self::is_reserve_met(&auction), Error::<T>::AuctionReserveMet);
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			let (auction, _) = Self::unchecked_settle_auction(&token_id)?;
+
+			// ensure listing of curated launch token is approved
+			let token = Self::token(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::consume_listing_approval(&token)?;
+
+			let price = as_listing_price.or(auction.reserve).unwrap_or(auction.min_bid);
+
+			// ensure price is not below minimum
+			Self::ensure_valid_price(&price)?;
+
+			// ensure price is not raised within cooldown
+			Self::ensure_price_change_allowed(&token_id, &price)?;
+
+			Self::unchecked_set_price(&token_id, Some(price))?;
+
+			// emit events
+			if let Some((bidder, amount)) = auction.highest_bid {
+				Self::deposit_event(Event::<T>::AuctionReserveNotMet(bidder, token_id, amount));
+			}
+			Self::deposit_event(Event::<T>::AuctionSettled(account.clone(), token_id, None, None));
+			Self::deposit_event(Event::<T>::TokenListed(account, token_id, Some(price)));
+
+			Ok(())
+		}
+
+		/// Cancel token auction without bids.
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_auction { token_id })?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let auction = Self::auction(token_id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			// ensure account is the seller
+			ensure!(auction.seller == account, Error::<T>::NotOwner);
+
+			// ensure auction has no bids
+			ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+			Auctions::<T>::remove(&token_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::AuctionCancelled(account, token_id));
+
+			Ok(())
+		}
+
+		/// Set milestones releasing launch proceeds held in escrow.
+		///
+		/// Each milestone is the share of proceeds it releases, shares must add up to 100%.
+		/// Milestones can only be set or cleared before any token is issued.
+		#[pallet::weight(T::WeightInfo::set_milestones())]
+		pub fn set_milestones(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			milestones: BoundedVec<Perbill, T::MaxMilestones>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_milestones {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				milestones: milestones.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			let launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+
+			// ensure no token has been issued
+			ensure!(launch_token_state.issued.is_zero(), Error::<T>::MilestonesLocked);
+
+			let count = milestones.len() as u32;
+			if milestones.is_empty() {
+				MilestoneEscrows::<T>::remove(&launch_token_id);
+			} else {
+				// ensure shares add up to 100%
+				let total = milestones
+					.iter()
+					.fold(Perbill::zero(), |total, share| total.saturating_add(*share));
+				ensure!(
+					total == Perbill::from_percent(100) &&
+						!milestones.iter().any(|share| share.is_zero()),
+					Error::<T>::InvalidMilestones
+				);
+
+				MilestoneEscrows::<T>::insert(&launch_token_id, MilestoneEscrow::new(milestones));
+			}
+
+			// emit events
+			Self::deposit_event(Event::<T>::MilestonesSet(creator_id, launch_token_id, count));
+
+			Ok(())
+		}
+
+		/// Approve next milestone of launch token and release its share of escrowed proceeds.
+		#[pallet::weight(T::WeightInfo::approve_milestone())]
+		pub fn approve_milestone(origin: OriginFor<T>, launch_token_id: TokenId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::approve_milestone { launch_token_id })?;
+
+			// allow only milestone origin
+			T::MilestoneOrigin::ensure_origin(origin)?;
+
+			let milestone = MilestoneEscrows::<T>::try_mutate(&launch_token_id, |escrow| {
+				let escrow = escrow.as_mut().ok_or(Error::<T>::MilestonesNotFound)?;
+
+				// ensure a milestone is left to approve
+				ensure!(!escrow.is_complete(), Error::<T>::MilestonesComplete);
+
+				escrow.approved = escrow.approved.saturating_add(1);
+
+				Ok::<_, Error<T>>(escrow.approved)
+			})?;
+
+			let (beneficiary, amount) = Self::unchecked_release_proceeds(&launch_token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::MilestoneApproved(launch_token_id, milestone));
+			Self::deposit_event(Event::<T>::MilestoneProceedsReleased(
+				launch_token_id,
+				beneficiary,
+				amount,
+			));
+
+			Ok(())
+		}
+
+		/// Release escrowed proceeds of launch token unlocked by approved milestones.
+		///
+		/// Releases proceeds of sales made after the last milestone approval.
+		#[pallet::weight(T::WeightInfo::claim_milestone_proceeds())]
+		pub fn claim_milestone_proceeds(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::claim_milestone_proceeds {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			let (beneficiary, amount) = Self::unchecked_release_proceeds(&launch_token_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::MilestoneProceedsReleased(
+				launch_token_id,
+				beneficiary,
+				amount,
+			));
+
+			Ok(())
+		}
+
+		/// Fund pool matching primary purchases of creator's launch tokens until `end_block`.
+		///
+		/// Each purchase is matched at `ratio` of its price while the pool has funds left.
+		/// Pool funds are reserved from the sponsor and the remainder is returned once closed.
+		#[pallet::weight(T::WeightInfo::fund_match())]
+		pub fn fund_match(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			amount: BalanceOf<T>,
+			ratio: Permill,
+			end_block: T::BlockNumber,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::fund_match {
+				creator_id: creator_id.clone(),
+				amount,
+				ratio,
+				end_block,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// check if creator exists
+			ensure!(Creators::<T>::contains_key(&creator_id), Error::<T>::CreatorNotFound);
+
+			// ensure pool matches a non-zero amount within an open window
+			ensure!(
+				!amount.is_zero() &&
+					!ratio.is_zero() &&
+					end_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidMatchingPool
+			);
+
+			// close previous matching pool once its window has ended
+			if let Some(pool) = Self::matching_pool(&creator_id) {
+				ensure!(!Self::is_matching_active(&pool), Error::<T>::MatchingPoolActive);
+
+				let pool = Self::unchecked_close_matching_pool(&creator_id)?;
+				Self::deposit_event(Event::<T>::MatchingPoolClosed(
+					pool.sponsor,
+					creator_id.clone(),
+					pool.remaining,
+				));
+			}
+
+			// reserve pool funds from sponsor
+			T::Currency::reserve(&account, amount).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			MatchingPools::<T>::insert(
+				&creator_id,
+				MatchingPool {
+					sponsor: account.clone(),
+					remaining: amount,
+					ratio,
+					ends_at: end_block,
+				},
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::MatchingPoolFunded(
+				account, creator_id, amount, ratio, end_block,
+			));
+
+			Ok(())
+		}
+
+		/// Close matching pool once its window has ended and return the remainder to the sponsor.
+		///
+		/// Can be called by any account.
+		#[pallet::weight(T::WeightInfo::close_match())]
+		pub fn close_match(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::close_match { creator_id: creator_id.clone() })?;
+
+			// allow only signed origin
+			Self::ensure_signed_tracked(origin)?;
+
+			let pool = Self::matching_pool(&creator_id).ok_or(Error::<T>::MatchingPoolNotFound)?;
+
+			// ensure matching window has ended
+			ensure!(!Self::is_matching_active(&pool), Error::<T>::MatchingPoolActive);
+
+			let pool = Self::unchecked_close_matching_pool(&creator_id)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::MatchingPoolClosed(
+				pool.sponsor,
+				creator_id,
+				pool.remaining,
+			));
+
+			Ok(())
+		}
+
+		/// Add tag to the launch token taxonomy.
+		#[pallet::weight(T::WeightInfo::add_tag())]
+		pub fn add_tag(origin: OriginFor<T>, name: TagName) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::add_tag { name: name.clone() })?;
+
+			// allow only taxonomy origin
+			T::TaxonomyOrigin::ensure_origin(origin)?;
+
+			let tag_id = Self::tag_nonce();
+			let next_tag_id = tag_id.checked_add(1).ok_or(Error::<T>::TagsOverflow)?;
+
+			Tags::<T>::insert(tag_id, name.clone());
+			TagNonce::<T>::set(next_tag_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TagAdded(tag_id, name));
+
+			Ok(())
+		}
+
+		/// Remove tag from the launch token taxonomy.
+		///
+		/// Launch tokens keep removed tags until their tags are updated.
+		#[pallet::weight(T::WeightInfo::remove_tag())]
+		pub fn remove_tag(origin: OriginFor<T>, tag_id: TagId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::remove_tag { tag_id })?;
+
+			// allow only taxonomy origin
+			T::TaxonomyOrigin::ensure_origin(origin)?;
+
+			// ensure tag exists
+			ensure!(Tags::<T>::contains_key(tag_id), Error::<T>::TagNotFound);
+
+			Tags::<T>::remove(tag_id);
+
+			// emit events
+			Self::deposit_event(Event::<T>::TagRemoved(tag_id));
+
+			Ok(())
+		}
+
+		/// Set tags of launch token.
+		///
+		/// Tags can only be changed before any token is issued.
+		#[pallet::weight(T::WeightInfo::set_tags(tags.len() as u32))]
+		pub fn set_tags(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			tags: TokenTags,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_tags {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				tags: tags.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure tags exist in taxonomy
+			Self::ensure_valid_tags(&tags)?;
+
+			// ensure no token has been issued
+			let launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			ensure!(launch_token_state.issued.is_zero(), Error::<T>::TagsLocked);
+
+			LaunchTokens::<T>::try_mutate(&launch_token_id, |launch_token| {
+				let launch_token = launch_token.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+				launch_token.tags = tags.clone();
+
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::TokenTagsUpdated(creator_id, launch_token_id, tags));
+
+			Ok(())
+		}
+
+		/// Report launch token to moderators.
+		///
+		/// A deposit is reserved until the report is resolved, it is returned if the report is
+		/// upheld and slashed if dismissed.
+		#[pallet::weight(T::WeightInfo::report_launch())]
+		pub fn report_launch(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			reason: ReportReason,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::report_launch {
+				launch_token_id,
+				reason: reason.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure launch token exists
+			ensure!(LaunchTokens::<T>::contains_key(&launch_token_id), Error::<T>::TokenNotFound);
+
+			let report_id = Self::unchecked_report_launch(
+				&account,
+				&launch_token_id,
+				reason,
+				T::ReportDeposit::get(),
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchReported(account, launch_token_id, report_id));
+
+			Ok(())
+		}
+
+		/// Resolve report against launch token.
+		///
+		/// Upheld reports add a strike to the launch token.
+		#[pallet::weight(T::WeightInfo::resolve_report())]
+		pub fn resolve_report(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			report_id: ReportId,
+			upheld: bool,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::resolve_report {
+				launch_token_id,
+				report_id,
+				upheld,
+			})?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			let (report, strikes) =
+				Self::unchecked_resolve_report(&launch_token_id, report_id, upheld)?;
+
+			// emit events
+			if upheld {
+				Self::deposit_event(Event::<T>::ReportUpheld(
+					report.reporter,
+					launch_token_id,
+					report_id,
+					strikes,
+				));
+			} else {
+				Self::deposit_event(Event::<T>::ReportDismissed(
+					report.reporter,
+					launch_token_id,
+					report_id,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Claim launch token infringes work of account.
+		///
+		/// A deposit is reserved until the claim is resolved, it is returned if the claim is upheld
+		/// and slashed if dismissed. Launch tokens can have one open claim at a time.
+		#[pallet::weight(T::WeightInfo::claim_infringement())]
+		pub fn claim_infringement(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			evidence_hash: T::Hash,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::claim_infringement {
+				launch_token_id,
+				evidence_hash,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// ensure account is not blocked
+			Self::ensure_account_not_blocked(&account)?;
+
+			// ensure launch token exists
+			ensure!(LaunchTokens::<T>::contains_key(&launch_token_id), Error::<T>::TokenNotFound);
+
+			Self::unchecked_claim_infringement(
+				&account,
+				&launch_token_id,
+				evidence_hash,
+				T::InfringementDeposit::get(),
+			)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::InfringementClaimed(
+				account,
+				launch_token_id,
+				evidence_hash,
+			));
+
+			Ok(())
+		}
+
+		/// Resolve infringement claim against launch token.
+		///
+		/// Upheld claims freeze the launch token, ending issuance until moderators act further.
+		#[pallet::weight(T::WeightInfo::resolve_infringement())]
+		pub fn resolve_infringement(
+			origin: OriginFor<T>,
+			launch_token_id: TokenId,
+			upheld: bool,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::resolve_infringement {
+				launch_token_id,
+				upheld,
+			})?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			let claim = Self::unchecked_resolve_infringement(&launch_token_id, upheld)?;
+
+			// emit events
+			if upheld {
+				Self::deposit_event(Event::<T>::InfringementUpheld(
+					claim.claimant,
+					launch_token_id,
+				));
+				if let Some(launch_token) = Self::launch_token(launch_token_id) {
+					Self::deposit_event(Event::<T>::LaunchStateChanged(
+						launch_token.creator,
+						launch_token_id,
+						LaunchState::Frozen,
+					));
+				}
+			} else {
+				Self::deposit_event(Event::<T>::InfringementDismissed(
+					claim.claimant,
+					launch_token_id,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Replace price and metadata of draft launch token.
+		///
+		/// The draft flag of `metadata` is ignored, use `publish_launch` to publish the launch.
+		#[pallet::weight(T::WeightInfo::update_draft(
+			metadata.metadata_uri.len() as u32,
+			metadata.inline_data.as_ref().map_or(0, |data| data.len() as u32),
+			metadata.tags.len() as u32,
+		))]
+		pub fn update_draft(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			price: BalanceOf<T>,
+			mut metadata: LaunchTokenMetadata,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::update_draft {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				price,
+				metadata: metadata.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure price and metadata are valid
+			Self::ensure_valid_launch_metadata(&price, &metadata)?;
+
+			// ensure launch token is a draft
+			let mut launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			ensure!(launch_token_state.is_draft(), Error::<T>::LaunchNotDraft);
+			launch_token_state.update_draft(&metadata);
+			LaunchTokenStates::<T>::insert(&launch_token_id, launch_token_state);
+
+			let (version, old_hash, new_hash) =
+				LaunchTokens::<T>::try_mutate(&launch_token_id, |launch_token| {
+					let launch_token = launch_token.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+					let old_hash = launch_token.metadata_hash();
+					Self::unchecked_set_inline_data(
+						&account,
+						&launch_token_id,
+						metadata.inline_data.take(),
+					)?;
+					Self::unindex_launch_name(launch_token);
+					Self::unindex_launch_content(launch_token);
+					launch_token.update_draft(price, metadata);
+					Self::index_launch_name(launch_token);
+					Self::check_duplicate_content(launch_token)?;
+
+					Ok::<_, Error<T>>((
+						launch_token.metadata_version,
+						old_hash,
+						launch_token.metadata_hash(),
+					))
+				})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchDraftUpdated(
+				creator_id,
+				launch_token_id,
+				version,
+				old_hash,
+				new_hash,
+			));
+
+			Ok(())
+		}
+
+		/// Publish draft launch token so its tokens can be issued.
+		#[pallet::weight(T::WeightInfo::publish_launch())]
+		pub fn publish_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::publish_launch {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			LaunchTokenStates::<T>::try_mutate(&launch_token_id, |launch_token_state| {
+				let launch_token_state =
+					launch_token_state.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure launch token is a draft
+				ensure!(launch_token_state.is_draft(), Error::<T>::LaunchNotDraft);
+
+				let state = launch_token_state.live_state();
+				Self::unchecked_set_launch_state(&launch_token_id, launch_token_state, state);
+
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchPublished(creator_id, launch_token_id));
+
+			Ok(())
+		}
+
+		/// Set launch defaults of creator inherited by new launch tokens.
+		///
+		/// Royalties set in launch token metadata override the default royalty, the default
+		/// beneficiary can be changed per launch token with `set_launch_beneficiary`.
+		#[pallet::weight(T::WeightInfo::set_launch_defaults())]
+		pub fn set_launch_defaults(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			royalty: Permill,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_launch_defaults {
+				creator_id: creator_id.clone(),
+				royalty,
+				beneficiary: beneficiary.clone(),
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			// ensure royalty does not exceed max royalty
+			Self::ensure_valid_royalty(&royalty)?;
+
+			LaunchDefaultsForCreator::<T>::insert(
+				&creator_id,
+				LaunchDefaults { royalty, beneficiary: beneficiary.clone() },
+			);
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchDefaultsUpdated(
+				creator_id,
+				royalty,
+				beneficiary,
+			));
+
+			Ok(())
+		}
+
+		/// Pause issuance of live or sold out launch token.
+		#[pallet::weight(T::WeightInfo::pause_launch())]
+		pub fn pause_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::pause_launch {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
+
+			LaunchTokenStates::<T>::try_mutate(&launch_token_id, |launch_token_state| {
+				let launch_token_state =
+					launch_token_state.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure launch token is live or sold out
+				match launch_token_state.state {
+					LaunchState::Live | LaunchState::SoldOut => (),
+					LaunchState::Upcoming => return Err(Error::<T>::LaunchIsDraft),
+					LaunchState::Paused => return Err(Error::<T>::LaunchPaused),
+					LaunchState::Cancelled => return Err(Error::<T>::LaunchCancelled),
+					LaunchState::Frozen => return Err(Error::<T>::LaunchFrozen),
+				}
+
+				Self::unchecked_set_launch_state(
+					&launch_token_id,
+					launch_token_state,
+					LaunchState::Paused,
+				);
+
+				Ok(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchStateChanged(
+				creator_id,
+				launch_token_id,
+				LaunchState::Paused,
+			));
+
+			Ok(())
+		}
+
+		/// Resume issuance of paused launch token.
+		#[pallet::weight(T::WeightInfo::resume_launch())]
+		pub fn resume_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::resume_launch {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			let state =
+				LaunchTokenStates::<T>::try_mutate(&launch_token_id, |launch_token_state| {
+					let launch_token_state =
+						launch_token_state.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+					// ensure launch token is paused
+					ensure!(
+						launch_token_state.state == LaunchState::Paused,
+						Error::<T>::LaunchNotPaused
+					);
+
+					let state = launch_token_state.live_state();
+					Self::unchecked_set_launch_state(&launch_token_id, launch_token_state, state);
+
+					Ok::<_, Error<T>>(state)
+				})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchStateChanged(creator_id, launch_token_id, state));
+
+			Ok(())
+		}
+
+		/// Cancel launch token, ending issuance permanently.
+		///
+		/// Tokens already issued are not affected.
+		#[pallet::weight(T::WeightInfo::cancel_launch())]
+		pub fn cancel_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::cancel_launch {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			LaunchTokenStates::<T>::try_mutate(&launch_token_id, |launch_token_state| {
+				let launch_token_state =
+					launch_token_state.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+
+				// ensure launch token is not cancelled
+				ensure!(
+					launch_token_state.state != LaunchState::Cancelled,
+					Error::<T>::LaunchCancelled
+				);
+
+				Self::unchecked_set_launch_state(
+					&launch_token_id,
+					launch_token_state,
+					LaunchState::Cancelled,
+				);
+
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchStateChanged(
+				creator_id,
+				launch_token_id,
+				LaunchState::Cancelled,
+			));
+
+			Ok(())
+		}
+
+		/// Delegate management of launch token to another creator account.
+		///
+		/// Managers of the delegate can update the launch price, gift and pause or resume the
+		/// launch token. A previous delegate is replaced.
+		#[pallet::weight(T::WeightInfo::delegate_launch())]
+		pub fn delegate_launch(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			delegate: CreatorId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::delegate_launch {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				delegate: delegate.clone(),
+			})?;
 
-		/// Cannot set token price to zero
-		ZeroPrice,
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
 
-		/// Cannot transfer token to self
-		TransferToSelf,
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
 
-		/// Max number of creator accounts reached
-		MaxCreatorAccountsReached,
+			// ensure delegate is another existing creator account
+			ensure!(delegate != creator_id, Error::<T>::InvalidDelegate);
+			ensure!(Creators::<T>::contains_key(&delegate), Error::<T>::CreatorNotFound);
 
-		/// Max number of launch tokens reached
-		MaxLaunchTokensReached,
+			LaunchDelegates::<T>::insert(&launch_token_id, &delegate);
 
-		/// Max number of tokens reached
-		MaxTokensReached,
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchDelegated(creator_id, launch_token_id, delegate));
 
-		/// Max launch tokens minted
-		LaunchTokensOverflow,
+			Ok(())
+		}
 
-		/// Max tokens minted
-		TokensOverflow,
-	}
+		/// Revoke delegated management of launch token.
+		#[pallet::weight(T::WeightInfo::revoke_launch_delegation())]
+		pub fn revoke_launch_delegation(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::revoke_launch_delegation {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
 
-	// CALLS
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Create new creator account.
-		#[pallet::weight(weights::HIGH + T::DbWeight::get().reads_writes(2, 2))]
-		pub fn create_account(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			Self::add_new_creator_to_account(creator_id.clone(), account.clone())?;
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			let delegate = LaunchDelegates::<T>::take(&launch_token_id)
+				.ok_or(Error::<T>::DelegationNotFound)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::NewCreator(account, creator_id));
+			Self::deposit_event(Event::<T>::LaunchDelegationRevoked(
+				creator_id,
+				launch_token_id,
+				delegate,
+			));
 
 			Ok(())
 		}
 
-		/// Drop creator account.
-		///
-		/// Keeps creator account alive if tokens have been created by the creator account.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(3, 2))]
-		pub fn drop_account(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+		/// Set creator account as primary creator of account, used as its display name.
+		#[pallet::weight(T::WeightInfo::set_primary_creator())]
+		pub fn set_primary_creator(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_primary_creator {
+				creator_id: creator_id.clone(),
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			Self::remove_creator_from_account(creator_id.clone(), account.clone())?;
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
+
+			PrimaryCreatorForAccount::<T>::insert(&account, &creator_id);
 
 			// emit events
-			Self::deposit_event(Event::<T>::DroppedCreator(account, creator_id));
+			Self::deposit_event(Event::<T>::PrimaryCreatorSet(account, creator_id));
 
 			Ok(())
 		}
 
-		/// Create new token.
-		#[pallet::weight(weights::HIGH + T::DbWeight::get().reads_writes(3, 3))]
-		pub fn mint(
+		/// Upload chunk of media for launch token at index, replacing any chunk already at index.
+		///
+		/// Media is assembled from chunks `0..n` and fixed with `finalize_media`. Deposit per byte
+		/// is reserved from account, chunks of an upload can only be sent by the account that
+		/// started it.
+		#[pallet::weight(T::WeightInfo::upload_media_chunk())]
+		pub fn upload_media_chunk(
 			origin: OriginFor<T>,
 			creator_id: CreatorId,
-			price: BalanceOf<T>,
-			metadata: LaunchTokenMetadata,
+			launch_token_id: TokenId,
+			index: u32,
+			chunk: MediaChunk,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::upload_media_chunk {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				index,
+				chunk: chunk.clone(),
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// verify account owns creator account
-			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
 
-			// mint launch token
-			let token_id = Self::unchecked_mint(creator_id.clone(), price, metadata)?;
+			Self::unchecked_upload_media_chunk(&account, &launch_token_id, index, chunk)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenCreated(creator_id, token_id));
+			Self::deposit_event(Event::<T>::MediaChunkUploaded(launch_token_id, index));
 
 			Ok(())
 		}
 
-		/// Gift token to account first hand.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(4, 4))]
-		pub fn launch_gift(
+		/// Finalize media of launch token, fixing its content hash.
+		#[pallet::weight(T::WeightInfo::finalize_media(T::MaxMediaChunks::get()))]
+		pub fn finalize_media(
 			origin: OriginFor<T>,
 			creator_id: CreatorId,
 			launch_token_id: TokenId,
-			receiver: T::AccountId,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::finalize_media {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// verify account owns creator account
-			Self::ensure_account_owns_creator(&account, &creator_id)?;
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
 			// verify creator account owns launch token
 			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
 
-			// transfer token to receiver
-			let token_id = Self::unchecked_launch_transfer(&receiver, &launch_token_id)?;
+			let hash = Self::unchecked_finalize_media(&launch_token_id)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenInitialCollection(account, creator_id, token_id));
+			Self::deposit_event(Event::<T>::MediaFinalized(launch_token_id, hash));
 
 			Ok(())
 		}
 
-		/// Buy token from creator first hand.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(5, 4))]
-		pub fn launch_buy(
+		/// Remove unfinalized media of launch token, returning its deposit.
+		#[pallet::weight(T::WeightInfo::clear_media(T::MaxMediaChunks::get()))]
+		pub fn clear_media(
 			origin: OriginFor<T>,
+			creator_id: CreatorId,
 			launch_token_id: TokenId,
-			bid_price: BalanceOf<T>,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::clear_media {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// ensure sufficient balance
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns launch token
+			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+
+			// ensure media is not finalized
 			ensure!(
-				T::Currency::free_balance(&account) >= bid_price,
-				Error::<T>::InsufficientFunds
+				!Self::media_upload(&launch_token_id).map_or(false, |upload| upload.is_finalized()),
+				Error::<T>::MediaFinalized
 			);
 
-			let launch_token =
-				Self::launch_tokens(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			Self::unchecked_clear_media(&launch_token_id)?;
 
-			// get launch token owner
-			let (launch_token_owner, launch_token_creator) =
-				Self::get_launch_token_owner(&launch_token_id)
-					.ok_or(Error::<T>::TokenUnavailable)?;
+			// emit events
+			Self::deposit_event(Event::<T>::MediaCleared(launch_token_id));
+
+			Ok(())
+		}
+
+		/// Set creator account as given, for seeding state on test networks and migrations.
+		///
+		/// Only available with the `force-calls` feature.
+		#[pallet::weight(T::WeightInfo::force_set_creator())]
+		pub fn force_set_creator(origin: OriginFor<T>, creator: Creator<T>) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::force_set_creator { creator: creator.clone() })?;
 
-			// ensure bid price is enough to cover purchase
-			ensure!(bid_price >= launch_token.price, Error::<T>::BidPriceTooLow);
+			// allow only root origin
+			ensure_root(origin)?;
 
-			// transfer token to receiver from launch token
-			let token_id = Self::unchecked_launch_transfer(&account, &launch_token_id)?;
+			// ensure force calls are enabled
+			ensure!(cfg!(feature = "force-calls"), Error::<T>::ForceCallsDisabled);
 
-			// transfer funds
-			T::Currency::transfer(&account, &launch_token_owner, bid_price, KeepAlive)
-				.expect("Funds not transferred after token transfer");
+			let creator_id = creator.id.clone();
+			Self::unchecked_force_set_creator(creator)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenInitialCollection(
-				account,
-				launch_token_creator.clone(),
-				token_id,
-			));
+			Self::deposit_event(Event::<T>::CreatorForceSet(creator_id));
 
 			Ok(())
 		}
 
-		/// Buy token from market.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(4, 3))]
-		pub fn buy(
+		/// Set launch token and its state as given, for seeding state on test networks and
+		/// migrations.
+		///
+		/// Only available with the `force-calls` feature.
+		#[pallet::weight(T::WeightInfo::force_set_launch_token())]
+		pub fn force_set_launch_token(
 			origin: OriginFor<T>,
-			token_id: TokenId,
-			bid_price: BalanceOf<T>,
+			launch_token: LaunchToken<T>,
+			launch_token_state: LaunchTokenState,
 		) -> DispatchResult {
-			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::force_set_launch_token {
+				launch_token: launch_token.clone(),
+				launch_token_state,
+			})?;
 
-			// ensure sufficient balance
-			ensure!(
-				T::Currency::free_balance(&account) >= bid_price,
-				Error::<T>::InsufficientFunds
-			);
+			// allow only root origin
+			ensure_root(origin)?;
 
-			let token = Self::tokens(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			// ensure force calls are enabled
+			ensure!(cfg!(feature = "force-calls"), Error::<T>::ForceCallsDisabled);
 
-			// get if token price, return error if not for sale
-			let token_price = token.price.ok_or(Error::<T>::TokenNotForSale)?;
+			let launch_token_id = launch_token.id;
+			Self::unchecked_force_set_launch_token(launch_token, launch_token_state)?;
 
-			// ensure bid price is enough to cover purchase
-			ensure!(bid_price >= token_price, Error::<T>::BidPriceTooLow);
+			// emit events
+			Self::deposit_event(Event::<T>::LaunchTokenForceSet(launch_token_id));
+
+			Ok(())
+		}
+
+		/// Set token as given, for seeding state on test networks and migrations.
+		///
+		/// Only available with the `force-calls` feature.
+		#[pallet::weight(T::WeightInfo::force_set_token())]
+		pub fn force_set_token(origin: OriginFor<T>, token: Token<T>) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::force_set_token { token: token.clone() })?;
+
+			// allow only root origin
+			ensure_root(origin)?;
 
-			// transfer token from owner to account
-			Self::unchecked_transfer(&token.owner, &account, &token_id)?;
+			// ensure force calls are enabled
+			ensure!(cfg!(feature = "force-calls"), Error::<T>::ForceCallsDisabled);
 
-			// transfer funds
-			T::Currency::transfer(&account, &token.owner, bid_price, KeepAlive)
-				.expect("Funds not transferred after token transfer");
+			let token_id = token.id;
+			Self::unchecked_force_set_token(token)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenTransferred(token.owner, account, token_id));
+			Self::deposit_event(Event::<T>::TokenForceSet(token_id));
 
 			Ok(())
 		}
 
-		/// Transfer token to account.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(3, 3))]
-		pub fn transfer(
+		/// Register account as marketplace, allowing it to receive royalty enforced tokens.
+		#[pallet::weight(T::WeightInfo::add_marketplace())]
+		pub fn add_marketplace(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::add_marketplace { account: account.clone() })?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure account is not a marketplace
+			ensure!(!Marketplaces::<T>::contains_key(&account), Error::<T>::MarketplaceExists);
+
+			Marketplaces::<T>::insert(&account, ());
+
+			// emit events
+			Self::deposit_event(Event::<T>::MarketplaceAdded(account));
+
+			Ok(())
+		}
+
+		/// Unregister marketplace.
+		#[pallet::weight(T::WeightInfo::remove_marketplace())]
+		pub fn remove_marketplace(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::remove_marketplace { account: account.clone() })?;
+
+			// allow only moderation origin
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			// ensure account is a marketplace
+			ensure!(Marketplaces::<T>::contains_key(&account), Error::<T>::MarketplaceNotFound);
+
+			Marketplaces::<T>::remove(&account);
+
+			// emit events
+			Self::deposit_event(Event::<T>::MarketplaceRemoved(account));
+
+			Ok(())
+		}
+
+		/// Transfer tokens of launch token owned by account to receiver.
+		///
+		/// Moves up to `MaxBatch` tokens per call, all selected tokens are transferred or none.
+		#[pallet::weight(T::WeightInfo::transfer_all_of_launch(T::MaxBatch::get()))]
+		pub fn transfer_all_of_launch(
 			origin: OriginFor<T>,
-			token_id: TokenId,
+			launch_token_id: TokenId,
 			receiver: T::AccountId,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::transfer_all_of_launch {
+				launch_token_id,
+				receiver: receiver.clone(),
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// check if token exists and return `NotFound` error early
-			Self::tokens(token_id).ok_or(Error::<T>::TokenNotFound)?;
+			let token_ids = Self::token_ids_of_launch_for_account(
+				&account,
+				&launch_token_id,
+				T::MaxBatch::get() as usize,
+			);
 
-			// ensure account owns token
-			Self::ensure_account_owns_token(&account, &token_id)?;
+			// ensure account holds tokens of launch token
+			ensure!(!token_ids.is_empty(), Error::<T>::NoTokensOfLaunch);
 
-			// transfer token to receiver
-			Self::unchecked_transfer(&account, &receiver, &token_id)?;
+			for token_id in token_ids.iter() {
+				// ensure token is not locked
+				Self::ensure_token_unlocked(token_id)?;
+
+				// ensure token transfer lockup is over
+				Self::ensure_lockup_over(token_id)?;
+
+				// ensure transfer does not bypass enforced royalty
+				Self::ensure_transfer_respects_royalty(token_id, &receiver)?;
+
+				// transfer token to receiver
+				Self::unchecked_transfer(&account, &receiver, token_id)?;
+			}
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenTransferred(account, receiver, token_id));
+			for token_id in token_ids.iter() {
+				Self::deposit_event(Event::<T>::TokenTransferred(
+					account.clone(),
+					receiver.clone(),
+					*token_id,
+				));
+			}
+			Self::deposit_event(Event::<T>::TokensTransferredBatch(
+				account,
+				receiver,
+				token_ids.len() as u32,
+			));
 
 			Ok(())
 		}
 
-		/// List token on market.
-		#[pallet::weight(weights::LOW + T::DbWeight::get().reads_writes(1, 1))]
-		pub fn list(
+		/// Nominate account to claim creator account once its owner has not signed any call for
+		/// `threshold` blocks.
+		///
+		/// Removes the succession if `None`.
+		#[pallet::weight(T::WeightInfo::set_successor())]
+		pub fn set_successor(
 			origin: OriginFor<T>,
-			token_id: TokenId,
-			price: BalanceOf<T>,
+			creator_id: CreatorId,
+			succession: Option<(T::AccountId, T::BlockNumber)>,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_successor {
+				creator_id: creator_id.clone(),
+				succession: succession.clone(),
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// ensure account owns token
-			Self::ensure_account_owns_token(&account, &token_id)?;
+			// verify account owns creator account
+			Self::ensure_account_owns_creator(&account, &creator_id)?;
 
-			// ensure token does not have a price
-			ensure!(Self::get_token_price(&token_id).is_none(), Error::<T>::TokenAlreadyListed);
+			match succession.clone() {
+				Some((successor, threshold)) => {
+					// ensure threshold is not below minimum
+					ensure!(
+						threshold >= T::MinSuccessionThreshold::get(),
+						Error::<T>::InvalidSuccessionThreshold
+					);
 
-			Self::unchecked_set_price(&token_id, Some(price))?;
+					Self::unchecked_set_succession(
+						&creator_id,
+						&account,
+						Succession { successor, threshold },
+					);
+				},
+				None => Self::clear_succession(&creator_id),
+			}
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenListed(account, token_id, Some(price)));
+			Self::deposit_event(Event::<T>::SuccessorSet(
+				creator_id,
+				succession.as_ref().map(|(successor, _)| successor.clone()),
+				succession.map(|(_, threshold)| threshold),
+			));
 
 			Ok(())
 		}
 
-		/// Unlist token from market.
-		#[pallet::weight(weights::LOW + T::DbWeight::get().reads_writes(1, 1))]
-		pub fn unlist(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+		/// Claim ownership of creator account as its nominated successor.
+		///
+		/// The owner must not have signed any call for the succession threshold.
+		#[pallet::weight(T::WeightInfo::claim_succession())]
+		pub fn claim_succession(origin: OriginFor<T>, creator_id: CreatorId) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::claim_succession {
+				creator_id: creator_id.clone(),
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// ensure account owns token
-			Self::ensure_account_owns_token(&account, &token_id)?;
+			let succession = Self::succession(&creator_id)
+				.filter(|succession| succession.successor == account)
+				.ok_or(Error::<T>::SuccessionNotFound)?;
 
-			// ensure token has price
-			ensure!(Self::get_token_price(&token_id).is_some(), Error::<T>::TokenNotListed);
+			// ensure owner has been inactive for the threshold
+			let owner = Self::creator(&creator_id)
+				.and_then(|creator| creator.owner)
+				.ok_or(Error::<T>::CreatorNotFound)?;
+			Self::ensure_owner_inactive(&owner, succession.threshold)?;
 
-			// update token price
-			Self::unchecked_set_price(&token_id, None)?;
+			Self::unchecked_set_creator_owner(&creator_id, account.clone())?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenUnlisted(account, token_id, None));
+			Self::deposit_event(Event::<T>::CreatorOwnerChanged(creator_id, owner, account));
 
 			Ok(())
 		}
 
-		/// Update launch price of token.
-		#[pallet::weight(weights::LOW + T::DbWeight::get().reads_writes(2, 1))]
-		pub fn set_launch_price(
+		/// Withdraw proceeds of sales and royalties credited to account.
+		///
+		/// Proceeds below the existential deposit can only be withdrawn to existing accounts.
+		#[pallet::weight(T::WeightInfo::withdraw_proceeds())]
+		pub fn withdraw_proceeds(origin: OriginFor<T>) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::withdraw_proceeds {})?;
+
+			// allow only signed origin
+			let account = Self::ensure_signed_tracked(origin)?;
+
+			let amount = Self::unchecked_withdraw_proceeds(&account)?;
+
+			// emit events
+			Self::deposit_event(Event::<T>::ProceedsWithdrawn(account, amount));
+
+			Ok(())
+		}
+
+		/// Set account credited with proceeds of tokens sold by the signing account, through
+		/// purchases, accepted offers and other secondary sales.
+		///
+		/// Removes the payout account if `None`, proceeds are then credited to the seller.
+		#[pallet::weight(T::WeightInfo::set_payout_account())]
+		pub fn set_payout_account(
 			origin: OriginFor<T>,
-			creator_id: CreatorId,
-			launch_token_id: TokenId,
-			price: BalanceOf<T>,
+			payout: Option<T::AccountId>,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::set_payout_account { payout: payout.clone() })?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// verify account owns creator account
-			Self::ensure_account_owns_creator(&account, &creator_id)?;
-			// verify creator account owns launch token
-			Self::ensure_creator_owns_launch_token(&creator_id, &launch_token_id)?;
+			// ensure payout account differs from account
+			ensure!(payout.as_ref() != Some(&account), Error::<T>::InvalidPayoutAccount);
 
-			// update launch token price
-			Self::unchecked_set_launch_price(&launch_token_id, price)?;
+			PayoutAccounts::<T>::set(&account, payout.clone());
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenLaunchPriceUpdated(
-				creator_id,
-				launch_token_id,
-				Some(price),
-			));
+			Self::deposit_event(Event::<T>::PayoutAccountUpdated(account, payout));
 
 			Ok(())
 		}
 
-		/// Update price of token.
-		#[pallet::weight(weights::LOW + T::DbWeight::get().reads_writes(1, 1))]
-		pub fn set_price(
+		/// Record current holders of launch token in a snapshot.
+		///
+		/// Launch tokens with more holders than a snapshot can record cannot be snapshotted.
+		#[pallet::weight(T::WeightInfo::take_snapshot(T::MaxSnapshotHolders::get()))]
+		pub fn take_snapshot(
 			origin: OriginFor<T>,
-			token_id: TokenId,
-			price: BalanceOf<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
 		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::take_snapshot {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// ensure account owns token
-			Self::ensure_account_owns_token(&account, &token_id)?;
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
 
-			// ensure token has price
-			ensure!(Self::get_token_price(&token_id).is_some(), Error::<T>::TokenNotListed);
+			// ensure holders fit in a snapshot
+			ensure!(
+				Self::unique_holders(launch_token_id) <= T::MaxSnapshotHolders::get(),
+				Error::<T>::SnapshotTooLarge
+			);
 
-			// update token price
-			Self::unchecked_set_price(&token_id, Some(price))?;
+			let (snapshot_id, holders) =
+				Self::unchecked_take_snapshot(&creator_id, &launch_token_id)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenPriceUpdated(account, token_id, Some(price)));
+			Self::deposit_event(Event::<T>::SnapshotTaken(
+				creator_id,
+				snapshot_id,
+				launch_token_id,
+				holders,
+			));
 
 			Ok(())
 		}
 
-		/// Destroy token.
-		#[pallet::weight(weights::MID + T::DbWeight::get().reads_writes(3, 3))]
-		pub fn burn(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+		/// Airdrop one token of launch token to each holder of snapshot.
+		///
+		/// Tokens are issued from the gift supply by the work queue over the following blocks.
+		#[pallet::weight(T::WeightInfo::airdrop_to_snapshot())]
+		pub fn airdrop_to_snapshot(
+			origin: OriginFor<T>,
+			creator_id: CreatorId,
+			launch_token_id: TokenId,
+			snapshot_id: SnapshotId,
+		) -> DispatchResult {
+			// ensure call is enabled
+			Self::ensure_call_allowed(&Call::<T>::airdrop_to_snapshot {
+				creator_id: creator_id.clone(),
+				launch_token_id,
+				snapshot_id,
+			})?;
+
 			// allow only signed origin
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_tracked(origin)?;
 
-			// ensure account owns token
-			Self::ensure_account_owns_token(&account, &token_id)?;
+			// verify account manages creator account
+			Self::ensure_account_manages_creator(&account, &creator_id)?;
+			// verify creator account owns or manages launch token by delegation
+			Self::ensure_creator_operates_launch_token(&creator_id, &launch_token_id)?;
 
-			Self::unchecked_burn(&token_id)?;
+			// ensure creator account is not banned
+			Self::ensure_creator_not_banned(&creator_id)?;
+
+			// ensure snapshot was taken by creator account
+			let snapshot = Self::snapshot(snapshot_id)
+				.filter(|snapshot| snapshot.creator == creator_id)
+				.ok_or(Error::<T>::SnapshotNotFound)?;
+
+			// ensure gift supply covers all snapshot holders
+			let launch_token_state =
+				Self::launch_token_state(launch_token_id).ok_or(Error::<T>::TokenNotFound)?;
+			ensure!(
+				launch_token_state.available_for_gift() >= snapshot.holders,
+				Error::<T>::GiftSupplyExhausted
+			);
+
+			let work_id = Self::unchecked_queue_work(
+				WorkItem::Airdrop { launch_token_id, snapshot_id },
+				snapshot.holders,
+			)?;
 
 			// emit events
-			Self::deposit_event(Event::<T>::TokenDestroyed(account, token_id));
+			Self::deposit_event(Event::<T>::AirdropQueued(
+				creator_id,
+				launch_token_id,
+				snapshot_id,
+				work_id,
+			));
 
 			Ok(())
 		}
@@ -0,0 +1,197 @@
+//! Storage migrations of pallet-fanbase
+
+use crate::{Config, Pallet};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+/// Endow the pallet account on chains that started before it was endowed at genesis.
+///
+/// Redeemable reserves, proceeds and milestone escrows are transferred into the pallet account,
+/// transfers below the minimum balance fail until it exists. Runs on every upgrade and only
+/// writes if the pallet account is below the minimum balance.
+pub struct FundPalletAccount<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for FundPalletAccount<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::ensure_pallet_account_funded() {
+			T::DbWeight::get().reads_writes(1, 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+}
+
+/// Migrate creators and launch tokens from the baseline layout to storage version 1.
+///
+/// Creators gain a registration expiry and a parent. Launch tokens gain their sale settings with
+/// defaults, issuance counters and sale state move out of `LaunchTokens` into
+/// `LaunchTokenStates`, and launch tokens are indexed by state and name. Creator stats are
+/// backfilled with the launches, issued and destroyed tokens of each creator.
+pub mod v1 {
+	use super::*;
+	use crate::{
+		types::{
+			aliases::BalanceOf, CreatorId, LaunchState, MetatataUri, MimeType, PriceMode, TokenId,
+			TokenName, TokenSupply,
+		},
+		Creator, Creators, LaunchToken, LaunchTokenState, LaunchTokenStates, LaunchTokens,
+	};
+	use frame_support::sp_runtime::{traits::Saturating, Permill};
+
+	/// Creator layout of storage version 0.
+	#[derive(Decode)]
+	struct OldCreator<T: Config> {
+		id: CreatorId,
+		owner: Option<T::AccountId>,
+	}
+
+	/// Launch token layout of storage version 0.
+	#[derive(Decode)]
+	struct OldLaunchToken<T: Config> {
+		id: TokenId,
+		creator: CreatorId,
+		name: TokenName,
+		price: BalanceOf<T>,
+		mime_type: MimeType,
+		metadata_uri: MetatataUri,
+		supply: TokenSupply,
+		issued: TokenSupply,
+		destroyed: TokenSupply,
+	}
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// existing registrations start a full registration period
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::CreatorRegistrationPeriod::get());
+			let mut creators = 0u64;
+			Creators::<T>::translate::<OldCreator<T>, _>(|_, old| {
+				creators += 1;
+				Some(Creator { id: old.id, owner: old.owner, expires_at, parent: None })
+			});
+
+			let mut launch_tokens = 0u64;
+			LaunchTokens::<T>::translate::<OldLaunchToken<T>, _>(|id, old| {
+				launch_tokens += 1;
+				let mut launch_token_state = LaunchTokenState {
+					supply: old.supply,
+					issued: old.issued,
+					destroyed: old.destroyed,
+					gift_supply: 0,
+					gifted: 0,
+					state: LaunchState::Live,
+				};
+				if launch_token_state.available_for_sale() == 0 {
+					launch_token_state.state = LaunchState::SoldOut;
+				}
+
+				let launch_token = LaunchToken {
+					id: old.id,
+					creator: old.creator,
+					name: old.name,
+					price: old.price,
+					price_mode: PriceMode::Native,
+					redeemable: Permill::zero(),
+					royalty: Permill::zero(),
+					royalty_enforced: false,
+					receipts: false,
+					lockup: 0,
+					curated: false,
+					mime_type: old.mime_type,
+					metadata_uri: old.metadata_uri,
+					tags: Default::default(),
+					content_hash: None,
+					metadata_version: 0,
+				};
+
+				Pallet::<T>::index_launch_state(&id, &launch_token_state);
+				Pallet::<T>::index_launch_name(&launch_token);
+				Pallet::<T>::update_creator_stats(&launch_token.creator, |stats| {
+					stats.launches = stats.launches.saturating_add(1);
+					stats.issued = stats.issued.saturating_add(launch_token_state.issued);
+					stats.destroyed = stats.destroyed.saturating_add(launch_token_state.destroyed);
+				});
+				LaunchTokenStates::<T>::insert(id, launch_token_state);
+
+				Some(launch_token)
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(
+				creators.saturating_add(launch_tokens.saturating_mul(3)).saturating_add(1),
+				creators.saturating_add(launch_tokens.saturating_mul(5)).saturating_add(1),
+			)
+		}
+	}
+}
+
+/// Migrate tokens from the baseline layout to storage version 2.
+///
+/// Tokens no longer copy name, mime type and metadata uri of their launch token. Metadata uris
+/// that differ from the launch token are kept on the token. Holders and listings are indexed.
+pub mod v2 {
+	use super::*;
+	use crate::{
+		types::{aliases::BalanceOf, CreatorId, MetatataUri, MimeType, TokenId, TokenName},
+		Token, Tokens,
+	};
+	use frame_support::sp_runtime::traits::Zero;
+
+	/// Token layout of storage version 0.
+	#[derive(Decode)]
+	struct OldToken<T: Config> {
+		id: TokenId,
+		launch_id: TokenId,
+		creator: CreatorId,
+		owner: T::AccountId,
+		_name: TokenName,
+		price: Option<BalanceOf<T>>,
+		_mime_type: MimeType,
+		metadata_uri: MetatataUri,
+	}
+
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Tokens::<T>::translate::<OldToken<T>, _>(|_, old| {
+				count += 1;
+				let metadata_uri = Pallet::<T>::launch_token(old.launch_id)
+					.filter(|launch_token| launch_token.metadata_uri == old.metadata_uri)
+					.map_or(Some(old.metadata_uri), |_| None);
+
+				let token = Token {
+					id: old.id,
+					launch_id: old.launch_id,
+					creator: old.creator,
+					owner: old.owner,
+					price: old.price,
+					metadata_uri,
+					metadata_version: 0,
+					locked_until: Zero::zero(),
+				};
+
+				Pallet::<T>::track_acquired(&token.launch_id, &token.owner, &token.id);
+				Pallet::<T>::index_listing(&token);
+
+				Some(token)
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(
+				count.saturating_mul(6).saturating_add(1),
+				count.saturating_mul(9).saturating_add(1),
+			)
+		}
+	}
+}
@@ -1,10 +1,18 @@
 use crate as pallet_fanbase;
-use frame_support::traits::{ConstU128, ConstU16, ConstU32, ConstU64};
+use codec::Decode;
+use frame_support::{
+	parameter_types,
+	traits::{
+		ConstBool, ConstU128, ConstU16, ConstU32, ConstU64, Contains, GenesisBuild, Randomness,
+	},
+	PalletId,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -70,15 +78,186 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Test>;
 }
 
+parameter_types! {
+	pub const FanbasePalletId: PalletId = PalletId(*b"fanbase_");
+	pub const MaxRoyalty: Permill = Permill::from_percent(50);
+	pub const DefaultBidIncrement: pallet_fanbase::types::BidIncrement<Balance> =
+		pallet_fanbase::types::BidIncrement::Absolute(1);
+}
+
+parameter_types! {
+	pub static BurnDisabled: bool = false;
+}
+
+/// Disable `burn` while `BurnDisabled` is set.
+pub struct TestCallFilter;
+
+impl Contains<pallet_fanbase::Call<Test>> for TestCallFilter {
+	fn contains(call: &pallet_fanbase::Call<Test>) -> bool {
+		!(BurnDisabled::get() && matches!(call, pallet_fanbase::Call::burn { .. }))
+	}
+}
+
 impl pallet_fanbase::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type Payment = pallet_fanbase::traits::CurrencyPayment<Balances>;
+	type Call = Call;
+	type PalletId = FanbasePalletId;
+	type IdentityVerifier = ();
+	type TransferFilter = ();
+	type CallFilter = TestCallFilter;
+	type PriceOracle = ();
+	type TokenIdGenerator = ();
+	type ModerationOrigin = frame_system::EnsureRoot<u64>;
+	type DisputeOrigin = frame_system::EnsureRoot<u64>;
+	type DisputePeriod = ConstU64<10>;
 	type MaxCreatorAccounts = ConstU32<100>;
+	type MaxCollaborators = ConstU32<10>;
+	type MaxOperators = ConstU32<5>;
 	type MaxLaunchTokens = ConstU32<100>;
 	type MaxTokens = ConstU32<100>;
+	type MinTokenPrice = ConstU128<1>;
+	type PriceChangeCooldown = ConstU64<10>;
+	type MaxMintsPerPeriod = ConstU32<10>;
+	type MintPeriod = ConstU64<100>;
+	type CreatorRegistrationPeriod = ConstU64<1000>;
+	type CreatorGracePeriod = ConstU64<100>;
+	type CreatorRenewalFee = ConstU128<0>;
+	type MaxSubCreators = ConstU32<10>;
+	type RecoveryOrigin = frame_system::EnsureRoot<u64>;
+	type RecoveryDelay = ConstU64<10>;
+	type CreatorTransferTimeout = ConstU64<10>;
+	type MinSuccessionThreshold = ConstU64<10>;
+	type MaxSweepCount = ConstU32<10>;
+	type MaxLaunchBuyQuantity = ConstU32<10>;
+	type Randomness = TestRandomness;
+	type MaxRaffleEntries = ConstU32<100>;
+	type MaxPurchaseIntents = ConstU32<100>;
+	type MaxMemoLength = ConstU32<64>;
+	type MaxBatch = ConstU32<10>;
+	type MaxExpiryWeightPerBlock = ConstU64<1_000_000>;
+	type MilestoneOrigin = frame_system::EnsureRoot<u64>;
+	type MaxMilestones = ConstU32<10>;
+	type TaxonomyOrigin = frame_system::EnsureRoot<u64>;
+	type ReportDeposit = ConstU128<0>;
+	type InfringementDeposit = ConstU128<0>;
+	type OfferDeposit = ConstU128<0>;
+	type AllowDeathPayments = ConstBool<false>;
+	type RoyaltyDustTreasury = ();
+	type MaxRoyalty = MaxRoyalty;
+	type OnPurchase = ();
+	type InlineDataDepositPerByte = ConstU128<0>;
+	type MaxMediaChunks = ConstU32<16>;
+	type WeightInfo = ();
+	type MaxActivityLog = ConstU32<10>;
+	type WashTradeWindow = ConstU64<10>;
+	type RejectWashTrades = ConstBool<false>;
+	type RejectDuplicateContent = ConstBool<false>;
+	type AttestationOrigin = frame_system::EnsureRoot<u64>;
+	type CurationOrigin = frame_system::EnsureRoot<u64>;
+	type MaxFeaturedLaunches = ConstU32<10>;
+	type CalendarPeriod = ConstU64<100>;
+	type MaxCalendarEntries = ConstU32<10>;
+	type CalendarDeposit = ConstU128<0>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type MaxSnapshotHolders = ConstU32<100>;
+	type MaxQueuedWork = ConstU32<10>;
+	type BridgeVerifier = TestBridgeVerifier;
+	type MaxBridgeProofLength = ConstU32<256>;
+	type MaxSweepScan = ConstU32<100>;
+	type MaxRafflesClosingPerBlock = ConstU32<10>;
+	type MaxSocialPlatforms = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+/// Deterministic randomness derived from the subject.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
+/// Chain supported by the test bridge.
+pub const BRIDGE_CHAIN: u64 = 1;
+
+/// Bridge to `BRIDGE_CHAIN` releasing tokens to the account named by a proof of the encoded token
+/// id and receiver.
+pub struct TestBridgeVerifier;
+
+impl pallet_fanbase::traits::BridgeVerifier<u64, u64> for TestBridgeVerifier {
+	fn supports_chain(dest_chain: pallet_fanbase::types::ChainId) -> bool {
+		dest_chain == BRIDGE_CHAIN
+	}
+
+	fn verify_unlock(
+		token_id: &pallet_fanbase::types::TokenId,
+		_custody: &pallet_fanbase::types::BridgeCustody<u64, u64>,
+		proof: &[u8],
+	) -> Option<u64> {
+		let (proof_token_id, receiver) =
+			<(pallet_fanbase::types::TokenId, u64)>::decode(&mut &proof[..]).ok()?;
+
+		if proof_token_id == *token_id {
+			Some(receiver)
+		} else {
+			None
+		}
+	}
 }
 
+/// Test signatures and bridge proofs for benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_fanbase::traits::BenchmarkHelper<u64, TestSignature> for TestBenchmarkHelper {
+	fn signer() -> u64 {
+		1_000
+	}
+
+	fn sign(signer: &u64, payload: &[u8]) -> TestSignature {
+		TestSignature(*signer, payload.to_vec())
+	}
+
+	fn bridge_chain() -> Option<pallet_fanbase::types::ChainId> {
+		Some(BRIDGE_CHAIN)
+	}
+
+	fn bridge_unlock_proof(
+		token_id: &pallet_fanbase::types::TokenId,
+		receiver: &u64,
+	) -> Option<Vec<u8>> {
+		Some(codec::Encode::encode(&(token_id, receiver)))
+	}
+}
+
+/// Initial free balance of the endowed test accounts `1..=ENDOWED_ACCOUNTS`.
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+/// Number of endowed test accounts.
+pub const ENDOWED_ACCOUNTS: u64 = 5;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=ENDOWED_ACCOUNTS).map(|account| (account, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	GenesisBuild::<Test>::assimilate_storage(&pallet_fanbase::GenesisConfig, &mut storage).unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// events are only deposited from block 1
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -0,0 +1,181 @@
+//! Solidity ABI interface to the core fanbase calls, for EVM precompiles on runtimes with Frontier.
+//!
+//! Input is a 4 byte function selector followed by 32 byte words, as encoded by Solidity for:
+//!
+//! ```solidity
+//! interface Fanbase {
+//!     function transfer(uint256 tokenId, bytes32 receiver) external;
+//!     function buy(uint256 tokenId, uint256 bidPrice) external;
+//!     function list(uint256 tokenId, uint256 price) external;
+//!     function ownerOf(uint256 tokenId) external view returns (bytes32);
+//! }
+//! ```
+//!
+//! Accounts are passed as the SCALE encoding of the account id, left aligned in a `bytes32` word.
+//! The runtime precompile maps the EVM caller to an account, charges gas for `weight` and returns
+//! the output of `execute`. Calls are dispatched as runtime calls signed by the caller, so they
+//! pass the runtime call filter and go through the same checks as the extrinsics.
+
+use crate::{BalanceOf, Call, Config, Pallet, TokenId};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo},
+	pallet_prelude::*,
+	sp_std::{prelude::*, vec},
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+
+/// Selector of `transfer(uint256,bytes32)`
+pub const SELECTOR_TRANSFER: [u8; 4] = [0x22, 0xc2, 0x6d, 0xa5];
+/// Selector of `buy(uint256,uint256)`
+pub const SELECTOR_BUY: [u8; 4] = [0xd6, 0xfe, 0xbd, 0xe8];
+/// Selector of `list(uint256,uint256)`
+pub const SELECTOR_LIST: [u8; 4] = [0x50, 0xfd, 0x73, 0x67];
+/// Selector of `ownerOf(uint256)`
+pub const SELECTOR_OWNER_OF: [u8; 4] = [0x63, 0x52, 0x21, 0x1e];
+
+/// Size of an ABI word
+const WORD_SIZE: usize = 32;
+
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum PrecompileError {
+	/// Input does not start with a known selector
+	UnknownSelector,
+	/// Input arguments are missing or cannot be decoded
+	InvalidInput,
+	/// Numeric argument does not fit the pallet type
+	ValueOverflow,
+	/// Token does not exist
+	TokenNotFound,
+	/// Dispatched call failed
+	Dispatch(DispatchError),
+}
+
+/// Decoded precompile call.
+pub enum PrecompileCall<T: Config> {
+	Transfer { token_id: TokenId, receiver: T::AccountId },
+	Buy { token_id: TokenId, bid_price: BalanceOf<T> },
+	List { token_id: TokenId, price: BalanceOf<T> },
+	OwnerOf { token_id: TokenId },
+}
+
+impl<T: Config> PrecompileCall<T> {
+	/// Decode precompile call from ABI encoded input.
+	pub fn decode(input: &[u8]) -> Result<Self, PrecompileError> {
+		let selector: [u8; 4] = input
+			.get(..4)
+			.and_then(|selector| selector.try_into().ok())
+			.ok_or(PrecompileError::UnknownSelector)?;
+		let args = &input[4..];
+
+		match selector {
+			SELECTOR_TRANSFER => Ok(PrecompileCall::Transfer {
+				token_id: read_u128(args, 0)?,
+				receiver: read_account::<T>(args, 1)?,
+			}),
+			SELECTOR_BUY => Ok(PrecompileCall::Buy {
+				token_id: read_u128(args, 0)?,
+				bid_price: read_balance::<T>(args, 1)?,
+			}),
+			SELECTOR_LIST => Ok(PrecompileCall::List {
+				token_id: read_u128(args, 0)?,
+				price: read_balance::<T>(args, 1)?,
+			}),
+			SELECTOR_OWNER_OF => Ok(PrecompileCall::OwnerOf { token_id: read_u128(args, 0)? }),
+			_ => Err(PrecompileError::UnknownSelector),
+		}
+	}
+
+	/// Weight to charge as gas before executing the call.
+	pub fn weight(&self) -> Weight {
+		match self.dispatchable() {
+			Some(call) => call.get_dispatch_info().weight,
+			None => T::DbWeight::get().reads(1),
+		}
+	}
+
+	/// Execute call as signed by caller.
+	///
+	/// The call is dispatched as a runtime call and is subject to the runtime call filter.
+	///
+	/// Returns ABI encoded output.
+	pub fn execute(self, caller: T::AccountId) -> Result<Vec<u8>, PrecompileError>
+	where
+		<T as Config>::Call: From<Call<T>>,
+	{
+		if let PrecompileCall::OwnerOf { token_id } = self {
+			let owner = Pallet::<T>::token(token_id).ok_or(PrecompileError::TokenNotFound)?.owner;
+			return write_account::<T>(&owner)
+		}
+
+		if let Some(call) = self.dispatchable() {
+			<T as Config>::Call::from(call)
+				.dispatch(RawOrigin::Signed(caller).into())
+				.map_err(|e| PrecompileError::Dispatch(e.error))?;
+		}
+
+		Ok(Vec::new())
+	}
+
+	/// Pallet call of precompile call, `None` for views.
+	fn dispatchable(&self) -> Option<Call<T>> {
+		match self {
+			PrecompileCall::Transfer { token_id, receiver } => Some(Call::<T>::transfer {
+				token_id: *token_id,
+				receiver: receiver.clone(),
+				memo: None,
+			}),
+			PrecompileCall::Buy { token_id, bid_price } =>
+				Some(Call::<T>::buy { token_id: *token_id, bid_price: *bid_price, memo: None }),
+			PrecompileCall::List { token_id, price } =>
+				Some(Call::<T>::list { token_id: *token_id, price: *price }),
+			PrecompileCall::OwnerOf { .. } => None,
+		}
+	}
+}
+
+/// Read argument word at index.
+fn read_word(args: &[u8], index: usize) -> Result<&[u8], PrecompileError> {
+	args.get(index * WORD_SIZE..(index + 1) * WORD_SIZE)
+		.ok_or(PrecompileError::InvalidInput)
+}
+
+/// Read `uint256` argument at index, values above `u128::MAX` overflow.
+fn read_u128(args: &[u8], index: usize) -> Result<u128, PrecompileError> {
+	let (high, low) = read_word(args, index)?.split_at(16);
+
+	// ensure value fits in 128 bits
+	if high.iter().any(|byte| *byte != 0) {
+		return Err(PrecompileError::ValueOverflow)
+	}
+
+	let mut bytes = [0u8; 16];
+	bytes.copy_from_slice(low);
+
+	Ok(u128::from_be_bytes(bytes))
+}
+
+/// Read `uint256` argument at index as balance.
+fn read_balance<T: Config>(args: &[u8], index: usize) -> Result<BalanceOf<T>, PrecompileError> {
+	BalanceOf::<T>::try_from(read_u128(args, index)?).map_err(|_| PrecompileError::ValueOverflow)
+}
+
+/// Read `bytes32` argument at index as account.
+fn read_account<T: Config>(args: &[u8], index: usize) -> Result<T::AccountId, PrecompileError> {
+	T::AccountId::decode(&mut read_word(args, index)?).map_err(|_| PrecompileError::InvalidInput)
+}
+
+/// Write account as `bytes32` output.
+fn write_account<T: Config>(account: &T::AccountId) -> Result<Vec<u8>, PrecompileError> {
+	let encoded = account.encode();
+
+	// ensure account fits in a word
+	if encoded.len() > WORD_SIZE {
+		return Err(PrecompileError::ValueOverflow)
+	}
+
+	let mut output = vec![0u8; WORD_SIZE];
+	output[..encoded.len()].copy_from_slice(&encoded);
+
+	Ok(output)
+}
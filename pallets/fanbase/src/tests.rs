@@ -1,5 +1,73 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations,
+	mock::*,
+	traits::FanbaseInterface,
+	types::{
+		BridgeAddress, BridgeProofOf, CreatorId, LaunchState, LaunchTokenMetadata, MetatataUri,
+		MimeType, PalletParameter, PriceMode, PriceQuote, TokenId, TokenName, TokenSupply,
+	},
+	CollaboratorRole, Error,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{Currency, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+	BoundedVec,
+};
+use sp_runtime::{testing::TestSignature, DispatchError, Perbill, Permill};
+
+/// Creator id from bytes.
+fn creator_id(id: &[u8]) -> CreatorId {
+	id.to_vec().try_into().unwrap()
+}
+
+/// Launch token metadata with supply and no extras.
+fn launch_metadata(supply: TokenSupply) -> LaunchTokenMetadata {
+	LaunchTokenMetadata {
+		name: b"token".to_vec().try_into().unwrap(),
+		mime_type: b"image/png".to_vec().try_into().unwrap(),
+		metadata_uri: b"ipfs://token".to_vec().try_into().unwrap(),
+		tags: Default::default(),
+		content_hash: None,
+		supply,
+		gift_supply: 0,
+		price_mode: PriceMode::Native,
+		redeemable: Permill::zero(),
+		royalty: None,
+		royalty_enforced: false,
+		receipts: false,
+		lockup: 0,
+		curated: false,
+		draft: false,
+		inline_data: None,
+	}
+}
+
+/// Create creator account owned by account and mint launch token of creator.
+fn mint_launch(account: u64, id: &[u8], price: Balance, supply: TokenSupply) -> TokenId {
+	assert_ok!(Fanbase::create_account(Origin::signed(account), creator_id(id)));
+	assert_ok!(Fanbase::mint(
+		Origin::signed(account),
+		creator_id(id),
+		price,
+		launch_metadata(supply)
+	));
+	*Fanbase::launch_token_ids_for_creator(creator_id(id)).last().unwrap()
+}
+
+/// Mint launch token with price and buy a token of it as account 2.
+fn buy_token(price: Balance) -> TokenId {
+	let launch_token_id = mint_launch(1, b"alice", price, 10);
+	assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, price));
+	Fanbase::token_ids_for_account(2)[0]
+}
+
+/// Sign quote as its seller over the payload verified by the pallet.
+fn sign_quote(quote: &PriceQuote<u64, Balance, u64>) -> TestSignature {
+	let payload = (b"fanbase/quote", System::block_hash(0), quote).encode();
+	TestSignature(quote.seller, payload)
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -18,3 +86,1170 @@ fn correct_error_for_none_value() {
 		// assert_noop!(Fanbase::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
 	});
 }
+
+#[test]
+fn keyless_accounts_of_creator_ids_with_shared_prefix_differ() {
+	new_test_ext().execute_with(|| {
+		let first = creator_id(b"keyless-creator-with-a-long-shared-prefix-1");
+		let second = creator_id(b"keyless-creator-with-a-long-shared-prefix-2");
+
+		assert_ne!(
+			Fanbase::keyless_creator_account(&first),
+			Fanbase::keyless_creator_account(&second)
+		);
+
+		assert_ok!(Fanbase::create_account(Origin::signed(1), first.clone()));
+		assert_ok!(Fanbase::create_account(Origin::signed(2), second.clone()));
+		assert_ok!(Fanbase::add_collaborator(
+			Origin::signed(1),
+			first.clone(),
+			3,
+			CollaboratorRole::Admin
+		));
+		assert_ok!(Fanbase::make_creator_keyless(Origin::signed(1), first.clone()));
+		assert_ok!(Fanbase::make_creator_keyless(Origin::signed(2), second.clone()));
+
+		// admin of the first creator cannot drop the second through its keyless account
+		let drop_second = crate::Call::<Test>::drop_account { creator_id: second.clone() };
+		assert_ok!(Fanbase::dispatch_as_creator(
+			Origin::signed(3),
+			first,
+			Box::new(Call::Fanbase(drop_second))
+		));
+		assert_eq!(
+			Fanbase::creator(&second).and_then(|creator| creator.owner),
+			Some(Fanbase::keyless_creator_account(&second))
+		);
+		assert_noop!(
+			Fanbase::ensure_keyless_creator_admin(&3, &second),
+			Error::<Test>::NotCollaborator
+		);
+	});
+}
+
+#[test]
+fn failed_auction_sale_returns_bid_and_releases_token() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		let token_id = Fanbase::token_ids_for_account(2)[0];
+
+		assert_ok!(Fanbase::create_auction(Origin::signed(2), token_id, 100, 10, None, None, None));
+		assert_ok!(Fanbase::bid(Origin::signed(3), token_id, 200));
+		assert_eq!(Balances::reserved_balance(3), 200);
+
+		// highest bidder cannot hold more tokens
+		let held: BoundedVec<TokenId, _> = (1_000..1_100).collect::<Vec<_>>().try_into().unwrap();
+		crate::TokenIdsForAccount::<Test>::insert(3, held);
+
+		System::set_block_number(11);
+		assert_ok!(Fanbase::settle_auction(Origin::signed(4), token_id));
+
+		// bid is returned and token stays with the seller
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(2));
+		assert!(Fanbase::auction(token_id).is_none());
+
+		// token is released for a new auction
+		assert_ok!(Fanbase::create_auction(Origin::signed(2), token_id, 100, 10, None, None, None));
+	});
+}
+
+#[test]
+fn raffle_draws_from_seed_of_closing_block() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		assert_noop!(
+			Fanbase::open_raffle(
+				Origin::signed(1),
+				creator_id(b"alice"),
+				launch_token_id,
+				50,
+				1,
+				0
+			),
+			Error::<Test>::InvalidQuantity
+		);
+		assert_ok!(Fanbase::open_raffle(
+			Origin::signed(1),
+			creator_id(b"alice"),
+			launch_token_id,
+			50,
+			1,
+			5
+		));
+
+		assert_ok!(Fanbase::enter_raffle(Origin::signed(2), launch_token_id, 2));
+		assert_ok!(Fanbase::enter_raffle(Origin::signed(3), launch_token_id, 1));
+		assert_eq!(Balances::reserved_balance(2), 100);
+		assert_eq!(Balances::reserved_balance(3), 50);
+
+		// entries stay open until the closing block
+		System::set_block_number(5);
+		assert_noop!(
+			Fanbase::draw_raffle(Origin::signed(4), launch_token_id),
+			Error::<Test>::RaffleNotClosed
+		);
+
+		// draw is not possible before the closing block is seeded
+		System::set_block_number(6);
+		assert_noop!(
+			Fanbase::enter_raffle(Origin::signed(4), launch_token_id, 1),
+			Error::<Test>::RaffleClosed
+		);
+		assert_noop!(
+			Fanbase::draw_raffle(Origin::signed(4), launch_token_id),
+			Error::<Test>::RaffleNotClosed
+		);
+
+		Fanbase::seed_closing_raffles(6);
+		assert!(Fanbase::raffle(launch_token_id).and_then(|raffle| raffle.seed).is_some());
+		assert_ok!(Fanbase::draw_raffle(Origin::signed(4), launch_token_id));
+
+		// single winner is issued a token and pays its fees as a launch purchase, the other
+		// entrant is refunded
+		let winners: Vec<u64> = [2, 3]
+			.into_iter()
+			.filter(|account| !Fanbase::token_ids_for_account(account).is_empty())
+			.collect();
+		assert_eq!(winners.len(), 1);
+		let fees = if winners[0] == 2 { 100 } else { 50 };
+		assert_eq!(Fanbase::pending_proceeds(1), fees);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(
+			Balances::free_balance(2) + Balances::free_balance(3),
+			2 * INITIAL_BALANCE - fees
+		);
+		assert!(Fanbase::raffle(launch_token_id).is_none());
+	});
+}
+
+#[test]
+fn raffle_refunds_winner_that_cannot_be_issued_a_token() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		assert_ok!(Fanbase::open_raffle(
+			Origin::signed(1),
+			creator_id(b"alice"),
+			launch_token_id,
+			50,
+			1,
+			5
+		));
+		assert_ok!(Fanbase::enter_raffle(Origin::signed(2), launch_token_id, 1));
+
+		// entrant cannot hold more tokens
+		let held: BoundedVec<TokenId, _> = (1_000..1_100).collect::<Vec<_>>().try_into().unwrap();
+		crate::TokenIdsForAccount::<Test>::insert(2, held);
+
+		System::set_block_number(6);
+		Fanbase::seed_closing_raffles(6);
+		assert_ok!(Fanbase::draw_raffle(Origin::signed(4), launch_token_id));
+
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert_eq!(Fanbase::launch_token_state(launch_token_id).map(|state| state.issued), Some(0));
+	});
+}
+
+#[test]
+fn raffle_win_holds_redeemable_share_in_reserve() {
+	new_test_ext().execute_with(|| {
+		let mut metadata = launch_metadata(10);
+		metadata.redeemable = Permill::from_percent(10);
+		assert_ok!(Fanbase::create_account(Origin::signed(1), creator_id(b"alice")));
+		assert_ok!(Fanbase::mint(Origin::signed(1), creator_id(b"alice"), 100, metadata));
+		let launch_token_id = Fanbase::launch_token_ids_for_creator(creator_id(b"alice"))[0];
+
+		assert_ok!(Fanbase::open_raffle(
+			Origin::signed(1),
+			creator_id(b"alice"),
+			launch_token_id,
+			100,
+			1,
+			5
+		));
+		assert_ok!(Fanbase::enter_raffle(Origin::signed(2), launch_token_id, 1));
+
+		System::set_block_number(6);
+		Fanbase::seed_closing_raffles(6);
+		assert_ok!(Fanbase::draw_raffle(Origin::signed(4), launch_token_id));
+
+		// fees are split between creator proceeds and the redeemable reserve of the token
+		let token_id = Fanbase::token_ids_for_account(2)[0];
+		assert_eq!(Fanbase::pending_proceeds(1), 90);
+		assert_eq!(Fanbase::redeemable_reserve(token_id), Some(10));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+
+		// burning the token won in the raffle refunds the redeemable share
+		assert_ok!(Fanbase::burn(Origin::signed(2), token_id));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 90);
+	});
+}
+
+#[test]
+fn interface_mints_issues_transfers_and_burns_tokens() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Fanbase::create_account(Origin::signed(1), creator_id(b"alice")));
+
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::mint(creator_id(b"bob"), 100, launch_metadata(10)),
+			Error::<Test>::CreatorNotFound
+		);
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::mint(creator_id(b"alice"), 0, launch_metadata(10)),
+			Error::<Test>::PriceTooLow
+		);
+		let launch_token_id = <Fanbase as FanbaseInterface<_, _>>::mint(
+			creator_id(b"alice"),
+			100,
+			launch_metadata(10),
+		)
+		.unwrap();
+		assert_eq!(
+			Fanbase::launch_token_ids_for_creator(creator_id(b"alice")),
+			vec![launch_token_id]
+		);
+
+		// issued without payment
+		let token_id = <Fanbase as FanbaseInterface<_, _>>::issue(&2, &launch_token_id).unwrap();
+		assert_eq!(<Fanbase as FanbaseInterface<_, _>>::owner_of(&token_id), Some(2));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::transfer(&3, &4, &token_id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::transfer(&2, &2, &token_id),
+			Error::<Test>::TransferToSelf
+		);
+		assert_ok!(<Fanbase as FanbaseInterface<_, _>>::transfer(&2, &3, &token_id));
+		assert_eq!(Fanbase::token_ids_for_account(3), vec![token_id]);
+
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::burn(&2, &token_id),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(<Fanbase as FanbaseInterface<_, _>>::burn(&3, &token_id));
+		assert_eq!(<Fanbase as FanbaseInterface<_, _>>::owner_of(&token_id), None);
+	});
+}
+
+#[test]
+fn interface_rejects_mints_and_issuance_of_banned_creator() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		assert_ok!(Fanbase::ban_creator(Origin::root(), creator_id(b"alice")));
+
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::mint(
+				creator_id(b"alice"),
+				100,
+				launch_metadata(10)
+			),
+			Error::<Test>::CreatorIsBanned
+		);
+		assert_noop!(
+			<Fanbase as FanbaseInterface<_, _>>::issue(&2, &launch_token_id),
+			Error::<Test>::CreatorIsBanned
+		);
+	});
+}
+
+#[test]
+fn work_queue_processes_steps_within_weight_budget() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Fanbase::create_account(Origin::signed(1), creator_id(b"alice")));
+		for _ in 0..3 {
+			let mut metadata = launch_metadata(10);
+			metadata.royalty = Some(Permill::from_percent(20));
+			assert_ok!(Fanbase::mint(Origin::signed(1), creator_id(b"alice"), 100, metadata));
+		}
+
+		// lowering the max royalty queues work clamping each launch token
+		assert_ok!(Fanbase::set_parameter(
+			Origin::root(),
+			PalletParameter::MaxRoyalty(Some(Permill::from_percent(10)))
+		));
+		let work_id = Fanbase::work_queue()[0];
+		let step_weight = Fanbase::clamp_royalty_step_weight();
+
+		// work keeps its progress once the budget is used up
+		Fanbase::process_work_queue(2 * step_weight);
+		assert_eq!(Fanbase::work(work_id).map(|work| work.progress), Some(2));
+		assert_eq!(
+			Fanbase::launch_token(3).map(|launch_token| launch_token.royalty),
+			Some(Permill::from_percent(20))
+		);
+
+		// budget below a single step makes no progress
+		Fanbase::process_work_queue(step_weight - 1);
+		assert_eq!(Fanbase::work(work_id).map(|work| work.progress), Some(2));
+
+		Fanbase::process_work_queue(step_weight);
+		assert!(Fanbase::work(work_id).is_none());
+		assert!(Fanbase::work_queue().is_empty());
+		for launch_token_id in 1..=3 {
+			assert_eq!(
+				Fanbase::launch_token(launch_token_id).map(|launch_token| launch_token.royalty),
+				Some(Permill::from_percent(10))
+			);
+		}
+		System::assert_has_event(crate::Event::<Test>::WorkCompleted(work_id).into());
+	});
+}
+
+#[test]
+fn v1_migration_translates_baseline_creators_and_launch_tokens() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Fanbase>();
+		let alice = creator_id(b"alice");
+		let name: TokenName = b"token".to_vec().try_into().unwrap();
+		let mime_type: MimeType = b"image/png".to_vec().try_into().unwrap();
+		let metadata_uri: MetatataUri = b"ipfs://token".to_vec().try_into().unwrap();
+
+		// baseline creator and launch tokens, one of them sold out
+		unhashed::put(
+			&crate::Creators::<Test>::hashed_key_for(&alice),
+			&(alice.clone(), Some(1u64)),
+		);
+		for (launch_token_id, issued) in [(1 as TokenId, 4 as TokenSupply), (2, 10)] {
+			unhashed::put(
+				&crate::LaunchTokens::<Test>::hashed_key_for(launch_token_id),
+				&(
+					launch_token_id,
+					alice.clone(),
+					name.clone(),
+					100 as Balance,
+					mime_type.clone(),
+					metadata_uri.clone(),
+					10 as TokenSupply,
+					issued,
+					1 as TokenSupply,
+				),
+			);
+		}
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Fanbase>(), 1);
+
+		// creators start a full registration period
+		let creator = Fanbase::creator(&alice).unwrap();
+		assert_eq!(creator.owner, Some(1));
+		assert_eq!(creator.expires_at, 1001);
+		assert_eq!(creator.parent, None);
+
+		let launch_token = Fanbase::launch_token(1).unwrap();
+		assert_eq!(launch_token.creator, alice);
+		assert_eq!(launch_token.price, 100);
+		assert_eq!(launch_token.metadata_uri, metadata_uri);
+		assert_eq!(launch_token.royalty, Permill::zero());
+
+		// issuance counters move to the launch token state
+		let launch_token_state = Fanbase::launch_token_state(1).unwrap();
+		assert_eq!(
+			(launch_token_state.supply, launch_token_state.issued, launch_token_state.destroyed),
+			(10, 4, 1)
+		);
+		assert_eq!(launch_token_state.state, LaunchState::Live);
+		assert_eq!(
+			Fanbase::launch_token_state(2).map(|state| state.state),
+			Some(LaunchState::SoldOut)
+		);
+
+		// creator stats are backfilled from the migrated launch tokens
+		let stats = Fanbase::creator_stats(&alice);
+		assert_eq!((stats.launches, stats.issued, stats.destroyed, stats.listed), (2, 14, 2, 0));
+
+		// migrated launch tokens can be bought
+		crate::LaunchIssuanceNonce::<Test>::put(2);
+		crate::IssuanceNonce::<Test>::put(14);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), 1, 100));
+		assert_noop!(Fanbase::launch_buy(Origin::signed(2), 2, 100), Error::<Test>::TokenSoldOut);
+	});
+}
+
+#[test]
+fn v2_migration_drops_copied_metadata_and_indexes_holders_and_listings() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		StorageVersion::new(1).put::<Fanbase>();
+		let name: TokenName = b"token".to_vec().try_into().unwrap();
+		let mime_type: MimeType = b"image/png".to_vec().try_into().unwrap();
+		let launch_uri: MetatataUri = b"ipfs://token".to_vec().try_into().unwrap();
+		let token_uri: MetatataUri = b"ipfs://token/2".to_vec().try_into().unwrap();
+
+		// baseline tokens copying launch metadata, one listed and one with its own metadata uri
+		let tokens = [
+			(1 as TokenId, 2u64, Some(200 as Balance), launch_uri),
+			(2, 3, None, token_uri.clone()),
+		];
+		for (token_id, owner, price, metadata_uri) in tokens {
+			unhashed::put(
+				&crate::Tokens::<Test>::hashed_key_for(token_id),
+				&(
+					token_id,
+					launch_token_id,
+					creator_id(b"alice"),
+					owner,
+					name.clone(),
+					price,
+					mime_type.clone(),
+					metadata_uri,
+				),
+			);
+		}
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Fanbase>(), 2);
+
+		// metadata uris matching the launch token are resolved through the launch token
+		let token = Fanbase::token(1).unwrap();
+		assert_eq!((token.owner, token.price, token.metadata_uri), (2, Some(200), None));
+		assert_eq!(Fanbase::token(2).and_then(|token| token.metadata_uri), Some(token_uri));
+
+		// holders and listings are indexed
+		assert_eq!(Fanbase::held_tokens(launch_token_id, 2), 1);
+		assert_eq!(Fanbase::held_tokens(launch_token_id, 3), 1);
+		assert_eq!(Fanbase::unique_holders(launch_token_id), 2);
+		assert_eq!(Fanbase::get_floor_listings(&launch_token_id, 10), vec![(1, 200)]);
+		assert_eq!(Fanbase::creator_stats(creator_id(b"alice")).listed, 1);
+
+		// migration runs once
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Fanbase::unique_holders(launch_token_id), 2);
+	});
+}
+
+#[test]
+fn sweep_buys_cheapest_listings_from_price_floor() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		for account in [2, 2, 2, 3] {
+			assert_ok!(Fanbase::launch_buy(Origin::signed(account), launch_token_id, 100));
+		}
+		let tokens = Fanbase::token_ids_for_account(2);
+		let (first, second, dutch) = (tokens[0], tokens[1], tokens[2]);
+		let other = Fanbase::token_ids_for_account(3)[0];
+
+		assert_ok!(Fanbase::list(Origin::signed(2), first, 500));
+		assert_ok!(Fanbase::list(Origin::signed(2), second, 300));
+		assert_ok!(Fanbase::list_dutch(Origin::signed(2), dutch, 900, 100, 10));
+		assert_ok!(Fanbase::list(Origin::signed(3), other, 250));
+
+		// dutch listing is scanned from its end price but priced by block
+		assert_eq!(
+			Fanbase::get_floor_listings(&launch_token_id, 2),
+			vec![(other, 250), (dutch, 900)]
+		);
+
+		// repriced listing moves in the index
+		assert_ok!(Fanbase::set_price(Origin::signed(2), first, 200));
+		assert_eq!(
+			Fanbase::get_floor_listings(&launch_token_id, 3),
+			vec![(first, 200), (other, 250), (dutch, 900)]
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Fanbase::sweep(Origin::signed(4), launch_token_id, 2, 300, 10));
+		assert_eq!(Fanbase::token(dutch).map(|token| token.owner), Some(4));
+		assert_eq!(Fanbase::token(first).map(|token| token.owner), Some(4));
+		assert_eq!(Fanbase::token(other).map(|token| token.owner), Some(3));
+
+		// bought listings leave the index
+		assert_eq!(
+			Fanbase::get_floor_listings(&launch_token_id, 10),
+			vec![(other, 250), (second, 300)]
+		);
+		assert_noop!(
+			Fanbase::sweep(Origin::signed(4), launch_token_id, 2, 200, 10),
+			Error::<Test>::NothingToSweep
+		);
+	});
+}
+
+#[test]
+fn dutch_listing_price_declines_by_block_until_end_price() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		assert_noop!(
+			Fanbase::list_dutch(Origin::signed(2), token_id, 200, 1000, 8),
+			Error::<Test>::InvalidDutchListing
+		);
+		assert_noop!(
+			Fanbase::list_dutch(Origin::signed(2), token_id, 1000, 200, 0),
+			Error::<Test>::InvalidDutchListing
+		);
+		assert_ok!(Fanbase::list_dutch(Origin::signed(2), token_id, 1000, 200, 8));
+		assert_noop!(
+			Fanbase::list_dutch(Origin::signed(2), token_id, 1000, 200, 8),
+			Error::<Test>::TokenAlreadyListed
+		);
+
+		// halfway through the duration the price is halfway to the end price
+		System::set_block_number(5);
+		let token = Fanbase::token(token_id).unwrap();
+		assert_eq!(Fanbase::get_current_price(&token), Some(600));
+		assert_noop!(
+			Fanbase::buy(Origin::signed(3), token_id, 599, None),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		// price stays at the end price once the duration has passed
+		System::set_block_number(20);
+		assert_eq!(Fanbase::get_current_price(&token), Some(200));
+
+		let balance = Balances::free_balance(3);
+		assert_ok!(Fanbase::buy(Origin::signed(3), token_id, 600, None));
+		assert_eq!(Balances::free_balance(3), balance - 200);
+		assert_eq!(
+			Fanbase::token(token_id).map(|token| (token.owner, token.price)),
+			Some((3, None))
+		);
+		assert_eq!(Fanbase::dutch_listing(token_id), None);
+	});
+}
+
+#[test]
+fn price_change_ends_dutch_listing() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+		assert_ok!(Fanbase::list_dutch(Origin::signed(2), token_id, 1000, 200, 8));
+
+		System::set_block_number(5);
+		assert_ok!(Fanbase::set_price(Origin::signed(2), token_id, 500));
+		assert_eq!(Fanbase::dutch_listing(token_id), None);
+
+		System::set_block_number(20);
+		let token = Fanbase::token(token_id).unwrap();
+		assert_eq!(Fanbase::get_current_price(&token), Some(500));
+	});
+}
+
+#[test]
+fn milestones_release_escrowed_launch_proceeds() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		let alice = creator_id(b"alice");
+		let shares = |shares: &[u32]| -> Vec<Perbill> {
+			shares.iter().map(|share| Perbill::from_percent(*share)).collect()
+		};
+
+		assert_noop!(
+			Fanbase::set_milestones(
+				Origin::signed(1),
+				alice.clone(),
+				launch_token_id,
+				shares(&[40, 40]).try_into().unwrap()
+			),
+			Error::<Test>::InvalidMilestones
+		);
+		assert_ok!(Fanbase::set_milestones(
+			Origin::signed(1),
+			alice.clone(),
+			launch_token_id,
+			shares(&[40, 60]).try_into().unwrap()
+		));
+
+		// launch proceeds are held in escrow
+		let balance = Balances::free_balance(1);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		assert_ok!(Fanbase::launch_buy(Origin::signed(3), launch_token_id, 100));
+		assert_eq!(
+			Fanbase::milestone_escrow(launch_token_id).map(|escrow| escrow.escrowed),
+			Some(200)
+		);
+		assert_eq!(Fanbase::pending_proceeds(1), 0);
+		assert_noop!(
+			Fanbase::set_milestones(
+				Origin::signed(1),
+				alice.clone(),
+				launch_token_id,
+				Default::default()
+			),
+			Error::<Test>::MilestonesLocked
+		);
+
+		// approved milestones release their share
+		assert_noop!(
+			Fanbase::approve_milestone(Origin::signed(1), launch_token_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Fanbase::approve_milestone(Origin::root(), launch_token_id));
+		assert_eq!(Balances::free_balance(1), balance + 80);
+
+		// later sales are released up to the approved share
+		assert_ok!(Fanbase::launch_buy(Origin::signed(4), launch_token_id, 100));
+		assert_ok!(Fanbase::claim_milestone_proceeds(Origin::signed(1), alice, launch_token_id));
+		assert_eq!(Balances::free_balance(1), balance + 120);
+
+		assert_ok!(Fanbase::approve_milestone(Origin::root(), launch_token_id));
+		assert_eq!(Balances::free_balance(1), balance + 300);
+		assert_noop!(
+			Fanbase::approve_milestone(Origin::root(), launch_token_id),
+			Error::<Test>::MilestonesComplete
+		);
+	});
+}
+
+#[test]
+fn matching_pool_matches_launch_purchases_within_window_and_balance() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		let alice = creator_id(b"alice");
+		let ratio = Permill::from_percent(50);
+
+		assert_noop!(
+			Fanbase::fund_match(Origin::signed(3), alice.clone(), 120, Permill::zero(), 10),
+			Error::<Test>::InvalidMatchingPool
+		);
+		assert_noop!(
+			Fanbase::fund_match(Origin::signed(3), alice.clone(), 120, ratio, 1),
+			Error::<Test>::InvalidMatchingPool
+		);
+		assert_ok!(Fanbase::fund_match(Origin::signed(3), alice.clone(), 120, ratio, 10));
+		assert_eq!(Balances::reserved_balance(3), 120);
+
+		// purchases are matched at ratio until the pool runs dry
+		let balance = Balances::free_balance(1);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		assert_eq!(Balances::free_balance(1), balance + 100);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(4), launch_token_id, 100));
+		assert_eq!(Balances::free_balance(1), balance + 120);
+		assert_eq!(Fanbase::matching_pool(&alice).map(|pool| pool.remaining), Some(0));
+
+		assert_noop!(
+			Fanbase::close_match(Origin::signed(5), alice.clone()),
+			Error::<Test>::MatchingPoolActive
+		);
+
+		// purchases after the window are not matched
+		System::set_block_number(10);
+		assert_ok!(Fanbase::launch_buy(Origin::signed(5), launch_token_id, 100));
+		assert_eq!(Balances::free_balance(1), balance + 120);
+
+		assert_ok!(Fanbase::close_match(Origin::signed(5), alice.clone()));
+		assert_eq!(Fanbase::matching_pool(&alice), None);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 120);
+	});
+}
+
+#[test]
+fn closing_matching_pool_returns_remainder_to_sponsor() {
+	new_test_ext().execute_with(|| {
+		let launch_token_id = mint_launch(1, b"alice", 100, 10);
+		let alice = creator_id(b"alice");
+
+		assert_ok!(Fanbase::fund_match(
+			Origin::signed(3),
+			alice.clone(),
+			500,
+			Permill::from_percent(10),
+			10
+		));
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+
+		// new pool closes the ended pool
+		System::set_block_number(10);
+		assert_ok!(Fanbase::fund_match(
+			Origin::signed(4),
+			alice.clone(),
+			200,
+			Permill::from_percent(10),
+			20
+		));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 10);
+		assert_eq!(Fanbase::matching_pool(&alice).map(|pool| pool.sponsor), Some(4));
+	});
+}
+
+#[test]
+fn buy_now_settles_auction_and_returns_highest_bid() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		assert_noop!(
+			Fanbase::create_auction(
+				Origin::signed(2),
+				token_id,
+				100,
+				10,
+				None,
+				Some(200),
+				Some(150)
+			),
+			Error::<Test>::InvalidAuction
+		);
+		assert_ok!(Fanbase::create_auction(
+			Origin::signed(2),
+			token_id,
+			100,
+			10,
+			None,
+			Some(200),
+			Some(500)
+		));
+		assert_ok!(Fanbase::bid(Origin::signed(3), token_id, 200));
+		assert_eq!(Balances::reserved_balance(3), 200);
+
+		assert_noop!(
+			Fanbase::buy_now(Origin::signed(4), token_id, 499),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_ok!(Fanbase::buy_now(Origin::signed(4), token_id, 500));
+
+		// auction is settled and the outbid reserve returned
+		assert!(Fanbase::auction(token_id).is_none());
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(4));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(4), INITIAL_BALANCE - 500);
+
+		// auctions without a buy-now price can only be bid on
+		assert_ok!(Fanbase::create_auction(Origin::signed(4), token_id, 100, 10, None, None, None));
+		assert_noop!(
+			Fanbase::buy_now(Origin::signed(5), token_id, 1_000),
+			Error::<Test>::BuyNowUnavailable
+		);
+
+		// buy-now closes with bidding
+		assert_ok!(Fanbase::cancel_auction(Origin::signed(4), token_id));
+		assert_ok!(Fanbase::create_auction(
+			Origin::signed(4),
+			token_id,
+			100,
+			10,
+			None,
+			None,
+			Some(500)
+		));
+		System::set_block_number(11);
+		assert_noop!(
+			Fanbase::buy_now(Origin::signed(5), token_id, 500),
+			Error::<Test>::AuctionEnded
+		);
+	});
+}
+
+#[test]
+fn relist_from_auction_returns_bid_below_reserve_and_lists_token() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+		let launch_token_id = Fanbase::token(token_id).unwrap().launch_id;
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		let sold_token_id = Fanbase::token_ids_for_account(2)[1];
+
+		assert_ok!(Fanbase::create_auction(
+			Origin::signed(2),
+			token_id,
+			100,
+			10,
+			None,
+			Some(300),
+			None
+		));
+		assert_ok!(Fanbase::create_auction(
+			Origin::signed(2),
+			sold_token_id,
+			100,
+			10,
+			None,
+			Some(300),
+			None
+		));
+		assert_ok!(Fanbase::bid(Origin::signed(3), token_id, 200));
+		assert_ok!(Fanbase::bid(Origin::signed(4), sold_token_id, 300));
+
+		assert_noop!(
+			Fanbase::relist_from_auction(Origin::signed(2), token_id, None),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Fanbase::relist_from_auction(Origin::signed(3), token_id, None),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Fanbase::relist_from_auction(Origin::signed(2), sold_token_id, None),
+			Error::<Test>::AuctionReserveMet
+		);
+
+		// bid below reserve is returned and token is listed at the reserve price
+		assert_ok!(Fanbase::relist_from_auction(Origin::signed(2), token_id, None));
+		assert!(Fanbase::auction(token_id).is_none());
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(
+			Fanbase::token(token_id).map(|token| (token.owner, token.price)),
+			Some((2, Some(300)))
+		);
+
+		// relisted token can be bought at its listing price
+		assert_ok!(Fanbase::buy(Origin::signed(3), token_id, 300, None));
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(3));
+	});
+}
+
+#[test]
+fn relist_from_auction_lists_at_given_price() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		assert_ok!(Fanbase::create_auction(Origin::signed(2), token_id, 100, 10, None, None, None));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Fanbase::relist_from_auction(Origin::signed(2), token_id, Some(0)),
+			Error::<Test>::PriceTooLow
+		);
+		assert_ok!(Fanbase::relist_from_auction(Origin::signed(2), token_id, Some(250)));
+		assert_eq!(Fanbase::token(token_id).and_then(|token| token.price), Some(250));
+	});
+}
+
+#[test]
+fn airdrop_to_snapshot_is_issued_by_work_queue_in_idle_time() {
+	new_test_ext().execute_with(|| {
+		let alice = creator_id(b"alice");
+		let mut metadata = launch_metadata(10);
+		metadata.gift_supply = 2;
+		assert_ok!(Fanbase::create_account(Origin::signed(1), alice.clone()));
+		assert_ok!(Fanbase::mint(Origin::signed(1), alice.clone(), 100, metadata));
+		let launch_token_id = *Fanbase::launch_token_ids_for_creator(&alice).last().unwrap();
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		assert_ok!(Fanbase::launch_buy(Origin::signed(3), launch_token_id, 100));
+
+		let snapshot_id = Fanbase::snapshot_nonce();
+		assert_ok!(Fanbase::take_snapshot(Origin::signed(1), alice.clone(), launch_token_id));
+		assert_ok!(Fanbase::airdrop_to_snapshot(
+			Origin::signed(1),
+			alice.clone(),
+			launch_token_id,
+			snapshot_id
+		));
+		let work_id = Fanbase::work_queue()[0];
+
+		// a second airdrop exceeds the gift supply
+		assert_ok!(Fanbase::launch_buy(Origin::signed(4), launch_token_id, 100));
+		assert_ok!(Fanbase::take_snapshot(Origin::signed(1), alice.clone(), launch_token_id));
+		assert_noop!(
+			Fanbase::airdrop_to_snapshot(
+				Origin::signed(1),
+				alice.clone(),
+				launch_token_id,
+				snapshot_id + 1
+			),
+			Error::<Test>::GiftSupplyExhausted
+		);
+
+		// idle time of a single step airdrops to one holder
+		Fanbase::on_idle(1, Fanbase::airdrop_step_weight());
+		assert_eq!(Fanbase::work(work_id).map(|work| (work.progress, work.total)), Some((1, 2)));
+		System::assert_has_event(crate::Event::<Test>::WorkProgressed(work_id, 1, 2).into());
+		assert_eq!(
+			Fanbase::held_tokens(launch_token_id, 2) + Fanbase::held_tokens(launch_token_id, 3),
+			3
+		);
+
+		// no idle time makes no progress
+		Fanbase::on_idle(2, 0);
+		assert_eq!(Fanbase::work(work_id).map(|work| work.progress), Some(1));
+
+		Fanbase::on_idle(3, 10 * Fanbase::airdrop_step_weight());
+		assert!(Fanbase::work(work_id).is_none());
+		assert!(Fanbase::work_queue().is_empty());
+		assert_eq!(Fanbase::held_tokens(launch_token_id, 2), 2);
+		assert_eq!(Fanbase::held_tokens(launch_token_id, 3), 2);
+		assert_eq!(Fanbase::held_tokens(launch_token_id, 4), 1);
+		System::assert_has_event(crate::Event::<Test>::WorkCompleted(work_id).into());
+	});
+}
+
+#[test]
+fn call_filter_disables_calls() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		BurnDisabled::set(true);
+		assert_noop!(Fanbase::burn(Origin::signed(2), token_id), Error::<Test>::CallDisabled);
+
+		// other calls are not affected
+		assert_ok!(Fanbase::transfer(Origin::signed(2), token_id, 3, None));
+
+		BurnDisabled::set(false);
+		assert_ok!(Fanbase::burn(Origin::signed(3), token_id));
+		assert!(Fanbase::token(token_id).is_none());
+	});
+}
+
+#[test]
+fn bridged_token_is_locked_until_released_by_proof() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+		let dest_address: BridgeAddress = vec![7; 20].try_into().unwrap();
+		let proof = |token_id: TokenId, receiver: u64| -> BridgeProofOf<Test> {
+			(token_id, receiver).encode().try_into().unwrap()
+		};
+		assert_ok!(Fanbase::list(Origin::signed(2), token_id, 500));
+
+		assert_noop!(
+			Fanbase::bridge_lock(
+				Origin::signed(2),
+				token_id,
+				BRIDGE_CHAIN + 1,
+				dest_address.clone()
+			),
+			Error::<Test>::UnsupportedBridgeChain
+		);
+		assert_noop!(
+			Fanbase::bridge_lock(Origin::signed(3), token_id, BRIDGE_CHAIN, dest_address.clone()),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Fanbase::bridge_lock(
+			Origin::signed(2),
+			token_id,
+			BRIDGE_CHAIN,
+			dest_address.clone()
+		));
+
+		// token is unlisted and held in custody
+		assert_eq!(Fanbase::token(token_id).and_then(|token| token.price), None);
+		assert_eq!(
+			Fanbase::bridge_custody(token_id).map(|custody| (custody.owner, custody.dest_address)),
+			Some((2, dest_address))
+		);
+		assert_noop!(
+			Fanbase::transfer(Origin::signed(2), token_id, 3, None),
+			Error::<Test>::TokenLocked
+		);
+		assert_noop!(Fanbase::list(Origin::signed(2), token_id, 500), Error::<Test>::TokenLocked);
+
+		// proof releases token to the receiver it names
+		assert_noop!(
+			Fanbase::bridge_unlock(Origin::signed(4), token_id, proof(token_id + 1, 3)),
+			Error::<Test>::InvalidBridgeProof
+		);
+		assert_ok!(Fanbase::bridge_unlock(Origin::signed(4), token_id, proof(token_id, 3)));
+		assert!(Fanbase::bridge_custody(token_id).is_none());
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(3));
+		assert_noop!(
+			Fanbase::bridge_unlock(Origin::signed(4), token_id, proof(token_id, 3)),
+			Error::<Test>::TokenNotBridged
+		);
+
+		// released token can be transferred again
+		assert_ok!(Fanbase::transfer(Origin::signed(3), token_id, 2, None));
+	});
+}
+
+#[test]
+fn prices_below_minimum_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = creator_id(b"alice");
+		let token_id = buy_token(1);
+		let launch_token_id = Fanbase::token(token_id).unwrap().launch_id;
+
+		assert_noop!(
+			Fanbase::mint(Origin::signed(1), alice.clone(), 0, launch_metadata(10)),
+			Error::<Test>::PriceTooLow
+		);
+		assert_noop!(
+			Fanbase::set_launch_price(Origin::signed(1), alice.clone(), launch_token_id, 0),
+			Error::<Test>::PriceTooLow
+		);
+		assert_noop!(Fanbase::list(Origin::signed(2), token_id, 0), Error::<Test>::PriceTooLow);
+
+		assert_ok!(Fanbase::list(Origin::signed(2), token_id, 1));
+		assert_noop!(
+			Fanbase::set_price(Origin::signed(2), token_id, 0),
+			Error::<Test>::PriceTooLow
+		);
+	});
+}
+
+#[test]
+fn reference_launch_price_is_not_checked_against_minimum() {
+	new_test_ext().execute_with(|| {
+		let alice = creator_id(b"alice");
+		let mut metadata = launch_metadata(10);
+		metadata.price_mode = PriceMode::Reference;
+		assert_ok!(Fanbase::create_account(Origin::signed(1), alice.clone()));
+		assert_ok!(Fanbase::mint(Origin::signed(1), alice, 0, metadata));
+	});
+}
+
+#[test]
+fn successor_claims_creator_after_owner_is_inactive() {
+	new_test_ext().execute_with(|| {
+		let alice = creator_id(b"alice");
+		assert_ok!(Fanbase::create_account(Origin::signed(1), alice.clone()));
+
+		assert_noop!(
+			Fanbase::set_successor(Origin::signed(1), alice.clone(), Some((2, 9))),
+			Error::<Test>::InvalidSuccessionThreshold
+		);
+		assert_ok!(Fanbase::set_successor(Origin::signed(1), alice.clone(), Some((2, 10))));
+		assert_eq!(Fanbase::last_active(1), Some(1));
+
+		// only the successor can claim
+		System::set_block_number(8);
+		assert_noop!(
+			Fanbase::claim_succession(Origin::signed(3), alice.clone()),
+			Error::<Test>::SuccessionNotFound
+		);
+
+		// any signed call of the owner resets the threshold
+		assert_ok!(Fanbase::set_payout_account(Origin::signed(1), None));
+		System::set_block_number(11);
+		assert_noop!(
+			Fanbase::claim_succession(Origin::signed(2), alice.clone()),
+			Error::<Test>::OwnerStillActive
+		);
+
+		System::set_block_number(18);
+		assert_ok!(Fanbase::claim_succession(Origin::signed(2), alice.clone()));
+		assert_eq!(Fanbase::creator(&alice).and_then(|creator| creator.owner), Some(2));
+
+		// succession does not carry over to the new owner
+		assert!(Fanbase::succession(&alice).is_none());
+	});
+}
+
+#[test]
+fn succession_requires_tracked_owner_and_is_cleared_on_drop() {
+	new_test_ext().execute_with(|| {
+		let alice = creator_id(b"alice");
+		assert_ok!(Fanbase::create_account(Origin::signed(1), alice.clone()));
+		assert_ok!(Fanbase::set_successor(Origin::signed(1), alice.clone(), Some((2, 10))));
+
+		// owners whose activity is not tracked are never inactive
+		crate::LastActive::<Test>::remove(1);
+		System::set_block_number(100);
+		assert_noop!(
+			Fanbase::claim_succession(Origin::signed(2), alice.clone()),
+			Error::<Test>::OwnerStillActive
+		);
+
+		assert_ok!(Fanbase::drop_account(Origin::signed(1), alice.clone()));
+		assert!(Fanbase::succession(&alice).is_none());
+	});
+}
+
+#[test]
+fn quote_is_executed_once_with_seller_signature() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+		let quote =
+			PriceQuote { token_id, seller: 2, buyer: 3, price: 200, expires_at: 10, nonce: 0 };
+
+		// signature must cover the domain tag and genesis hash
+		let untagged = TestSignature(2, (System::block_hash(0), &quote).encode());
+		assert_noop!(
+			Fanbase::execute_quote(Origin::signed(3), quote.clone(), untagged),
+			Error::<Test>::InvalidQuoteSignature
+		);
+		assert_noop!(
+			Fanbase::execute_quote(Origin::signed(4), quote.clone(), sign_quote(&quote)),
+			Error::<Test>::QuoteNotForAccount
+		);
+
+		assert_ok!(Fanbase::execute_quote(Origin::signed(3), quote.clone(), sign_quote(&quote)));
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(3));
+		assert_eq!(Fanbase::pending_proceeds(2), 200);
+
+		// executed quote cannot be replayed
+		assert_noop!(
+			Fanbase::execute_quote(Origin::signed(3), quote.clone(), sign_quote(&quote)),
+			Error::<Test>::QuoteRevoked
+		);
+	});
+}
+
+#[test]
+fn quote_below_min_price_and_expired_quote_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		let free = PriceQuote { token_id, seller: 2, buyer: 3, price: 0, expires_at: 10, nonce: 0 };
+		assert_noop!(
+			Fanbase::execute_quote(Origin::signed(3), free.clone(), sign_quote(&free)),
+			Error::<Test>::PriceTooLow
+		);
+
+		let quote = PriceQuote { price: 200, ..free };
+		System::set_block_number(10);
+		assert_noop!(
+			Fanbase::execute_quote(Origin::signed(3), quote.clone(), sign_quote(&quote)),
+			Error::<Test>::QuoteExpired
+		);
+	});
+}
+
+#[test]
+fn accepted_offer_credits_proceeds_of_seller() {
+	new_test_ext().execute_with(|| {
+		let token_id = buy_token(100);
+
+		assert_noop!(
+			Fanbase::make_offer(Origin::signed(3), token_id, 0),
+			Error::<Test>::PriceTooLow
+		);
+		assert_noop!(
+			Fanbase::make_offer(Origin::signed(2), token_id, 150),
+			Error::<Test>::TransferToSelf
+		);
+
+		assert_ok!(Fanbase::make_offer(Origin::signed(3), token_id, 150));
+		assert!(Balances::reserved_balance(3) >= 150);
+
+		assert_ok!(Fanbase::accept_offer(Origin::signed(2), token_id, 3));
+		assert_eq!(Fanbase::token(token_id).map(|token| token.owner), Some(3));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Fanbase::pending_proceeds(2), 150);
+		assert!(Fanbase::offer(token_id, 3).is_none());
+	});
+}
+
+#[test]
+fn burn_redeems_reserve_to_owner() {
+	new_test_ext().execute_with(|| {
+		let mut metadata = launch_metadata(10);
+		metadata.redeemable = Permill::from_percent(10);
+		assert_ok!(Fanbase::create_account(Origin::signed(1), creator_id(b"alice")));
+		assert_ok!(Fanbase::mint(Origin::signed(1), creator_id(b"alice"), 100, metadata));
+		let launch_token_id = Fanbase::launch_token_ids_for_creator(creator_id(b"alice"))[0];
+
+		assert_ok!(Fanbase::launch_buy(Origin::signed(2), launch_token_id, 100));
+		let token_id = Fanbase::token_ids_for_account(2)[0];
+
+		// redeemable share is held in reserve and not credited to the creator
+		assert_eq!(Fanbase::redeemable_reserve(token_id), Some(10));
+		assert_eq!(Fanbase::pending_proceeds(1), 90);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 100);
+
+		assert_ok!(Fanbase::burn(Origin::signed(2), token_id));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 90);
+		assert!(Fanbase::redeemable_reserve(token_id).is_none());
+		assert!(Fanbase::token(token_id).is_none());
+	});
+}
+
+#[test]
+fn proceeds_are_withdrawn_from_pallet_account() {
+	new_test_ext().execute_with(|| {
+		buy_token(100);
+		assert_eq!(Fanbase::pending_proceeds(1), 100);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+
+		assert_ok!(Fanbase::withdraw_proceeds(Origin::signed(1)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 100);
+		assert_eq!(Fanbase::pending_proceeds(1), 0);
+		assert_eq!(Balances::free_balance(Fanbase::pallet_account()), Balances::minimum_balance());
+	});
+}
@@ -0,0 +1,290 @@
+use crate::types::{BridgeCustody, ChainId, CreatorId, LaunchTokenMetadata, TokenId, TokenSupply};
+use codec::Encode;
+use frame_support::{
+	pallet_prelude::{DispatchError, DispatchResult, PhantomData},
+	sp_io::hashing::blake2_128,
+	traits::{Currency, ExistenceRequirement, WithdrawReasons},
+};
+
+/// Verify accounts have an on-chain identity.
+pub trait VerifyIdentity<AccountId> {
+	/// Returns `true` if account has a verified identity.
+	fn has_identity(account: &AccountId) -> bool;
+}
+
+/// Allow any account.
+impl<AccountId> VerifyIdentity<AccountId> for () {
+	fn has_identity(_account: &AccountId) -> bool {
+		true
+	}
+}
+
+/// Restrict token transfers between accounts.
+pub trait EnsureTransferAllowed<AccountId, TokenId> {
+	/// Returns `true` if token can be transferred from `from` to `to`.
+	///
+	/// For launch transfers `from` is the launch token creator's owner and `token_id` is the launch
+	/// token id.
+	fn is_transfer_allowed(from: &AccountId, to: &AccountId, token_id: &TokenId) -> bool;
+}
+
+/// Allow all transfers.
+impl<AccountId, TokenId> EnsureTransferAllowed<AccountId, TokenId> for () {
+	fn is_transfer_allowed(_from: &AccountId, _to: &AccountId, _token_id: &TokenId) -> bool {
+		true
+	}
+}
+
+/// Convert prices denominated in a reference unit into native currency.
+pub trait PriceOracle<Balance> {
+	/// Returns native currency amount for reference price, or `None` if no rate is available.
+	fn to_native(reference_price: Balance) -> Option<Balance>;
+}
+
+/// No rate available, reference priced launch tokens cannot be bought.
+impl<Balance> PriceOracle<Balance> for () {
+	fn to_native(_reference_price: Balance) -> Option<Balance> {
+		None
+	}
+}
+
+/// Verify proofs releasing tokens locked in bridge custody, e.g. backed by a bridge pallet or the
+/// attestations of a bridge operator.
+pub trait BridgeVerifier<AccountId, BlockNumber> {
+	/// Returns `true` if tokens can be bridged to chain.
+	fn supports_chain(dest_chain: ChainId) -> bool;
+
+	/// Returns the account to release token to if proof shows the token was returned from the
+	/// destination chain of its custody.
+	fn verify_unlock(
+		token_id: &TokenId,
+		custody: &BridgeCustody<AccountId, BlockNumber>,
+		proof: &[u8],
+	) -> Option<AccountId>;
+}
+
+/// Bridging disabled, no chain is supported.
+impl<AccountId, BlockNumber> BridgeVerifier<AccountId, BlockNumber> for () {
+	fn supports_chain(_dest_chain: ChainId) -> bool {
+		false
+	}
+
+	fn verify_unlock(
+		_token_id: &TokenId,
+		_custody: &BridgeCustody<AccountId, BlockNumber>,
+		_proof: &[u8],
+	) -> Option<AccountId> {
+		None
+	}
+}
+
+/// Create signatures and bridge proofs accepted by the runtime in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+	/// Returns account of a new off-chain signer.
+	fn signer() -> AccountId;
+
+	/// Returns signature of payload by signer.
+	fn sign(signer: &AccountId, payload: &[u8]) -> Signature;
+
+	/// Returns a chain supported by the bridge verifier, or `None` if bridging is disabled.
+	fn bridge_chain() -> Option<ChainId>;
+
+	/// Returns proof releasing token from bridge custody to receiver, or `None` if bridging is
+	/// disabled.
+	fn bridge_unlock_proof(
+		token_id: &TokenId,
+		receiver: &AccountId,
+	) -> Option<frame_support::sp_std::vec::Vec<u8>>;
+}
+
+/// Generate ids of new tokens.
+///
+/// Launch token ids are always sequential since work items walk launch tokens by id up to the
+/// launch token nonce. Generated ids are checked against existing tokens, issuance fails on
+/// collisions.
+pub trait TokenIdGenerator {
+	/// Returns id of the next token issued from launch token, `nonce` counts tokens issued
+	/// including it and `issued` counts tokens previously issued from the launch token.
+	fn next_token_id(
+		nonce: TokenId,
+		launch_token_id: &TokenId,
+		issued: TokenSupply,
+	) -> Option<TokenId>;
+}
+
+/// Sequential token ids across all launch tokens.
+impl TokenIdGenerator for () {
+	fn next_token_id(
+		nonce: TokenId,
+		_launch_token_id: &TokenId,
+		_issued: TokenSupply,
+	) -> Option<TokenId> {
+		Some(nonce)
+	}
+}
+
+/// Token ids counted per launch token, with the launch token id in the upper 64 bits and the
+/// issuance counter of the launch token in the lower 64 bits.
+pub struct PerLaunchTokenIds;
+
+impl TokenIdGenerator for PerLaunchTokenIds {
+	fn next_token_id(
+		_nonce: TokenId,
+		launch_token_id: &TokenId,
+		issued: TokenSupply,
+	) -> Option<TokenId> {
+		let launch_token_id = u64::try_from(*launch_token_id).ok()?;
+
+		Some((TokenId::from(launch_token_id) << 64) | TokenId::from(issued).checked_add(1)?)
+	}
+}
+
+/// Token ids derived from the hash of launch token id and issuance nonce, so ids do not reveal
+/// issuance order.
+pub struct HashedTokenIds;
+
+impl TokenIdGenerator for HashedTokenIds {
+	fn next_token_id(
+		nonce: TokenId,
+		launch_token_id: &TokenId,
+		_issued: TokenSupply,
+	) -> Option<TokenId> {
+		Some(TokenId::from_le_bytes(blake2_128(&(launch_token_id, nonce).encode())))
+	}
+}
+
+/// Notify other pallets of successful purchases.
+pub trait OnPurchase<AccountId, Balance> {
+	/// Called after buyer paid amount for a token of creator account.
+	fn on_purchase(buyer: &AccountId, creator_id: &CreatorId, amount: Balance);
+}
+
+/// Do nothing.
+impl<AccountId, Balance> OnPurchase<AccountId, Balance> for () {
+	fn on_purchase(_buyer: &AccountId, _creator_id: &CreatorId, _amount: Balance) {}
+}
+
+/// Move funds for purchases, proceeds, escrows and fees.
+pub trait Payment<AccountId, Balance> {
+	/// Pay amount from `from` to `to`.
+	fn pay(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		existence: ExistenceRequirement,
+	) -> DispatchResult;
+
+	/// Charge fee from account.
+	fn charge_fee(from: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+/// Pay directly in currency, fees are burned.
+pub struct CurrencyPayment<C>(PhantomData<C>);
+
+impl<AccountId, C: Currency<AccountId>> Payment<AccountId, C::Balance> for CurrencyPayment<C> {
+	fn pay(
+		from: &AccountId,
+		to: &AccountId,
+		amount: C::Balance,
+		existence: ExistenceRequirement,
+	) -> DispatchResult {
+		C::transfer(from, to, amount, existence)
+	}
+
+	fn charge_fee(from: &AccountId, amount: C::Balance) -> DispatchResult {
+		C::withdraw(from, amount, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive).map(|_| ())
+	}
+}
+
+/// Resolve creator ids, as domain name elements, to owner accounts and back.
+pub trait CreatorLookup<AccountId> {
+	/// Returns owner account of creator id, sub-creators resolve to the owner of their parent.
+	///
+	/// Expired creator accounts do not resolve.
+	fn lookup(creator_id: &CreatorId) -> Option<AccountId>;
+
+	/// Returns primary creator id of account.
+	fn reverse_lookup(account: &AccountId) -> Option<CreatorId>;
+}
+
+/// Programmatic access to fanbase tokens for other pallets.
+///
+/// Mints and issuance are rejected for banned creator accounts, mints validate price and metadata
+/// like the `mint` call and issuance checks the launch token state and supply. Transfers check
+/// ownership, token locks, transfer lockups, enforced royalties and the runtime `TransferFilter`,
+/// burns check ownership and token locks. Creator management permissions, account blocks and the
+/// `CallFilter` are left to the calling pallet.
+pub trait FanbaseInterface<AccountId, Balance> {
+	/// Mint launch token for creator account, counted against the creator's mint quota.
+	///
+	/// Deposit for on-chain content is reserved from the creator's owner.
+	///
+	/// Returns the launch token id.
+	fn mint(
+		creator_id: CreatorId,
+		price: Balance,
+		metadata: LaunchTokenMetadata,
+	) -> Result<TokenId, DispatchError>;
+
+	/// Issue token from the public supply of launch token to receiver without payment.
+	///
+	/// Returns the issued token id.
+	fn issue(receiver: &AccountId, launch_token_id: &TokenId) -> Result<TokenId, DispatchError>;
+
+	/// Transfer token owned by `owner` to receiver.
+	fn transfer(owner: &AccountId, receiver: &AccountId, token_id: &TokenId) -> DispatchResult;
+
+	/// Burn token owned by `owner`, refunding its redeemable reserve.
+	fn burn(owner: &AccountId, token_id: &TokenId) -> DispatchResult;
+
+	/// Returns owner of token if it exists.
+	fn owner_of(token_id: &TokenId) -> Option<AccountId>;
+}
+
+/// Require accounts to have an identity judged `Reasonable` or `KnownGood` by a registrar in
+/// `pallet_identity`.
+#[cfg(feature = "identity")]
+pub struct JudgedIdentity<T>(frame_support::pallet_prelude::PhantomData<T>);
+
+#[cfg(feature = "identity")]
+impl<T: pallet_identity::Config> VerifyIdentity<T::AccountId> for JudgedIdentity<T> {
+	fn has_identity(account: &T::AccountId) -> bool {
+		use pallet_identity::Judgement;
+
+		pallet_identity::Pallet::<T>::identity(account).map_or(false, |registration| {
+			registration.judgements.iter().any(|(_, judgement)| {
+				matches!(judgement, Judgement::Reasonable | Judgement::KnownGood)
+			})
+		})
+	}
+}
+
+/// Award loyalty points to buyers by minting a `pallet_assets` asset, `Rate` points per unit of
+/// currency spent.
+///
+/// Points that cannot be minted (e.g. below the asset's minimum balance) are skipped, purchases never
+/// fail because of this hook.
+#[cfg(feature = "loyalty")]
+pub struct AssetLoyaltyPoints<T, AssetId, Rate>(PhantomData<(T, AssetId, Rate)>);
+
+#[cfg(feature = "loyalty")]
+impl<T, AssetId, Rate, Balance> OnPurchase<T::AccountId, Balance>
+	for AssetLoyaltyPoints<T, AssetId, Rate>
+where
+	T: pallet_assets::Config,
+	AssetId: frame_support::traits::Get<T::AssetId>,
+	Rate: frame_support::traits::Get<frame_support::sp_runtime::Permill>,
+	Balance: Into<T::Balance>,
+{
+	fn on_purchase(buyer: &T::AccountId, _creator_id: &CreatorId, amount: Balance) {
+		use frame_support::{sp_runtime::traits::Zero, traits::tokens::fungibles::Mutate};
+
+		let points = Rate::get() * amount.into();
+		if points.is_zero() {
+			return
+		}
+
+		let _ = pallet_assets::Pallet::<T>::mint_into(AssetId::get(), buyer, points);
+	}
+}
@@ -0,0 +1,27 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::{CollaboratorRole, TokenId};
+
+/// Privileged action performed on behalf of a creator account
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CreatorAction {
+	/// Launch token minted
+	Mint,
+	/// Launch price changed
+	PriceChange,
+	/// Token gifted from the gift supply
+	Gift,
+}
+
+/// Entry of the creator activity log
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CreatorActivity<T: Config> {
+	pub actor: T::AccountId,
+	/// Collaborator role of actor, `None` if performed by the creator owner
+	pub role: Option<CollaboratorRole>,
+	pub action: CreatorAction,
+	pub launch_token_id: TokenId,
+	pub at: T::BlockNumber,
+}
@@ -0,0 +1,18 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::{CreatorId, TokenId};
+
+pub type SnapshotId = u32;
+
+/// Holders of a launch token recorded at a block
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Snapshot<T: Config> {
+	/// Creator account that took the snapshot
+	pub creator: CreatorId,
+	pub launch_id: TokenId,
+	/// Number of recorded holders
+	pub holders: u32,
+	pub taken_at: T::BlockNumber,
+}
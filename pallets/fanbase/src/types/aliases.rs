@@ -1,5 +1,7 @@
 use crate::Config;
-use frame_support::traits::Currency;
+use frame_support::{traits::Currency, BoundedVec};
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type MemoOf<T> = BoundedVec<u8, <T as Config>::MaxMemoLength>;
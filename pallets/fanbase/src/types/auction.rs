@@ -0,0 +1,34 @@
+use crate::Config;
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+use super::aliases::BalanceOf;
+
+/// Minimum raise of a bid over the highest bid
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BidIncrement<Balance> {
+	/// Fixed amount over the highest bid
+	Absolute(Balance),
+	/// Share of the highest bid
+	Percent(Permill),
+}
+
+pub type BidIncrementOf<T> = BidIncrement<BalanceOf<T>>;
+
+/// English auction of token with bids reserved from bidders
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Auction<T: Config> {
+	pub seller: T::AccountId,
+	/// Minimum amount of the first bid
+	pub min_bid: BalanceOf<T>,
+	/// Minimum raise of each bid over the highest bid
+	pub min_increment: BidIncrementOf<T>,
+	/// Lowest highest bid the token is sold at, lower bids are returned on settlement
+	pub reserve: Option<BalanceOf<T>>,
+	/// Price settling the auction immediately while bidding is open
+	pub buy_now: Option<BalanceOf<T>>,
+	/// Highest bidder and their reserved bid
+	pub highest_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	/// Block at which bidding closes and the auction can be settled
+	pub ends_at: T::BlockNumber,
+}
@@ -0,0 +1,28 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+/// Id of a chain tokens are bridged to, e.g. an EVM chain id
+pub type ChainId = u64;
+
+/// Address of the token holder on the destination chain limited to 32 bytes
+pub type BridgeAddress = BoundedVec<u8, ConstU32<32>>;
+
+/// Proof submitted to release a bridged token, verified by the configured bridge verifier
+pub type BridgeProofOf<T> = BoundedVec<u8, <T as Config>::MaxBridgeProofLength>;
+
+/// Token held in bridge custody while it lives on another chain
+///
+/// The token stays recorded with its owner but cannot be transferred, listed or sold until it is
+/// released.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BridgeCustody<AccountId, BlockNumber> {
+	/// Account that locked the token
+	pub owner: AccountId,
+	pub dest_chain: ChainId,
+	pub dest_address: BridgeAddress,
+	/// Block at which the token was locked
+	pub locked_at: BlockNumber,
+}
+
+pub type BridgeCustodyOf<T> =
+	BridgeCustody<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
@@ -0,0 +1,27 @@
+use frame_support::pallet_prelude::*;
+
+use super::{aliases::BalanceOf, CreatorId, TokenId};
+
+/// Calendar entry title limited to 64 bytes
+pub type CalendarTitle = BoundedVec<u8, ConstU32<64>>;
+
+/// Upcoming drop of a creator account registered on the launch calendar
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CalendarEntry<AccountId, Balance, BlockNumber> {
+	pub creator: CreatorId,
+	pub title: CalendarTitle,
+	/// Block at which the drop starts
+	pub starts_at: BlockNumber,
+	/// Launch token of the drop once minted
+	pub launch_id: Option<TokenId>,
+	/// Account the deposit is reserved from
+	pub depositor: AccountId,
+	/// Amount reserved from depositor until the entry is removed
+	pub deposit: Balance,
+}
+
+pub type CalendarEntryOf<T> = CalendarEntry<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
@@ -0,0 +1,10 @@
+use frame_support::pallet_prelude::*;
+
+/// Role of a collaborator on a creator account
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CollaboratorRole {
+	/// Can manage launch tokens and dispatch calls as a keyless creator account
+	Admin,
+	/// Can manage launch tokens (mint, gift and update launch prices)
+	Manager,
+}
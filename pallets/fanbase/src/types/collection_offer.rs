@@ -0,0 +1,31 @@
+use crate::Config;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
+
+use super::aliases::BalanceOf;
+
+/// Offer for any token of a launch token, reserved from the buyer for the remaining quantity
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CollectionOffer<T: Config> {
+	/// Amount paid per token
+	pub amount: BalanceOf<T>,
+	/// Tokens left to buy
+	pub quantity: u32,
+	/// Block at which the offer can no longer be accepted
+	pub expires_at: T::BlockNumber,
+	/// Amount reserved from buyer on top of the offer amount, returned once the offer is filled
+	/// or cancelled
+	pub deposit: BalanceOf<T>,
+}
+
+impl<T: Config> CollectionOffer<T> {
+	/// Amount reserved for the remaining quantity, excluding the deposit.
+	pub fn reserved(&self) -> BalanceOf<T> {
+		self.amount.saturating_mul(self.quantity.into())
+	}
+
+	/// Returns `true` if the offer can no longer be accepted.
+	pub fn is_expired(&self) -> bool {
+		frame_system::Pallet::<T>::block_number() >= self.expires_at
+	}
+}
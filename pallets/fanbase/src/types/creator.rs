@@ -4,16 +4,32 @@ use frame_support::pallet_prelude::*;
 /// CreatorId will represent a domain name element hence is restricted to max 63 bytes
 pub type CreatorId = BoundedVec<u8, ConstU32<63>>;
 
+/// Separator between parent and sub-creator ids
+pub const SUB_CREATOR_SEPARATOR: u8 = b'/';
+
 #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct Creator<T: Config> {
 	pub id: CreatorId,
 	pub owner: Option<T::AccountId>,
+	/// Block at which the creator registration expires
+	pub expires_at: T::BlockNumber,
+	/// Parent creator of a sub-creator, sub-creators resolve ownership through their parent
+	pub parent: Option<CreatorId>,
 }
 
 impl<T: Config> Creator<T> {
-	pub fn new(id: CreatorId, owner: T::AccountId) -> Self {
-		Self { id, owner: Some(owner) }
+	pub fn new(id: CreatorId, owner: T::AccountId, expires_at: T::BlockNumber) -> Self {
+		Self { id, owner: Some(owner), expires_at, parent: None }
+	}
+
+	pub fn new_sub(id: CreatorId, parent: CreatorId, expires_at: T::BlockNumber) -> Self {
+		Self { id, owner: None, expires_at, parent: Some(parent) }
+	}
+
+	/// Returns `true` if creator is a sub-creator
+	pub fn is_sub_creator(&self) -> bool {
+		self.parent.is_some()
 	}
 
 	/// Remove owner from creator by setting owner field to `None`
@@ -21,3 +37,13 @@ impl<T: Config> Creator<T> {
 		self.owner = None
 	}
 }
+
+/// Creator account handover awaiting acceptance by the new owner
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CreatorTransfer<T: Config> {
+	pub from: T::AccountId,
+	pub to: T::AccountId,
+	/// Block at which the handover can no longer be accepted
+	pub expires_at: T::BlockNumber,
+}
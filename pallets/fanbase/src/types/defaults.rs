@@ -0,0 +1,12 @@
+use crate::Config;
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+/// Creator settings inherited by new launch tokens
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct LaunchDefaults<T: Config> {
+	/// Share of secondary sale prices paid to the launch token beneficiary
+	pub royalty: Permill,
+	/// Account receiving proceeds of new launch tokens
+	pub beneficiary: Option<T::AccountId>,
+}
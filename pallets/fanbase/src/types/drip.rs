@@ -0,0 +1,30 @@
+use crate::Config;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{UniqueSaturatedInto, Zero},
+};
+
+use super::TokenSupply;
+
+/// Schedule unlocking purchasable supply of a launch token in equal amounts per period
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DripSchedule<T: Config> {
+	/// Supply unlocked per period
+	pub amount: TokenSupply,
+	/// Length of a period in blocks
+	pub period: T::BlockNumber,
+	/// Block at which the first period starts
+	pub start: T::BlockNumber,
+}
+
+impl<T: Config> DripSchedule<T> {
+	/// Supply unlocked by block, the first period unlocks on start.
+	pub fn unlocked(&self, now: T::BlockNumber) -> TokenSupply {
+		if now < self.start || self.period.is_zero() {
+			return 0
+		}
+		let periods: TokenSupply = ((now - self.start) / self.period).unique_saturated_into();
+		self.amount.saturating_mul(periods.saturating_add(1))
+	}
+}
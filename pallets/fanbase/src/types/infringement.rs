@@ -0,0 +1,15 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Claim by a right-holder that a launch token infringes their work
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct InfringementClaim<T: Config> {
+	pub claimant: T::AccountId,
+	/// Hash of evidence kept off-chain
+	pub evidence_hash: T::Hash,
+	/// Amount reserved from claimant, returned if upheld and slashed if dismissed
+	pub deposit: BalanceOf<T>,
+}
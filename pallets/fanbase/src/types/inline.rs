@@ -0,0 +1,20 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Max length of content stored on-chain with a launch token, 8KB e.g. for SVG or ASCII art
+pub type MaxInlineData = ConstU32<8192>;
+
+/// Content stored on-chain with a launch token
+pub type InlineData = BoundedVec<u8, MaxInlineData>;
+
+/// On-chain content of launch token, interpreted with the launch token's mime type
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct InlineContent<T: Config> {
+	pub data: InlineData,
+	pub depositor: T::AccountId,
+	/// Amount reserved from depositor while the content is stored
+	pub deposit: BalanceOf<T>,
+}
@@ -0,0 +1,14 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::{aliases::BalanceOf, TokenId};
+
+/// Queued launch purchase settled at the start of the next block
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct PurchaseIntent<T: Config> {
+	pub buyer: T::AccountId,
+	pub launch_token_id: TokenId,
+	/// Amount reserved from buyer, the max launch price the buyer is willing to pay
+	pub max_price: BalanceOf<T>,
+}
@@ -1,10 +1,51 @@
 use crate::Config;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Hash, Permill},
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
-use super::{aliases::BalanceOf, CreatorId, MetatataUri, MimeType, TokenId, TokenName};
+use super::{
+	aliases::BalanceOf, CreatorId, InlineData, MetatataUri, MimeType, TokenId, TokenName, TokenTags,
+};
 
 pub type TokenSupply = u32;
 
+/// Blake2 hash of lowercased launch token name
+pub type NameHash = [u8; 32];
+
+/// Hash of launch token content supplied by the creator
+pub type ContentHash = [u8; 32];
+
+/// Unit launch token prices are denominated in
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PriceMode {
+	/// Price is in native currency
+	Native,
+	/// Price is in a reference unit (e.g. USD cents) converted to native currency at purchase time
+	Reference,
+}
+
+/// Sale state of launch token
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum LaunchState {
+	/// Draft not yet published, tokens cannot be issued
+	Upcoming,
+	/// Tokens can be bought and gifted
+	Live,
+	/// Public supply is exhausted, the remaining gift supply can still be gifted
+	SoldOut,
+	/// Issuance is suspended until resumed by the creator
+	Paused,
+	/// Issuance has ended permanently
+	Cancelled,
+	/// Issuance is suspended by moderators after an upheld infringement claim
+	Frozen,
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct LaunchToken<T: Config> {
@@ -12,12 +53,43 @@ pub struct LaunchToken<T: Config> {
 	pub creator: CreatorId,
 	pub name: TokenName,
 	pub price: BalanceOf<T>,
+	pub price_mode: PriceMode,
+	/// Share of launch price held in reserve and refunded to holder on burn
+	pub redeemable: Permill,
+	/// Share of secondary sale prices paid to the launch token beneficiary
+	pub royalty: Permill,
+	/// Tokens can only be transferred to registered marketplaces, sales pay royalties
+	pub royalty_enforced: bool,
+	/// Buyers receive a purchase receipt on primary purchase
+	pub receipts: bool,
+	/// Blocks after issuance during which tokens cannot be transferred or listed
+	pub lockup: u32,
+	/// Tokens can only be listed with approval of the creator
+	pub curated: bool,
 	pub mime_type: MimeType,
 	pub metadata_uri: MetatataUri,
-	// launch token specific fields
+	/// Tags from the on-chain taxonomy
+	pub tags: TokenTags,
+	/// Hash of the content, used to detect duplicate launches
+	pub content_hash: Option<ContentHash>,
+	/// Metadata revision, increased on every metadata update
+	pub metadata_version: u32,
+}
+
+/// Issuance counters and sale state of launch token.
+///
+/// Kept apart from `LaunchToken` so issuance only reads and writes a few bytes.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LaunchTokenState {
 	pub supply: TokenSupply,
 	pub issued: TokenSupply,
 	pub destroyed: TokenSupply,
+	/// Part of supply reserved for gifting and not available for purchase
+	pub gift_supply: TokenSupply,
+	/// Tokens issued from the gift supply
+	pub gifted: TokenSupply,
+	/// Sale state, draft launch tokens are upcoming until published
+	pub state: LaunchState,
 }
 
 impl<T: Config> LaunchToken<T> {
@@ -31,16 +103,83 @@ impl<T: Config> LaunchToken<T> {
 			id,
 			creator,
 			price,
+			price_mode: metadata.price_mode,
+			redeemable: metadata.redeemable,
+			royalty: metadata.royalty.unwrap_or_default(),
+			royalty_enforced: metadata.royalty_enforced,
+			receipts: metadata.receipts,
+			lockup: metadata.lockup,
+			curated: metadata.curated,
 			name: metadata.name,
 			mime_type: metadata.mime_type,
 			metadata_uri: metadata.metadata_uri,
+			tags: metadata.tags,
+			content_hash: metadata.content_hash,
+			metadata_version: 0,
+		}
+	}
+
+	/// Replace price and metadata of draft launch token.
+	///
+	/// Increases the metadata version, supplies are updated with `LaunchTokenState::update_draft`.
+	pub fn update_draft(&mut self, price: BalanceOf<T>, metadata: LaunchTokenMetadata) {
+		self.price = price;
+		self.price_mode = metadata.price_mode;
+		self.redeemable = metadata.redeemable;
+		if let Some(royalty) = metadata.royalty {
+			self.royalty = royalty;
+		}
+		self.royalty_enforced = metadata.royalty_enforced;
+		self.receipts = metadata.receipts;
+		self.lockup = metadata.lockup;
+		self.curated = metadata.curated;
+		self.name = metadata.name;
+		self.mime_type = metadata.mime_type;
+		self.metadata_uri = metadata.metadata_uri;
+		self.tags = metadata.tags;
+		self.content_hash = metadata.content_hash;
+		self.metadata_version = self.metadata_version.saturating_add(1);
+	}
+
+	/// Hash of name, mime type, metadata uri and tags.
+	pub fn metadata_hash(&self) -> T::Hash {
+		T::Hashing::hash_of(&(&self.name, &self.mime_type, &self.metadata_uri, &self.tags))
+	}
+}
+
+impl LaunchTokenState {
+	pub fn new(metadata: &LaunchTokenMetadata) -> Self {
+		Self {
 			supply: metadata.supply,
 			issued: 0,
 			destroyed: 0,
+			gift_supply: metadata.gift_supply,
+			gifted: 0,
+			state: if metadata.draft { LaunchState::Upcoming } else { LaunchState::Live },
 		}
 	}
 
-	/// Increase issued count by 1.
+	/// Returns `true` if launch token is a draft.
+	pub fn is_draft(&self) -> bool {
+		self.state == LaunchState::Upcoming
+	}
+
+	/// State of launch token once live, sold out if the public supply is exhausted.
+	pub fn live_state(&self) -> LaunchState {
+		if self.available_for_sale() == 0 {
+			LaunchState::SoldOut
+		} else {
+			LaunchState::Live
+		}
+	}
+
+	/// Replace supplies of draft launch token, draft state is kept.
+	pub fn update_draft(&mut self, metadata: &LaunchTokenMetadata) {
+		self.supply = metadata.supply;
+		self.gift_supply = metadata.gift_supply;
+	}
+
+	/// Supply including destroyed tokens.
 	pub fn total_supply(&self) -> TokenSupply {
 		self.supply.saturating_add(self.destroyed)
 	}
@@ -50,6 +189,23 @@ impl<T: Config> LaunchToken<T> {
 		self.issued = self.issued.saturating_add(1);
 	}
 
+	/// Increase gifted count by 1.
+	pub fn bump_gifted(&mut self) {
+		self.gifted = self.gifted.saturating_add(1);
+	}
+
+	/// Tokens left in the gift supply.
+	pub fn available_for_gift(&self) -> TokenSupply {
+		self.gift_supply.saturating_sub(self.gifted)
+	}
+
+	/// Tokens left for purchase, excluding the remaining gift supply.
+	pub fn available_for_sale(&self) -> TokenSupply {
+		self.total_supply()
+			.saturating_sub(self.issued)
+			.saturating_sub(self.available_for_gift())
+	}
+
 	/// Increase destroyed count by 1 and decrease supply count by 1.
 	pub fn bump_destroyed_and_decrease_supply(&mut self) {
 		self.supply = self.supply.saturating_sub(1);
@@ -62,5 +218,42 @@ pub struct LaunchTokenMetadata {
 	pub name: TokenName,
 	pub mime_type: MimeType,
 	pub metadata_uri: MetatataUri,
+	/// Tags from the on-chain taxonomy
+	pub tags: TokenTags,
+	/// Hash of the content, used to detect duplicate launches
+	pub content_hash: Option<ContentHash>,
 	pub supply: TokenSupply,
+	/// Part of supply reserved for gifting
+	pub gift_supply: TokenSupply,
+	pub price_mode: PriceMode,
+	/// Share of launch price held in reserve and refunded to holder on burn
+	pub redeemable: Permill,
+	/// Share of secondary sale prices paid to the launch token beneficiary, creator default if
+	/// `None`
+	pub royalty: Option<Permill>,
+	/// Block plain transfers to accounts other than registered marketplaces
+	pub royalty_enforced: bool,
+	/// Issue a non-transferable purchase receipt to buyers on primary purchase
+	pub receipts: bool,
+	/// Blocks after issuance during which tokens cannot be transferred or listed
+	pub lockup: u32,
+	/// Require approval of the creator to list tokens
+	pub curated: bool,
+	/// Mint as draft, hidden from issuance until published
+	pub draft: bool,
+	/// Content stored on-chain against a deposit, independent of the metadata uri
+	pub inline_data: Option<InlineData>,
+}
+
+/// Overrides of a cloned launch token, fields left `None` are copied from the source launch token
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LaunchCloneOverrides<Balance, BlockNumber> {
+	pub price: Option<Balance>,
+	pub supply: Option<TokenSupply>,
+	pub gift_supply: Option<TokenSupply>,
+	/// Block at which purchasable supply starts to be released
+	pub sale_start: Option<BlockNumber>,
 }
+
+pub type LaunchCloneOverridesOf<T> =
+	LaunchCloneOverrides<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
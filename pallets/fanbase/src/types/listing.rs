@@ -0,0 +1,26 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Listing whose price declines linearly from start price to end price
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DutchListing<T: Config> {
+	pub start_price: BalanceOf<T>,
+	pub end_price: BalanceOf<T>,
+	/// Block at which the price starts declining
+	pub starts_at: T::BlockNumber,
+	/// Block at which the price reaches the end price
+	pub ends_at: T::BlockNumber,
+}
+
+/// Request by token owner to list a token of a curated launch token
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ListingRequest<T: Config> {
+	/// Token owner at request time, the request lapses when the token changes owner
+	pub owner: T::AccountId,
+	/// Approved by the creator, consumed when the token is listed
+	pub approved: bool,
+}
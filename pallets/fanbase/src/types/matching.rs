@@ -0,0 +1,17 @@
+use crate::Config;
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+use super::aliases::BalanceOf;
+
+/// Sponsor funded pool matching primary purchases of a creator's launch tokens
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct MatchingPool<T: Config> {
+	pub sponsor: T::AccountId,
+	/// Amount left in the pool, reserved from the sponsor
+	pub remaining: BalanceOf<T>,
+	/// Share of each purchase price matched from the pool
+	pub ratio: Permill,
+	/// Block at which matching ends
+	pub ends_at: T::BlockNumber,
+}
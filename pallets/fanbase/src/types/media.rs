@@ -0,0 +1,33 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Chunk of media content limited to 64KB
+pub type MediaChunk = BoundedVec<u8, ConstU32<65536>>;
+
+/// Media content of launch token assembled on-chain from chunks
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct MediaUpload<T: Config> {
+	pub uploader: T::AccountId,
+	/// Number of uploaded chunks
+	pub chunks: u32,
+	/// Total length of uploaded chunks in bytes
+	pub size: u32,
+	/// Amount reserved from uploader while the media is stored
+	pub deposit: BalanceOf<T>,
+	/// Content hash, set once finalized after which the media cannot change
+	pub hash: Option<T::Hash>,
+}
+
+impl<T: Config> MediaUpload<T> {
+	pub fn new(uploader: T::AccountId) -> Self {
+		Self { uploader, chunks: 0, size: 0, deposit: Default::default(), hash: None }
+	}
+
+	/// Returns `true` if media has been finalized.
+	pub fn is_finalized(&self) -> bool {
+		self.hash.is_some()
+	}
+}
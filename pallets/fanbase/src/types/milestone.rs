@@ -0,0 +1,48 @@
+use crate::Config;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		Perbill,
+	},
+};
+
+use super::aliases::BalanceOf;
+
+/// Launch proceeds held in escrow and released to the creator as milestones are approved
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct MilestoneEscrow<T: Config> {
+	/// Share of proceeds released by each milestone, shares add up to 100%
+	pub milestones: BoundedVec<Perbill, T::MaxMilestones>,
+	/// Number of approved milestones
+	pub approved: u32,
+	/// Total proceeds paid into escrow
+	pub escrowed: BalanceOf<T>,
+	/// Total proceeds released from escrow
+	pub released: BalanceOf<T>,
+}
+
+impl<T: Config> MilestoneEscrow<T> {
+	pub fn new(milestones: BoundedVec<Perbill, T::MaxMilestones>) -> Self {
+		Self { milestones, approved: 0, escrowed: Default::default(), released: Default::default() }
+	}
+
+	/// Share of proceeds unlocked by approved milestones.
+	pub fn approved_share(&self) -> Perbill {
+		self.milestones
+			.iter()
+			.take(self.approved as usize)
+			.fold(Perbill::zero(), |share, milestone| share.saturating_add(*milestone))
+	}
+
+	/// Proceeds unlocked by approved milestones and not yet released.
+	pub fn releasable(&self) -> BalanceOf<T> {
+		(self.approved_share() * self.escrowed).saturating_sub(self.released)
+	}
+
+	/// Returns `true` if all milestones are approved.
+	pub fn is_complete(&self) -> bool {
+		self.approved as usize >= self.milestones.len()
+	}
+}
@@ -1,8 +1,68 @@
+mod activity;
+mod airdrop;
 pub mod aliases;
+mod auction;
+mod bridge;
+mod calendar;
+mod collaborator;
+mod collection_offer;
 mod creator;
+mod defaults;
+mod drip;
+mod infringement;
+mod inline;
+mod intent;
 mod launch_token;
+mod listing;
+mod matching;
+mod media;
+mod milestone;
+mod parameters;
+mod protected_sale;
+mod quote;
+mod raffle;
+mod receipt;
+mod report;
+mod reputation;
+mod sale;
+mod social;
+mod stats;
+mod succession;
+mod tag;
 mod token;
+mod view;
+mod work;
 
+pub use activity::*;
+pub use airdrop::*;
+pub use auction::*;
+pub use bridge::*;
+pub use calendar::*;
+pub use collaborator::*;
+pub use collection_offer::*;
 pub use creator::*;
+pub use defaults::*;
+pub use drip::*;
+pub use infringement::*;
+pub use inline::*;
+pub use intent::*;
 pub use launch_token::*;
+pub use listing::*;
+pub use matching::*;
+pub use media::*;
+pub use milestone::*;
+pub use parameters::*;
+pub use protected_sale::*;
+pub use quote::*;
+pub use raffle::*;
+pub use receipt::*;
+pub use report::*;
+pub use reputation::*;
+pub use sale::*;
+pub use social::*;
+pub use stats::*;
+pub use succession::*;
+pub use tag::*;
 pub use token::*;
+pub use view::*;
+pub use work::*;
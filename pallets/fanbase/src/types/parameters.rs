@@ -0,0 +1,23 @@
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+use super::aliases::BalanceOf;
+
+/// Governance mutable parameter of the pallet
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PalletParameter<Balance> {
+	/// Fee burned when renewing a creator registration, `None` restores the runtime default
+	CreatorRenewalFee(Option<Balance>),
+	/// Max royalty of launch tokens, `None` restores the runtime default
+	MaxRoyalty(Option<Permill>),
+}
+
+pub type PalletParameterOf<T> = PalletParameter<BalanceOf<T>>;
+
+/// Governance set values of pallet parameters overriding their runtime defaults
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PalletParameters<Balance> {
+	pub creator_renewal_fee: Option<Balance>,
+	pub max_royalty: Option<Permill>,
+}
+
+pub type PalletParametersOf<T> = PalletParameters<BalanceOf<T>>;
@@ -0,0 +1,16 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Secondary sale held in escrow until finalized or reversed
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ProtectedSale<T: Config> {
+	pub seller: T::AccountId,
+	pub buyer: T::AccountId,
+	/// Amount reserved from buyer
+	pub price: BalanceOf<T>,
+	/// Block at which the sale can no longer be disputed
+	pub dispute_ends: T::BlockNumber,
+}
@@ -0,0 +1,26 @@
+use frame_support::pallet_prelude::*;
+
+use super::{aliases::BalanceOf, TokenId};
+
+/// Off-chain quote of a token owner to sell a token to a buyer at a price
+///
+/// The seller signs the SCALE encoded `(b"fanbase/quote", genesis hash, quote)`, so quotes cannot
+/// be replayed on other chains or as other payloads. Quotes are valid for the current quote nonce of the
+/// seller only, bumping the nonce revokes all outstanding quotes.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceQuote<AccountId, Balance, BlockNumber> {
+	pub token_id: TokenId,
+	pub seller: AccountId,
+	pub buyer: AccountId,
+	pub price: Balance,
+	/// Block at which the quote can no longer be executed
+	pub expires_at: BlockNumber,
+	/// Quote nonce of seller the quote was signed for
+	pub nonce: u32,
+}
+
+pub type PriceQuoteOf<T> = PriceQuote<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
@@ -0,0 +1,19 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::{aliases::BalanceOf, CreatorId};
+
+/// Raffle issuing launch tokens to randomly drawn entrants
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Raffle<T: Config> {
+	pub creator: CreatorId,
+	/// Amount reserved per ticket, paid by winners and refunded to losers
+	pub ticket_fee: BalanceOf<T>,
+	/// Max number of winners drawn
+	pub winners: u32,
+	/// Block at which entries close and the raffle can be drawn
+	pub ends_at: T::BlockNumber,
+	/// Random seed of the draw, set at the start of the closing block
+	pub seed: Option<T::Hash>,
+}
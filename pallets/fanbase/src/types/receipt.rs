@@ -0,0 +1,14 @@
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Proof of primary purchase of a token, kept by the buyer when the token is resold
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Receipt<Balance, BlockNumber> {
+	/// Launch price paid
+	pub price: Balance,
+	/// Block at which the token was bought
+	pub block: BlockNumber,
+}
+
+pub type ReceiptOf<T> = Receipt<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
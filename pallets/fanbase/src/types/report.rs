@@ -0,0 +1,19 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+pub type ReportId = u32;
+
+/// Report reason limited to 256 bytes
+pub type ReportReason = BoundedVec<u8, ConstU32<256>>;
+
+/// Report against a launch token awaiting resolution by moderators
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Report<T: Config> {
+	pub reporter: T::AccountId,
+	pub reason: ReportReason,
+	/// Amount reserved from reporter, returned if upheld and slashed if dismissed
+	pub deposit: BalanceOf<T>,
+}
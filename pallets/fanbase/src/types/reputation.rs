@@ -0,0 +1,49 @@
+use frame_support::pallet_prelude::*;
+
+use super::aliases::BalanceOf;
+
+/// Score earned per sold out launch
+pub const SCORE_PER_SUCCESSFUL_LAUNCH: u32 = 100;
+
+/// Score earned per primary sale
+pub const SCORE_PER_SALE: u32 = 1;
+
+/// Score lost per redeemable refund
+pub const SCORE_PER_REFUND: u32 = 2;
+
+/// Score lost per upheld report
+pub const SCORE_PER_UPHELD_REPORT: u32 = 50;
+
+/// On-chain activity of a creator account
+#[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Reputation<Balance> {
+	/// Launch tokens that sold out
+	pub successful_launches: u32,
+	/// Primary sales of launch tokens
+	pub sales: u32,
+	/// Total price of primary sales
+	pub sales_volume: Balance,
+	/// Redeemable reserves refunded to holders on burn
+	pub refunds: u32,
+	/// Reports upheld against launch tokens
+	pub upheld_reports: u32,
+}
+
+impl<Balance> Reputation<Balance> {
+	/// Score derived from activity, positive activity adds to the score and negative activity
+	/// subtracts from it down to zero.
+	pub fn score(&self) -> u32 {
+		let earned = self
+			.successful_launches
+			.saturating_mul(SCORE_PER_SUCCESSFUL_LAUNCH)
+			.saturating_add(self.sales.saturating_mul(SCORE_PER_SALE));
+		let lost = self
+			.refunds
+			.saturating_mul(SCORE_PER_REFUND)
+			.saturating_add(self.upheld_reports.saturating_mul(SCORE_PER_UPHELD_REPORT));
+
+		earned.saturating_sub(lost)
+	}
+}
+
+pub type ReputationOf<T> = Reputation<BalanceOf<T>>;
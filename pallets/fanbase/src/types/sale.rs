@@ -0,0 +1,20 @@
+use frame_support::pallet_prelude::*;
+
+use super::{aliases::BalanceOf, TokenId};
+
+/// Completed sale of a token
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Sale<AccountId, Balance, BlockNumber> {
+	pub token_id: TokenId,
+	pub seller: AccountId,
+	pub buyer: AccountId,
+	pub price: Balance,
+	/// Block at which the sale completed
+	pub block: BlockNumber,
+}
+
+pub type SaleOf<T> = Sale<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
@@ -0,0 +1,39 @@
+use frame_support::pallet_prelude::*;
+
+/// Social platform name limited to 32 bytes
+pub type SocialPlatform = BoundedVec<u8, ConstU32<32>>;
+
+/// Social handle limited to 64 bytes
+pub type SocialHandle = BoundedVec<u8, ConstU32<64>>;
+
+/// Off-chain proof that an attester verified a social handle belongs to a creator account
+///
+/// The signature covers the SCALE encoded `(creator id, owner, platform, handle, expires at)`,
+/// so proofs cannot be replayed after the creator account changes owner or the proof expires.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SocialProof<AccountId, BlockNumber, Signature> {
+	pub attester: AccountId,
+	/// Block from which the proof is no longer accepted
+	pub expires_at: BlockNumber,
+	pub signature: Signature,
+}
+
+pub type SocialProofOf<T> = SocialProof<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as crate::Config>::OffchainSignature,
+>;
+
+/// Social handle of a creator account verified by an attester
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SocialAttestation<AccountId, BlockNumber> {
+	pub handle: SocialHandle,
+	pub attester: AccountId,
+	/// Block at which the handle was attested
+	pub block: BlockNumber,
+}
+
+pub type SocialAttestationOf<T> = SocialAttestation<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
@@ -0,0 +1,21 @@
+use frame_support::pallet_prelude::*;
+
+/// Aggregate token counters of a creator account
+#[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreatorStats {
+	/// Launch tokens minted
+	pub launches: u32,
+	/// Tokens issued from launch tokens
+	pub issued: u32,
+	/// Tokens destroyed
+	pub destroyed: u32,
+	/// Tokens currently listed for sale
+	pub listed: u32,
+}
+
+impl CreatorStats {
+	/// Tokens issued and not yet destroyed.
+	pub fn circulating(&self) -> u32 {
+		self.issued.saturating_sub(self.destroyed)
+	}
+}
@@ -0,0 +1,11 @@
+use crate::Config;
+use frame_support::pallet_prelude::*;
+
+/// Successor nominated to claim a creator account once its owner has been inactive
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Succession<T: Config> {
+	pub successor: T::AccountId,
+	/// Blocks without activity of the owner after which the successor can claim ownership
+	pub threshold: T::BlockNumber,
+}
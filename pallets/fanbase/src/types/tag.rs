@@ -0,0 +1,9 @@
+use frame_support::pallet_prelude::*;
+
+pub type TagId = u32;
+
+/// Tag name limited to 64 bytes
+pub type TagName = BoundedVec<u8, ConstU32<64>>;
+
+/// Tags of launch token limited to 10 tags
+pub type TokenTags = BoundedVec<TagId, ConstU32<10>>;
@@ -1,5 +1,5 @@
 use crate::Config;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
 
 use super::{aliases::BalanceOf, CreatorId, LaunchToken};
 
@@ -21,23 +21,39 @@ pub struct Token<T: Config> {
 	pub launch_id: TokenId,
 	pub creator: CreatorId,
 	pub owner: T::AccountId,
-	pub name: TokenName,
 	pub price: Option<BalanceOf<T>>,
+	/// Metadata uri of this token, launch token metadata uri if `None`
+	pub metadata_uri: Option<MetatataUri>,
+	/// Metadata revision of launch token at issuance
+	pub metadata_version: u32,
+	/// Block until which the token cannot be transferred or listed
+	pub locked_until: T::BlockNumber,
+}
+
+/// Token metadata resolved through its launch token
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TokenMetadata {
+	pub name: TokenName,
 	pub mime_type: MimeType,
 	pub metadata_uri: MetatataUri,
 }
 
 impl<T: Config> Token<T> {
-	pub fn new(owner: T::AccountId, id: TokenId, launch_token: LaunchToken<T>) -> Self {
+	pub fn new(
+		owner: T::AccountId,
+		id: TokenId,
+		launch_token: LaunchToken<T>,
+		issued_at: T::BlockNumber,
+	) -> Self {
 		Self {
 			id,
 			owner,
 			launch_id: launch_token.id,
 			creator: launch_token.creator,
-			name: launch_token.name,
 			price: None, // reset token price
-			mime_type: launch_token.mime_type,
-			metadata_uri: launch_token.metadata_uri,
+			metadata_uri: None,
+			metadata_version: launch_token.metadata_version,
+			locked_until: issued_at.saturating_add(launch_token.lockup.into()),
 		}
 	}
 }
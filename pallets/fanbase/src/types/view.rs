@@ -0,0 +1,200 @@
+use frame_support::{pallet_prelude::*, sp_runtime::Permill, sp_std::prelude::*};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use super::{aliases::BalanceOf, LaunchState, PriceMode, TagId, TokenId, TokenSupply};
+
+/// Token as shown by frontends
+///
+/// Text fields serialize as strings and prices as decimal strings.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TokenView<AccountId, Balance, BlockNumber> {
+	pub id: TokenId,
+	pub launch_id: TokenId,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub creator: Vec<u8>,
+	pub owner: AccountId,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub name: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::option_number"))]
+	pub price: Option<Balance>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub mime_type: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub metadata_uri: Vec<u8>,
+	pub metadata_version: u32,
+	pub locked_until: BlockNumber,
+}
+
+/// Launch token as shown by frontends
+///
+/// Text fields serialize as strings and prices as decimal strings.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LaunchTokenView<Balance> {
+	pub id: TokenId,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub creator: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub name: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::number"))]
+	pub price: Balance,
+	pub price_mode: PriceMode,
+	pub royalty: Permill,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub mime_type: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub metadata_uri: Vec<u8>,
+	pub tags: Vec<TagId>,
+	pub metadata_version: u32,
+	pub supply: TokenSupply,
+	pub issued: TokenSupply,
+	pub destroyed: TokenSupply,
+	pub gift_supply: TokenSupply,
+	pub gifted: TokenSupply,
+	pub state: LaunchState,
+}
+
+pub type TokenViewOf<T> = TokenView<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type LaunchTokenViewOf<T> = LaunchTokenView<BalanceOf<T>>;
+
+/// Creator account as shown by frontends
+///
+/// Creator ids serialize as strings.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CreatorView<AccountId, BlockNumber> {
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub id: Vec<u8>,
+	/// Owner account, sub-creators resolve to the owner of their parent
+	pub owner: Option<AccountId>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::option_text"))]
+	pub parent: Option<Vec<u8>>,
+	pub expires_at: BlockNumber,
+	pub expired: bool,
+}
+
+pub type CreatorViewOf<T> =
+	CreatorView<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+/// Token metadata in the ERC-721 metadata JSON schema, read by bridges and generic NFT wallets
+///
+/// The content uri is exposed as `image` and, for content other than images, as
+/// `animation_url`.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Erc721Metadata {
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub name: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub image: Vec<u8>,
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "serde_text::option_text", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub animation_url: Option<Vec<u8>>,
+	pub attributes: Vec<Erc721Attribute>,
+}
+
+/// Trait of a token in the ERC-721 metadata JSON schema
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Erc721Attribute {
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub trait_type: Vec<u8>,
+	#[cfg_attr(feature = "std", serde(with = "serde_text::text"))]
+	pub value: Vec<u8>,
+}
+
+impl Erc721Attribute {
+	pub fn new(trait_type: &[u8], value: Vec<u8>) -> Self {
+		Erc721Attribute { trait_type: trait_type.to_vec(), value }
+	}
+}
+
+/// Serialize bytes as UTF-8 strings and numbers as decimal strings.
+#[cfg(feature = "std")]
+mod serde_text {
+	pub mod text {
+		use serde::{Deserialize, Deserializer, Serializer};
+
+		pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.serialize_str(&String::from_utf8_lossy(bytes))
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Vec<u8>, D::Error> {
+			String::deserialize(deserializer).map(String::into_bytes)
+		}
+	}
+
+	pub mod option_text {
+		use serde::{Deserialize, Deserializer, Serializer};
+
+		pub fn serialize<S: Serializer>(
+			bytes: &Option<Vec<u8>>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			match bytes {
+				Some(bytes) => serializer.serialize_some(&String::from_utf8_lossy(bytes)),
+				None => serializer.serialize_none(),
+			}
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Option<Vec<u8>>, D::Error> {
+			Option::<String>::deserialize(deserializer).map(|text| text.map(String::into_bytes))
+		}
+	}
+
+	pub mod number {
+		use serde::{de::Error, Deserialize, Deserializer, Serializer};
+		use std::{fmt::Display, str::FromStr};
+
+		pub fn serialize<N: Display, S: Serializer>(
+			number: &N,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			serializer.collect_str(number)
+		}
+
+		pub fn deserialize<'de, N: FromStr, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<N, D::Error> {
+			String::deserialize(deserializer)?
+				.parse()
+				.map_err(|_| D::Error::custom("invalid number"))
+		}
+	}
+
+	pub mod option_number {
+		use serde::{de::Error, Deserialize, Deserializer, Serializer};
+		use std::{fmt::Display, str::FromStr};
+
+		pub fn serialize<N: Display, S: Serializer>(
+			number: &Option<N>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			match number {
+				Some(number) => serializer.serialize_some(&number.to_string()),
+				None => serializer.serialize_none(),
+			}
+		}
+
+		pub fn deserialize<'de, N: FromStr, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Option<N>, D::Error> {
+			Option::<String>::deserialize(deserializer)?
+				.map(|number| number.parse().map_err(|_| D::Error::custom("invalid number")))
+				.transpose()
+		}
+	}
+}
@@ -0,0 +1,37 @@
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+
+use super::{SnapshotId, TokenId};
+
+pub type WorkId = u32;
+
+/// Multi-block operation processed one step at a time by the work queue
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum WorkItem<AccountId> {
+	/// Issue one token of launch token to each holder of snapshot
+	Airdrop { launch_token_id: TokenId, snapshot_id: SnapshotId },
+	/// Lower royalty of each launch token above `max_royalty` to `max_royalty`
+	ClampRoyalties { max_royalty: Permill },
+	/// Remove each social attestation of a removed attester
+	ClearAttestations { attester: AccountId },
+}
+
+pub type WorkItemOf<T> = WorkItem<<T as frame_system::Config>::AccountId>;
+
+/// Queued work and its progress
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Work<AccountId> {
+	pub item: WorkItem<AccountId>,
+	/// Number of processed steps
+	pub progress: u32,
+	/// Number of steps to complete the work
+	pub total: u32,
+}
+
+pub type WorkOf<T> = Work<<T as frame_system::Config>::AccountId>;
+
+impl<AccountId> Work<AccountId> {
+	/// Returns `true` if all steps are processed.
+	pub fn is_complete(&self) -> bool {
+		self.progress >= self.total
+	}
+}
@@ -1,4 +1,8 @@
-use frame_support::weights::Weight;
+use frame_support::{
+	pallet_prelude::PhantomData,
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
 
 /// Debug weight value for low weighted calls
 pub const LOW: Weight = 5_000;
@@ -8,3 +12,965 @@ pub const MID: Weight = 10_000;
 
 /// Debug weight value for high weighted calls
 pub const HIGH: Weight = 20_000;
+
+/// Weight functions for calls.
+///
+/// Components:
+/// - `c` creator ids on the account
+/// - `n` metadata uri length
+/// - `d` inline data length
+/// - `t` tokens on the receiving or owning account
+/// - `b` tokens in the batch
+/// - `q` tokens bought
+/// - `s` listings scanned
+/// - `e` raffle entries
+/// - `f` featured launch tokens
+/// - `p` attested social platforms of the creator account
+/// - `g` tags of the launch token
+/// - `m` media chunks of the launch token
+/// - `h` holders of the launch token
+pub trait WeightInfo {
+	fn create_account(c: u32) -> Weight;
+	fn drop_account_remove(c: u32) -> Weight;
+	fn drop_account_disconnect(c: u32) -> Weight;
+	fn mint(n: u32, d: u32) -> Weight;
+	fn launch_gift(t: u32) -> Weight;
+	fn launch_buy(t: u32) -> Weight;
+	fn buy(t: u32) -> Weight;
+	fn transfer(t: u32) -> Weight;
+	fn list() -> Weight;
+	fn unlist() -> Weight;
+	fn set_launch_price() -> Weight;
+	fn set_price() -> Weight;
+	fn burn(t: u32) -> Weight;
+	fn create_sub_creator() -> Weight;
+	fn renew_creator() -> Weight;
+	fn add_collaborator() -> Weight;
+	fn remove_collaborator() -> Weight;
+	fn add_operator() -> Weight;
+	fn remove_operator() -> Weight;
+	fn make_creator_keyless() -> Weight;
+	fn dispatch_as_creator() -> Weight;
+	fn clone_launch(n: u32) -> Weight;
+	fn execute_quote(t: u32) -> Weight;
+	fn revoke_quotes() -> Weight;
+	fn bridge_lock() -> Weight;
+	fn bridge_unlock(t: u32) -> Weight;
+	fn transfer_batch(b: u32) -> Weight;
+	fn make_offer() -> Weight;
+	fn set_offer_floor() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn accept_offer(t: u32) -> Weight;
+	fn make_collection_offer() -> Weight;
+	fn cancel_collection_offer() -> Weight;
+	fn accept_collection_offer(t: u32) -> Weight;
+	fn accept_offers_batch(b: u32) -> Weight;
+	fn request_listing() -> Weight;
+	fn approve_listing() -> Weight;
+	fn retire(t: u32) -> Weight;
+	fn burn_batch(b: u32) -> Weight;
+	fn buy_protected() -> Weight;
+	fn finalize_sale(t: u32) -> Weight;
+	fn reverse_sale() -> Weight;
+	fn ban_creator() -> Weight;
+	fn unban_creator() -> Weight;
+	fn block_account() -> Weight;
+	fn unblock_account() -> Weight;
+	fn set_parameter() -> Weight;
+	fn add_attester() -> Weight;
+	fn remove_attester() -> Weight;
+	fn feature_launch(f: u32) -> Weight;
+	fn unfeature_launch(f: u32) -> Weight;
+	fn reorder_featured_launches(f: u32) -> Weight;
+	fn attest_social(p: u32) -> Weight;
+	fn revoke_social() -> Weight;
+	fn initiate_creator_recovery() -> Weight;
+	fn cancel_creator_recovery() -> Weight;
+	fn execute_creator_recovery() -> Weight;
+	fn transfer_creator() -> Weight;
+	fn accept_creator_transfer() -> Weight;
+	fn cancel_creator_transfer() -> Weight;
+	fn sweep(q: u32, s: u32) -> Weight;
+	fn list_dutch() -> Weight;
+	fn launch_buy_many(q: u32) -> Weight;
+	fn set_launch_beneficiary() -> Weight;
+	fn set_royalty_beneficiary() -> Weight;
+	fn set_drip_schedule() -> Weight;
+	fn set_block_purchase_cap() -> Weight;
+	fn schedule_drop() -> Weight;
+	fn link_drop_launch() -> Weight;
+	fn cancel_drop() -> Weight;
+	fn open_raffle() -> Weight;
+	fn enter_raffle() -> Weight;
+	fn draw_raffle(e: u32) -> Weight;
+	fn submit_purchase_intent() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn buy_now(t: u32) -> Weight;
+	fn settle_auction(t: u32) -> Weight;
+	fn relist_from_auction() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn set_milestones() -> Weight;
+	fn approve_milestone() -> Weight;
+	fn claim_milestone_proceeds() -> Weight;
+	fn fund_match() -> Weight;
+	fn close_match() -> Weight;
+	fn add_tag() -> Weight;
+	fn remove_tag() -> Weight;
+	fn set_tags(g: u32) -> Weight;
+	fn report_launch() -> Weight;
+	fn resolve_report() -> Weight;
+	fn claim_infringement() -> Weight;
+	fn resolve_infringement() -> Weight;
+	fn update_draft(n: u32, d: u32, g: u32) -> Weight;
+	fn publish_launch() -> Weight;
+	fn set_launch_defaults() -> Weight;
+	fn pause_launch() -> Weight;
+	fn resume_launch() -> Weight;
+	fn cancel_launch() -> Weight;
+	fn delegate_launch() -> Weight;
+	fn revoke_launch_delegation() -> Weight;
+	fn set_primary_creator() -> Weight;
+	fn upload_media_chunk() -> Weight;
+	fn finalize_media(m: u32) -> Weight;
+	fn clear_media(m: u32) -> Weight;
+	fn force_set_creator() -> Weight;
+	fn force_set_launch_token() -> Weight;
+	fn force_set_token() -> Weight;
+	fn add_marketplace() -> Weight;
+	fn remove_marketplace() -> Weight;
+	fn transfer_all_of_launch(b: u32) -> Weight;
+	fn set_successor() -> Weight;
+	fn claim_succession() -> Weight;
+	fn withdraw_proceeds() -> Weight;
+	fn set_payout_account() -> Weight;
+	fn take_snapshot(h: u32) -> Weight;
+	fn airdrop_to_snapshot() -> Weight;
+}
+
+/// Weights for the fanbase pallet, replace with the output of `benchmark pallet` on reference
+/// hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_account(c: u32) -> Weight {
+		HIGH.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(4, 6))
+	}
+	fn drop_account_remove(c: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn drop_account_disconnect(c: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn mint(n: u32, d: u32) -> Weight {
+		HIGH.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add((10 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(14, 13))
+	}
+	fn launch_gift(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(12, 9))
+	}
+	fn launch_buy(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(18, 18))
+	}
+	fn buy(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(18, 20))
+	}
+	fn transfer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(13, 15))
+	}
+	fn list() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(11, 7))
+	}
+	fn unlist() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn set_launch_price() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(8, 3))
+	}
+	fn set_price() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 6))
+	}
+	fn burn(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(10, 14))
+	}
+	fn create_sub_creator() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+	}
+	fn renew_creator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+	}
+	fn add_collaborator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn remove_collaborator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn add_operator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 1))
+	}
+	fn remove_operator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 1))
+	}
+	fn make_creator_keyless() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn dispatch_as_creator() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(3, 1))
+	}
+	fn clone_launch(n: u32) -> Weight {
+		HIGH.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(18, 14))
+	}
+	fn execute_quote(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(23, 21))
+	}
+	fn revoke_quotes() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn bridge_lock() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(9, 2))
+	}
+	fn bridge_unlock(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(7, 6))
+	}
+	fn transfer_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((13 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((15 as Weight).saturating_mul(b as Weight)))
+	}
+	fn make_offer() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(7, 6))
+	}
+	fn set_offer_floor() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	fn cancel_offer() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn accept_offer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(14, 17))
+	}
+	fn make_collection_offer() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 4))
+	}
+	fn cancel_collection_offer() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	fn accept_collection_offer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(14, 16))
+	}
+	fn accept_offers_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((14 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((17 as Weight).saturating_mul(b as Weight)))
+	}
+	fn request_listing() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn approve_listing() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(6, 2))
+	}
+	fn retire(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(10, 10))
+	}
+	fn burn_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((14 as Weight).saturating_mul(b as Weight)))
+	}
+	fn buy_protected() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(7, 7))
+	}
+	fn finalize_sale(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(8, 11))
+	}
+	fn reverse_sale() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	fn ban_creator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	fn unban_creator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn block_account() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn unblock_account() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn set_parameter() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 4))
+	}
+	fn add_attester() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	fn remove_attester() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+	}
+	fn feature_launch(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	fn unfeature_launch(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn reorder_featured_launches(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn attest_social(p: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(7, 6))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn revoke_social() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+	}
+	fn initiate_creator_recovery() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	fn cancel_creator_recovery() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn execute_creator_recovery() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(5, 8))
+	}
+	fn transfer_creator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	fn accept_creator_transfer() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(5, 6))
+	}
+	fn cancel_creator_transfer() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	fn sweep(q: u32, s: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(q as Weight))
+			.saturating_add((100 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((12 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().writes((17 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+	}
+	fn list_dutch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(16, 16))
+	}
+	fn launch_buy_many(q: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((14 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().writes((12 as Weight).saturating_mul(q as Weight)))
+	}
+	fn set_launch_beneficiary() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+	}
+	fn set_royalty_beneficiary() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+	}
+	fn set_drip_schedule() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+	}
+	fn set_block_purchase_cap() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 1))
+	}
+	fn schedule_drop() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn link_drop_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 1))
+	}
+	fn cancel_drop() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn open_raffle() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(6, 3))
+	}
+	fn enter_raffle() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+	}
+	fn draw_raffle(e: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(6, 2))
+			.saturating_add(T::DbWeight::get().reads((16 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((14 as Weight).saturating_mul(e as Weight)))
+	}
+	fn submit_purchase_intent() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+	}
+	fn create_auction() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(9, 6))
+	}
+	fn bid() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 4))
+	}
+	fn buy_now(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(20, 22))
+	}
+	fn settle_auction(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(11, 14))
+	}
+	fn relist_from_auction() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(13, 9))
+	}
+	fn cancel_auction() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	fn set_milestones() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+	}
+	fn approve_milestone() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+	}
+	fn claim_milestone_proceeds() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(7, 3))
+	}
+	fn fund_match() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 4))
+	}
+	fn close_match() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	fn add_tag() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+	}
+	fn remove_tag() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn set_tags(g: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+	}
+	fn report_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+	}
+	fn resolve_report() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	fn claim_infringement() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+	}
+	fn resolve_infringement() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+	fn update_draft(n: u32, d: u32, g: u32) -> Weight {
+		MID.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add((10 as Weight).saturating_mul(d as Weight))
+			.saturating_add((10 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(10, 9))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+	}
+	fn publish_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+	}
+	fn set_launch_defaults() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn pause_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 4))
+	}
+	fn resume_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 4))
+	}
+	fn cancel_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+	}
+	fn delegate_launch() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+	}
+	fn revoke_launch_delegation() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+	}
+	fn set_primary_creator() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	fn upload_media_chunk() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(6, 5))
+	}
+	fn finalize_media(m: u32) -> Weight {
+		MID.saturating_add((1000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
+	}
+	fn clear_media(m: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+	}
+	fn force_set_creator() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(2, 4))
+	}
+	fn force_set_launch_token() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(4, 7))
+	}
+	fn force_set_token() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(6, 10))
+	}
+	fn add_marketplace() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn remove_marketplace() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn transfer_all_of_launch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((14 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((15 as Weight).saturating_mul(b as Weight)))
+	}
+	fn set_successor() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	fn claim_succession() -> Weight {
+		MID.saturating_add(T::DbWeight::get().reads_writes(7, 7))
+	}
+	fn withdraw_proceeds() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	fn set_payout_account() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn take_snapshot(h: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads_writes(6, 3))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	fn airdrop_to_snapshot() -> Weight {
+		LOW.saturating_add(T::DbWeight::get().reads_writes(9, 2))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_account(c: u32) -> Weight {
+		HIGH.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(4, 6))
+	}
+	fn drop_account_remove(c: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn drop_account_disconnect(c: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn mint(n: u32, d: u32) -> Weight {
+		HIGH.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add((10 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(14, 13))
+	}
+	fn launch_gift(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(12, 9))
+	}
+	fn launch_buy(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(18, 18))
+	}
+	fn buy(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(18, 20))
+	}
+	fn transfer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(13, 15))
+	}
+	fn list() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(11, 7))
+	}
+	fn unlist() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn set_launch_price() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(8, 3))
+	}
+	fn set_price() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 6))
+	}
+	fn burn(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(10, 14))
+	}
+	fn create_sub_creator() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+	}
+	fn renew_creator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+	}
+	fn add_collaborator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn remove_collaborator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn add_operator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 1))
+	}
+	fn remove_operator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 1))
+	}
+	fn make_creator_keyless() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn dispatch_as_creator() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(3, 1))
+	}
+	fn clone_launch(n: u32) -> Weight {
+		HIGH.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(18, 14))
+	}
+	fn execute_quote(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(23, 21))
+	}
+	fn revoke_quotes() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn bridge_lock() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(9, 2))
+	}
+	fn bridge_unlock(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(7, 6))
+	}
+	fn transfer_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((13 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((15 as Weight).saturating_mul(b as Weight)))
+	}
+	fn make_offer() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(7, 6))
+	}
+	fn set_offer_floor() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 1))
+	}
+	fn cancel_offer() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn accept_offer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(14, 17))
+	}
+	fn make_collection_offer() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+	}
+	fn cancel_collection_offer() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+	fn accept_collection_offer(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(14, 16))
+	}
+	fn accept_offers_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((14 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((17 as Weight).saturating_mul(b as Weight)))
+	}
+	fn request_listing() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn approve_listing() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(6, 2))
+	}
+	fn retire(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(10, 10))
+	}
+	fn burn_batch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((10 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((14 as Weight).saturating_mul(b as Weight)))
+	}
+	fn buy_protected() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(7, 7))
+	}
+	fn finalize_sale(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(8, 11))
+	}
+	fn reverse_sale() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 2))
+	}
+	fn ban_creator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 1))
+	}
+	fn unban_creator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn block_account() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn unblock_account() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_parameter() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 4))
+	}
+	fn add_attester() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 1))
+	}
+	fn remove_attester() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 4))
+	}
+	fn feature_launch(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(2, 1))
+	}
+	fn unfeature_launch(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn reorder_featured_launches(f: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn attest_social(p: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(7, 6))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn revoke_social() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 3))
+	}
+	fn initiate_creator_recovery() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 1))
+	}
+	fn cancel_creator_recovery() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn execute_creator_recovery() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(5, 8))
+	}
+	fn transfer_creator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 2))
+	}
+	fn accept_creator_transfer() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(5, 6))
+	}
+	fn cancel_creator_transfer() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 2))
+	}
+	fn sweep(q: u32, s: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(q as Weight))
+			.saturating_add((100 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((12 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((17 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+	}
+	fn list_dutch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(16, 16))
+	}
+	fn launch_buy_many(q: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((14 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((12 as Weight).saturating_mul(q as Weight)))
+	}
+	fn set_launch_beneficiary() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 2))
+	}
+	fn set_royalty_beneficiary() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 2))
+	}
+	fn set_drip_schedule() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 2))
+	}
+	fn set_block_purchase_cap() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 1))
+	}
+	fn schedule_drop() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn link_drop_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 1))
+	}
+	fn cancel_drop() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn open_raffle() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(6, 3))
+	}
+	fn enter_raffle() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+	}
+	fn draw_raffle(e: u32) -> Weight {
+		HIGH.saturating_add((1000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(6, 2))
+			.saturating_add(RocksDbWeight::get().reads((16 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((14 as Weight).saturating_mul(e as Weight)))
+	}
+	fn submit_purchase_intent() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+	}
+	fn create_auction() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(9, 6))
+	}
+	fn bid() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+	}
+	fn buy_now(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(20, 22))
+	}
+	fn settle_auction(t: u32) -> Weight {
+		MID.saturating_add((1 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(11, 14))
+	}
+	fn relist_from_auction() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(13, 9))
+	}
+	fn cancel_auction() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(2, 2))
+	}
+	fn set_milestones() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 2))
+	}
+	fn approve_milestone() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(5, 2))
+	}
+	fn claim_milestone_proceeds() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(7, 3))
+	}
+	fn fund_match() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+	}
+	fn close_match() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+	fn add_tag() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 2))
+	}
+	fn remove_tag() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_tags(g: u32) -> Weight {
+		LOW.saturating_add((10 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(5, 2))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+	}
+	fn report_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 4))
+	}
+	fn resolve_report() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+	fn claim_infringement() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 3))
+	}
+	fn resolve_infringement() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 5))
+	}
+	fn update_draft(n: u32, d: u32, g: u32) -> Weight {
+		MID.saturating_add((10 as Weight).saturating_mul(n as Weight))
+			.saturating_add((10 as Weight).saturating_mul(d as Weight))
+			.saturating_add((10 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(10, 9))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+	}
+	fn publish_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 4))
+	}
+	fn set_launch_defaults() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn pause_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+	}
+	fn resume_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+	}
+	fn cancel_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 4))
+	}
+	fn delegate_launch() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(5, 2))
+	}
+	fn revoke_launch_delegation() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(4, 2))
+	}
+	fn set_primary_creator() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
+	fn upload_media_chunk() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(6, 5))
+	}
+	fn finalize_media(m: u32) -> Weight {
+		MID.saturating_add((1000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(5, 2))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
+	}
+	fn clear_media(m: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(5, 3))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+	}
+	fn force_set_creator() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(2, 4))
+	}
+	fn force_set_launch_token() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(4, 7))
+	}
+	fn force_set_token() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(6, 10))
+	}
+	fn add_marketplace() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn remove_marketplace() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn transfer_all_of_launch(b: u32) -> Weight {
+		LOW.saturating_add((MID as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((14 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((15 as Weight).saturating_mul(b as Weight)))
+	}
+	fn set_successor() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+	fn claim_succession() -> Weight {
+		MID.saturating_add(RocksDbWeight::get().reads_writes(7, 7))
+	}
+	fn withdraw_proceeds() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+	fn set_payout_account() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+	fn take_snapshot(h: u32) -> Weight {
+		MID.saturating_add((100 as Weight).saturating_mul(h as Weight))
+			.saturating_add(RocksDbWeight::get().reads_writes(6, 3))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	fn airdrop_to_snapshot() -> Weight {
+		LOW.saturating_add(RocksDbWeight::get().reads_writes(9, 2))
+	}
+}
@@ -27,13 +27,14 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -101,10 +102,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -266,18 +267,166 @@ impl pallet_sudo::Config for Runtime {
 }
 
 parameter_types! {
+	pub const FanbasePalletId: PalletId = PalletId(*b"fanbase_");
 	pub const MaxCreatorAccounts: u32 = 100;
+	pub const MaxCollaborators: u32 = 10;
+	pub const MaxOperators: u32 = 5;
+	pub const DisputePeriod: BlockNumber = 3 * DAYS;
 	pub const MaxLaunchTokens: u32 = u32::MAX;
 	pub const MaxTokens: u32 = u32::MAX;
+	pub const MinTokenPrice: Balance = EXISTENTIAL_DEPOSIT;
+	pub const PriceChangeCooldown: BlockNumber = 10 * MINUTES;
+	pub const MaxMintsPerPeriod: u32 = 10;
+	pub const MintPeriod: BlockNumber = HOURS;
+	pub const CreatorRegistrationPeriod: BlockNumber = 365 * DAYS;
+	pub const CreatorGracePeriod: BlockNumber = 30 * DAYS;
+	pub const CreatorRenewalFee: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const MaxSubCreators: u32 = 20;
+	pub const RecoveryDelay: BlockNumber = 7 * DAYS;
+	pub const CreatorTransferTimeout: BlockNumber = 3 * DAYS;
+	pub const MinSuccessionThreshold: BlockNumber = 30 * DAYS;
+	pub const MaxSweepCount: u32 = 20;
+	pub const MaxLaunchBuyQuantity: u32 = 20;
+	pub const MaxRaffleEntries: u32 = 1_000;
+	pub const MaxPurchaseIntents: u32 = 200;
+	pub const MaxMemoLength: u32 = 128;
+	pub const MaxBatch: u32 = 50;
+	pub MaxExpiryWeightPerBlock: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const MaxMilestones: u32 = 10;
+	pub const ReportDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const InfringementDeposit: Balance = 1000 * EXISTENTIAL_DEPOSIT;
+	pub const OfferDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
+	pub const DefaultBidIncrement: pallet_fanbase::types::BidIncrement<Balance> =
+		pallet_fanbase::types::BidIncrement::Percent(Permill::from_percent(5));
+	pub const InlineDataDepositPerByte: Balance = EXISTENTIAL_DEPOSIT / 100;
+	pub const MaxMediaChunks: u32 = 64;
+	pub const MaxActivityLog: u32 = 100;
+	pub const WashTradeWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxSnapshotHolders: u32 = 1_000;
+	pub const MaxQueuedWork: u32 = 50;
+	pub const MaxFeaturedLaunches: u32 = 24;
+	pub const CalendarPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxCalendarEntries: u32 = 200;
+	pub const CalendarDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const MaxBridgeProofLength: u32 = 1_024;
+	pub const MaxSweepScan: u32 = 500;
+	pub const MaxRafflesClosingPerBlock: u32 = 50;
+	pub const MaxSocialPlatforms: u32 = 16;
 }
 
 /// Configure the pallet-fanbase in pallets/fanbase.
 impl pallet_fanbase::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type Payment = pallet_fanbase::traits::CurrencyPayment<Balances>;
+	type Call = Call;
+	type PalletId = FanbasePalletId;
+	type IdentityVerifier = ();
+	type TransferFilter = ();
+	type CallFilter = frame_support::traits::Everything;
+	type PriceOracle = ();
+	type TokenIdGenerator = ();
+	type ModerationOrigin = frame_system::EnsureRoot<AccountId>;
+	type DisputeOrigin = frame_system::EnsureRoot<AccountId>;
+	type DisputePeriod = DisputePeriod;
 	type MaxCreatorAccounts = MaxCreatorAccounts;
+	type MaxCollaborators = MaxCollaborators;
+	type MaxOperators = MaxOperators;
 	type MaxLaunchTokens = MaxLaunchTokens;
 	type MaxTokens = MaxTokens;
+	type MinTokenPrice = MinTokenPrice;
+	type PriceChangeCooldown = PriceChangeCooldown;
+	type MaxMintsPerPeriod = MaxMintsPerPeriod;
+	type MintPeriod = MintPeriod;
+	type CreatorRegistrationPeriod = CreatorRegistrationPeriod;
+	type CreatorGracePeriod = CreatorGracePeriod;
+	type CreatorRenewalFee = CreatorRenewalFee;
+	type MaxSubCreators = MaxSubCreators;
+	type RecoveryOrigin = frame_system::EnsureRoot<AccountId>;
+	type RecoveryDelay = RecoveryDelay;
+	type CreatorTransferTimeout = CreatorTransferTimeout;
+	type MinSuccessionThreshold = MinSuccessionThreshold;
+	type MaxSweepCount = MaxSweepCount;
+	type MaxLaunchBuyQuantity = MaxLaunchBuyQuantity;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxRaffleEntries = MaxRaffleEntries;
+	type MaxPurchaseIntents = MaxPurchaseIntents;
+	type MaxMemoLength = MaxMemoLength;
+	type MaxBatch = MaxBatch;
+	type MaxExpiryWeightPerBlock = MaxExpiryWeightPerBlock;
+	type MilestoneOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxMilestones = MaxMilestones;
+	type TaxonomyOrigin = frame_system::EnsureRoot<AccountId>;
+	type ReportDeposit = ReportDeposit;
+	type InfringementDeposit = InfringementDeposit;
+	type OfferDeposit = OfferDeposit;
+	type AllowDeathPayments = ConstBool<false>;
+	type RoyaltyDustTreasury = ();
+	type MaxRoyalty = MaxRoyalty;
+	type OnPurchase = ();
+	type InlineDataDepositPerByte = InlineDataDepositPerByte;
+	type MaxMediaChunks = MaxMediaChunks;
+	type WeightInfo = pallet_fanbase::weights::SubstrateWeight<Runtime>;
+	type MaxActivityLog = MaxActivityLog;
+	type WashTradeWindow = WashTradeWindow;
+	type RejectWashTrades = ConstBool<false>;
+	type RejectDuplicateContent = ConstBool<false>;
+	type AttestationOrigin = frame_system::EnsureRoot<AccountId>;
+	type CurationOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxFeaturedLaunches = MaxFeaturedLaunches;
+	type CalendarPeriod = CalendarPeriod;
+	type MaxCalendarEntries = MaxCalendarEntries;
+	type CalendarDeposit = CalendarDeposit;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type MaxSnapshotHolders = MaxSnapshotHolders;
+	type MaxQueuedWork = MaxQueuedWork;
+	type BridgeVerifier = ();
+	type MaxBridgeProofLength = MaxBridgeProofLength;
+	type MaxSweepScan = MaxSweepScan;
+	type MaxRafflesClosingPerBlock = MaxRafflesClosingPerBlock;
+	type MaxSocialPlatforms = MaxSocialPlatforms;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = FanbaseBenchmarkHelper;
+}
+
+/// Key type of off-chain signers generated in fanbase benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+const FANBASE_BENCHMARK_KEY_TYPE: KeyTypeId = KeyTypeId(*b"fanb");
+
+/// Signs fanbase benchmark payloads with sr25519 keys generated in the benchmark keystore, bridging
+/// is disabled.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FanbaseBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_fanbase::traits::BenchmarkHelper<AccountId, Signature> for FanbaseBenchmarkHelper {
+	fn signer() -> AccountId {
+		let public =
+			frame_support::sp_io::crypto::sr25519_generate(FANBASE_BENCHMARK_KEY_TYPE, None);
+		sp_runtime::MultiSigner::from(public).into_account()
+	}
+
+	fn sign(signer: &AccountId, payload: &[u8]) -> Signature {
+		let public = sp_core::sr25519::Public::from_raw(signer.clone().into());
+		frame_support::sp_io::crypto::sr25519_sign(FANBASE_BENCHMARK_KEY_TYPE, &public, payload)
+			.expect("signer key is generated in the benchmark keystore; qed")
+			.into()
+	}
+
+	fn bridge_chain() -> Option<pallet_fanbase::types::ChainId> {
+		None
+	}
+
+	fn bridge_unlock_proof(
+		_token_id: &pallet_fanbase::types::TokenId,
+		_receiver: &AccountId,
+	) -> Option<Vec<u8>> {
+		None
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -329,8 +478,16 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on runtime upgrade.
+type Migrations = (
+	pallet_fanbase::migrations::FundPalletAccount<Runtime>,
+	pallet_fanbase::migrations::v1::MigrateToV1<Runtime>,
+	pallet_fanbase::migrations::v2::MigrateToV2<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -494,6 +651,133 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fanbase_runtime_api::FanbaseApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn sales_of(
+			launch_id: pallet_fanbase::types::TokenId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_fanbase::types::Sale<AccountId, Balance, BlockNumber>> {
+			Fanbase::sales_of(launch_id, offset, limit)
+		}
+
+		fn listed_tokens(
+			cursor: Option<pallet_fanbase::types::TokenId>,
+			limit: u32,
+		) -> (
+			Vec<(pallet_fanbase::types::TokenId, Balance)>,
+			Option<pallet_fanbase::types::TokenId>,
+		) {
+			Fanbase::listed_tokens(cursor, limit)
+		}
+
+		fn listed_tokens_of_creator(
+			creator_id: pallet_fanbase::types::CreatorId,
+			cursor: Option<pallet_fanbase::types::TokenId>,
+			limit: u32,
+		) -> (
+			Vec<(pallet_fanbase::types::TokenId, Balance)>,
+			Option<pallet_fanbase::types::TokenId>,
+		) {
+			Fanbase::listed_tokens_of_creator(creator_id, cursor, limit)
+		}
+
+		fn launch_tokens_in_state(
+			state: pallet_fanbase::types::LaunchState,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_fanbase::types::TokenId> {
+			Fanbase::launch_tokens_in_state(state, offset, limit)
+		}
+
+		fn launch_tokens_by_name(
+			name: Vec<u8>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_fanbase::types::TokenId> {
+			Fanbase::launch_tokens_by_name(&name, offset, limit)
+		}
+
+		fn lookup_creator(creator_id: pallet_fanbase::types::CreatorId) -> Option<AccountId> {
+			<Fanbase as pallet_fanbase::traits::CreatorLookup<AccountId>>::lookup(&creator_id)
+		}
+
+		fn reverse_lookup_creator(account: AccountId) -> Option<pallet_fanbase::types::CreatorId> {
+			<Fanbase as pallet_fanbase::traits::CreatorLookup<AccountId>>::reverse_lookup(&account)
+		}
+
+		fn creator_reputation(
+			creator_id: pallet_fanbase::types::CreatorId,
+		) -> pallet_fanbase::types::Reputation<Balance> {
+			Fanbase::reputation(creator_id)
+		}
+
+		fn creator_score(creator_id: pallet_fanbase::types::CreatorId) -> u32 {
+			Fanbase::creator_score(&creator_id)
+		}
+
+		fn creator_stats(
+			creator_id: pallet_fanbase::types::CreatorId,
+		) -> pallet_fanbase::types::CreatorStats {
+			Fanbase::creator_stats(creator_id)
+		}
+
+		fn tokens_of_paged(
+			account: AccountId,
+			cursor: Option<pallet_fanbase::types::TokenId>,
+			limit: u32,
+		) -> (Vec<pallet_fanbase::types::TokenId>, Option<pallet_fanbase::types::TokenId>) {
+			Fanbase::tokens_of_paged(&account, cursor, limit)
+		}
+
+		fn receipts_of(
+			launch_id: pallet_fanbase::types::TokenId,
+			account: AccountId,
+		) -> Vec<(
+			pallet_fanbase::types::TokenId,
+			pallet_fanbase::types::Receipt<Balance, BlockNumber>,
+		)> {
+			Fanbase::receipts_of(&launch_id, &account)
+		}
+
+		fn pending_proceeds(account: AccountId) -> Balance {
+			Fanbase::pending_proceeds(account)
+		}
+
+		fn token_view(
+			token_id: pallet_fanbase::types::TokenId,
+		) -> Option<pallet_fanbase::types::TokenView<AccountId, Balance, BlockNumber>> {
+			Fanbase::token_view(&token_id)
+		}
+
+		fn launch_token_view(
+			launch_id: pallet_fanbase::types::TokenId,
+		) -> Option<pallet_fanbase::types::LaunchTokenView<Balance>> {
+			Fanbase::launch_token_view(&launch_id)
+		}
+
+		fn creator_view(
+			creator_id: pallet_fanbase::types::CreatorId,
+		) -> Option<pallet_fanbase::types::CreatorView<AccountId, BlockNumber>> {
+			Fanbase::creator_view(&creator_id)
+		}
+
+		fn token_uri(token_id: pallet_fanbase::types::TokenId) -> Option<Vec<u8>> {
+			Fanbase::token_uri(&token_id)
+		}
+
+		fn erc721_metadata(
+			token_id: pallet_fanbase::types::TokenId,
+		) -> Option<pallet_fanbase::types::Erc721Metadata> {
+			Fanbase::erc721_metadata(&token_id)
+		}
+
+		fn launch_calendar(
+			period: BlockNumber,
+		) -> Vec<pallet_fanbase::types::CalendarEntry<AccountId, Balance, BlockNumber>> {
+			Fanbase::launch_calendar_of(period)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (